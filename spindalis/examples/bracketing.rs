@@ -0,0 +1,35 @@
+use spindalis::polynomials::{PolynomialTraits, SimplePolynomial};
+use spindalis::solvers::{
+    Bounds, SolveMode, SolverError, bisection, brent, false_position, ridders,
+};
+
+type Solver = fn(&SimplePolynomial, Bounds, f64, usize, SolveMode) -> Result<f64, SolverError>;
+
+fn main() {
+    let polynomial = "-2x^6 - 1.6x^4 + 12x + 1";
+    let parsed = SimplePolynomial::parse(polynomial).unwrap();
+    let error_tol = 1e-8;
+    let itermax = 10000;
+    println!("The polynomial being evaluated is {polynomial}\n");
+
+    let solvers: [(&str, Solver); 4] = [
+        ("Bisection", bisection),
+        ("False position", false_position),
+        ("Brent", brent),
+        ("Ridders", ridders),
+    ];
+    for (name, solver) in solvers {
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 0.6,
+            upper: 2.0,
+        };
+        match solver(&parsed, bounds, error_tol, itermax, SolveMode::Root) {
+            Ok(x) => println!(
+                "{name}: root at x = {x:.8}, f(x) = {:.2e}",
+                parsed.eval_univariate(x).unwrap()
+            ),
+            Err(e) => eprintln!("{name}: {e:?}"),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use spindalis::utils::{Arr2D, Rounding};
 fn main() {
     let matrix = Arr2D::from(&[[2.0, 8.0, 10.0], [8.0, 4.0, 5.0], [10.0, 5.0, 7.0]]);
     println!("Original Matrix:\n{matrix}\n");
-    let result = power_method(&matrix, 1e-10);
+    let result = power_method(&matrix, 1e-10, None, 1000);
     match result {
         Ok((value, vector)) => println!(
             "Largest Eigenvalue = {value:.4}\nAssociated Eigenvector:\n{}\n",
@@ -20,7 +20,7 @@ fn main() {
         }
     };
     if !inverse_matrix.is_empty() {
-        let result = power_method(&inverse_matrix, 1e-10);
+        let result = power_method(&inverse_matrix, 1e-10, None, 1000);
         match result {
             Ok((value, vector)) => println!(
                 "Smallest Eigenvalue = {:.4}\nAssociated Eigenvector:\n{}",
@@ -0,0 +1,47 @@
+use spindalis::polynomials::{IntermediatePolynomial, PolynomialTraits};
+use spindalis::solvers::{broyden_system, damped_newton_system, newton_system, system_variables};
+
+fn main() {
+    let equations = ["x^2 + xy - 10", "y + 3xy^2 - 57"];
+    let system: Vec<IntermediatePolynomial> = equations
+        .iter()
+        .map(|eq| IntermediatePolynomial::parse(eq).unwrap())
+        .collect();
+    let variables = system_variables(&system);
+    let error_tol = 1e-8;
+    let itermax = 100;
+
+    println!("Solving the system:");
+    for eq in equations {
+        println!("    {eq} = 0");
+    }
+    println!("True solution: x = 2, y = 3\n");
+
+    let results = [
+        (
+            "Newton-Raphson",
+            newton_system(&system, &[1.5, 3.5], itermax, error_tol),
+        ),
+        (
+            "Damped Newton-Raphson from a far guess",
+            damped_newton_system(&system, &[8.0, 8.0], itermax, error_tol),
+        ),
+        (
+            "Broyden",
+            broyden_system(&system, &[1.5, 3.5], itermax, error_tol),
+        ),
+    ];
+    for (name, result) in results {
+        match result {
+            Ok(solution) => {
+                let formatted: Vec<String> = variables
+                    .iter()
+                    .zip(&solution)
+                    .map(|(var, value)| format!("{var} = {value:.8}"))
+                    .collect();
+                println!("{name}: {}", formatted.join(", "));
+            }
+            Err(e) => eprintln!("{name}: {e:?}"),
+        }
+    }
+}
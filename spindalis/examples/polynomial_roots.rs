@@ -0,0 +1,21 @@
+use spindalis::polynomials::{PolynomialTraits, SimplePolynomial};
+use spindalis::solvers::polynomial_roots;
+
+fn main() {
+    // (x - 1)^2 (x + 2) (x^2 + 4)
+    let polynomial = "x^5 + x^3 + 2x^2 - 12x + 8";
+    let parsed = SimplePolynomial::parse(polynomial).unwrap();
+
+    println!("The polynomial being evaluated is {polynomial}");
+    match polynomial_roots(&parsed, 1e-12, 500) {
+        Ok(roots) => {
+            for root in roots {
+                println!(
+                    "Root {:.5} with multiplicity {} (error bound {:.1e})",
+                    root.value, root.multiplicity, root.error
+                );
+            }
+        }
+        Err(e) => eprintln!("{e:?}"),
+    }
+}
@@ -6,30 +6,54 @@ pub mod regressors;
 pub mod solvers;
 pub mod utils;
 
+pub mod complex {
+    pub use spindalis_core::complex::Complex;
+}
+
+pub mod convergence {
+    pub use spindalis_core::convergence::{
+        Iteration, IterationCallback, SolveReport, SolverOptions, Termination,
+    };
+}
+
+pub mod objective {
+    pub use spindalis_core::objective::{Derivative, Objective, PolyObjective};
+}
+
 pub mod polynomials {
     pub use spindalis_core::polynomials as core;
     pub use spindalis_macros as macros;
 
     // Component Structs
     pub use core::Term;
+    pub use core::advanced::Expr;
+    pub use core::compiled::{CompiledExpr, Instruction};
     pub use core::structs::PolynomialTraits;
 
     // Polynomial Structs
     pub use core::structs::IntermediatePolynomial;
+    pub use core::structs::Polynomial;
     pub use core::structs::SimplePolynomial;
 
     // Error Enums
     pub use core::PolynomialError;
 
     // Parsers and evaluators
+    pub use core::advanced::eval_advanced_polynomial;
+    pub use core::advanced::eval_advanced_polynomial_complex;
+    pub use core::advanced::parse_advanced_polynomial;
     pub use core::intermediate::eval_intermediate_polynomial;
+    pub use core::intermediate::eval_intermediate_polynomial_complex;
     pub use core::intermediate::parse_intermediate_polynomial;
     pub use core::simple::eval_simple_polynomial;
+    pub use core::simple::eval_simple_polynomial_complex;
     pub use core::simple::parse_simple_polynomial;
+    pub use core::simplify::simplify_expr;
     pub use macros::{parse_intermediate_polynomial, parse_simple_polynomial};
 }
 
 pub mod derivatives {
+    pub use spindalis_core::derivatives::advanced::partial_derivative_advanced;
     pub use spindalis_core::derivatives::intermediate::partial_derivative;
     pub use spindalis_core::derivatives::simple::simple_derivative;
 }
@@ -39,18 +63,50 @@ pub mod integrals {
     pub use spindalis_core::integrals::IntegralError;
 
     // Functions
+    pub use spindalis_core::integrals::advanced_indefinite::indefinite_integral_advanced;
     pub use spindalis_core::integrals::intermediate_indefinite::indefinite_integral_intermediate;
     pub use spindalis_core::integrals::simple_indefinite::indefinite_integral_simple;
     pub use spindalis_core::integrals::univariate_definite::analytical_integral;
     pub use spindalis_core::integrals::univariate_definite::definite_integral;
     pub use spindalis_core::integrals::univariate_definite::romberg_definite;
+    pub use spindalis_core::integrals::univariate_definite::romberg_definite_with_options;
 }
 
 pub mod decomposition {
+    pub use crate::solvers::decomposition::cholesky::{cholesky_decomposition, cholesky_solve};
+    pub use crate::solvers::decomposition::ldl::{LDLResult, ldl_decomposition, ldl_solve};
     pub use crate::solvers::decomposition::lu::lu_decomposition;
-    pub use crate::solvers::decomposition::plu::lu_pivot_decomposition;
+    pub use crate::solvers::decomposition::plu::{
+        lu_pivot_decomposition, lu_pivot_decomposition_unchecked,
+    };
+    pub use crate::solvers::decomposition::qr::{
+        QRMode, qr_decomposition, qr_pivot_decomposition, qr_solve,
+    };
+    pub use crate::solvers::decomposition::svd::{SVDMode, SVDResult, svd_decomposition};
+}
+
+pub mod iterative {
+    pub use crate::solvers::iterative::bicgstab::bicgstab;
+    pub use crate::solvers::iterative::conjugate_gradient::conjugate_gradient;
+    pub use crate::solvers::iterative::gmres::gmres;
+    pub use crate::solvers::iterative::preconditioner::{
+        JacobiPreconditioner, Preconditioner, SsorPreconditioner,
+    };
+    pub use crate::solvers::iterative::stationary::{gauss_seidel, jacobi, sor};
+    pub use crate::solvers::iterative::{FnOperator, LinearOperator, RowOperator};
 }
 
 pub mod eigen {
-    pub use crate::solvers::eigen::power_method::power_method;
+    pub use crate::solvers::eigen::inverse_iteration::{
+        inverse_iteration, rayleigh_quotient_iteration, shifted_inverse_iteration,
+    };
+    pub use crate::solvers::eigen::power_method::{
+        Eigenpair, power_method, power_method_top_k, power_method_with_options,
+    };
+    pub use crate::solvers::eigen::qr_algorithm::{
+        ComplexEigenpair, eigen_decomposition, eigenvalues, hessenberg_eigen,
+    };
+    pub use crate::solvers::eigen::symmetric::{
+        SymmetricMethod, symmetric_eigen, symmetric_eigen_with_method,
+    };
 }
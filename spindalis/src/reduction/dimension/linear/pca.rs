@@ -19,27 +19,30 @@ fn _center_data(
     data: &Arr2D<f64>,
     std_type: Option<StdDevType>,
 ) -> Result<Arr2D<f64>, ReductionError> {
-    let mut result = data.clone();
-    let mut std = 1_f64;
-    for row in &mut result {
-        let len = row.len();
-        if len == 0 {
-            return Err(ReductionError::ShapeError(DimensionError::EmptyVector));
-        }
-        if let Some(std_kind) = std_type {
-            std = std_dev(row, std_kind);
-        }
-
+    if data.height == 0 {
+        return Ok(data.clone());
+    }
+    if data.width == 0 {
+        return Err(ReductionError::ShapeError(DimensionError::EmptyVector));
+    }
+    let mut means = Vec::with_capacity(data.height);
+    let mut stds = Vec::with_capacity(data.height);
+    for row in data {
+        let std = match std_type {
+            Some(std_kind) => std_dev(row, std_kind),
+            None => 1_f64,
+        };
         if std.is_nan() {
             return Err(ReductionError::ZeroMean);
         }
-
-        let mean = arith_mean(row);
-        for item in row {
-            *item = (*item - mean) / std;
-        }
+        means.push(arith_mean(row));
+        stds.push(std);
     }
-    Ok(result)
+
+    // Column vectors of per-variable statistics, broadcast across every feature
+    let means = Arr2D::from_flat(means, 0.0, data.height, 1)?;
+    let stds = Arr2D::from_flat(stds, 1.0, data.height, 1)?;
+    Ok(data.try_sub(&means)?.elementwise_div(&stds)?)
 }
 
 fn _variance(data: &[f64]) -> Result<f64, DimensionError> {
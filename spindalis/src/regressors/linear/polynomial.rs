@@ -1,32 +1,24 @@
 use crate::regressors::linear::{LinearModel, LinearRegressor};
-use crate::solvers::gaussian_elimination;
+use crate::solvers::decomposition::qr::qr_solve;
+use crate::utils::Arr2D;
 
 pub struct PolynomialRegression {
     pub order: usize,
 }
 
 impl LinearRegressor for PolynomialRegression {
-    #[allow(clippy::needless_range_loop)]
     fn fit(&self, x: &[f64], y: &[f64]) -> LinearModel {
         let order = self.order;
 
-        let mut matrix: Vec<Vec<f64>> = vec![vec![0.0; order + 1]; order + 1];
-        let mut rhs: Vec<f64> = vec![0.0; order + 1];
-        for i in 0..=order {
-            for j in 0..=i {
-                let k = i + j;
-                let poly_sum = x.iter().map(|x_i| x_i.powi(k as i32)).sum::<f64>();
-                matrix[i][j] = poly_sum;
-                matrix[j][i] = poly_sum;
+        // Least squares on the Vandermonde matrix with QR, which avoids the normal
+        // equations and the precision they lose at higher orders
+        let mut vandermonde = Arr2D::full(0.0, x.len(), order + 1);
+        for (row, &x_i) in vandermonde.rows_mut().zip(x) {
+            for (pow, item) in row.iter_mut().enumerate() {
+                *item = x_i.powi(pow as i32);
             }
-            let poly_sum = y
-                .iter()
-                .zip(x.iter())
-                .map(|(y_i, x_i)| y_i * x_i.powi(i as i32))
-                .sum::<f64>();
-            rhs[i] = poly_sum;
         }
-        let coefficients = gaussian_elimination(&matrix, &rhs, 1e-5).unwrap();
+        let coefficients = qr_solve(&vandermonde, y).unwrap();
 
         let length = y.len() as f64;
 
@@ -158,4 +150,29 @@ mod tests {
         assert!(approx_eq(model.std_err, 0.0, 1e-6));
         assert!(approx_eq(model.r2, 1.0, 1e-6));
     }
+
+    #[test]
+    fn high_order_recovery_order_7() {
+        // The normal equations square the already large condition number of this fit
+        let x: Vec<f64> = (0..=20).map(|i| i as f64).collect();
+        let expected = [2.0, -1.0, 0.5, 0.25, -0.1, 0.02, -0.001, 0.0001];
+        let y: Vec<f64> = x
+            .iter()
+            .map(|&x| {
+                expected
+                    .iter()
+                    .enumerate()
+                    .map(|(pow, c)| c * x.powi(pow as i32))
+                    .sum()
+            })
+            .collect();
+
+        let poly_regression = PolynomialRegression { order: 7 };
+        let model = poly_regression.fit(&x, &y);
+
+        for (coef, exp) in model.coefficients.iter().zip(expected) {
+            assert!(approx_eq(*coef, exp, 1e-4));
+        }
+        assert!(approx_eq(model.r2, 1.0, 1e-9));
+    }
 }
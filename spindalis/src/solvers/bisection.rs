@@ -1,18 +1,48 @@
-use crate::polynomials::PolynomialTraits;
-use crate::solvers::{Bounds, SolveMode, SolverError};
+use crate::convergence::{Iteration, SolveReport, SolverOptions, Termination};
+use crate::objective::Objective;
+use crate::solvers::{Bounds, SolveMode, SolverError, report_solution};
 
-pub fn bisection<P>(
-    polynomial: &P,
+// Largest |f(x)| accepted once the interval has converged
+const RESIDUAL_TOL: f64 = 1e-4;
+
+pub fn bisection<O>(
+    function: &O,
     bounds: Bounds,
     error_tol: f64,
     itermax: usize,
     mode: SolveMode,
 ) -> Result<f64, SolverError>
 where
-    P: PolynomialTraits,
+    O: Objective,
+{
+    let mut options = SolverOptions {
+        rel_tol: error_tol,
+        itermax,
+        ..Default::default()
+    };
+    report_solution(bisection_with_options(
+        function,
+        bounds,
+        mode,
+        &mut options,
+    )?)
+}
+
+/// Bisection with shared stopping criteria.
+/// The residual is the function value at the final midpoint. A result that meets
+/// the tolerances but leaves `|f(x)| >= 1e-4` ends with `Termination::NoConvergence`.
+pub fn bisection_with_options<O>(
+    function: &O,
+    bounds: Bounds,
+    mode: SolveMode,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<f64>, SolverError>
+where
+    O: Objective,
 {
     let mut iter = 0;
     let mut approx_err = 100.0;
+    let mut history = Vec::new();
     let mut lower_bound: f64 = bounds.lower;
     let mut x_curr: f64 = bounds.init;
     let mut upper_bound: f64 = bounds.upper;
@@ -21,13 +51,11 @@ where
         return Err(SolverError::XInitOutOfBounds);
     }
 
-    let polynomial = {
-        match mode {
-            SolveMode::Root => polynomial,
-            SolveMode::Extrema => &polynomial.derivate_univariate()?,
-        }
+    let function: &dyn Objective = match mode {
+        SolveMode::Root => function,
+        SolveMode::Extrema => &function.derivative_or_numerical()?,
     };
-    loop {
+    let (termination, residual) = loop {
         let old_x_curr = x_curr;
         x_curr = (lower_bound + upper_bound) / 2_f64;
         if x_curr != 0 as f64 {
@@ -36,7 +64,8 @@ where
                 (absv.abs() / x_curr) * 100_f64
             };
         }
-        let test = polynomial.eval_univariate(lower_bound)? * polynomial.eval_univariate(x_curr)?;
+        let f_curr = function.eval(x_curr)?;
+        let test = function.eval(lower_bound)? * f_curr;
         if test < 0 as f64 {
             upper_bound = x_curr;
         } else if test > 0 as f64 {
@@ -44,27 +73,39 @@ where
         } else {
             approx_err = 0.0;
         }
-        if approx_err.abs() < error_tol || iter >= itermax {
-            break;
-        }
         iter += 1;
-    }
-    if iter >= itermax {
-        return Err(SolverError::MaxIterationsReached);
-    }
+        history.push(approx_err);
+
+        let iteration = Iteration {
+            iteration: iter,
+            x: x_curr,
+            step: (x_curr - old_x_curr).abs(),
+            error: approx_err,
+            residual: f_curr,
+        };
+        if let Some(termination) = options.termination(&iteration) {
+            break (termination, f_curr);
+        }
+    };
 
-    let poss_sol = polynomial.eval_univariate(x_curr)?;
-    if poss_sol.abs() < 1e-4 {
-        Ok(x_curr)
+    let termination = if termination.is_converged() && residual.abs() >= RESIDUAL_TOL {
+        Termination::NoConvergence
     } else {
-        Err(SolverError::NoConvergence)
-    }
+        termination
+    };
+    Ok(SolveReport {
+        solution: x_curr,
+        iterations: iter,
+        residual,
+        history,
+        termination,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::polynomials::{IntermediatePolynomial, SimplePolynomial};
+    use crate::polynomials::{IntermediatePolynomial, PolynomialTraits, SimplePolynomial};
 
     const ERROR_TOL: f64 = 0.00001;
 
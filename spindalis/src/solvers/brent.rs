@@ -0,0 +1,206 @@
+use crate::objective::Objective;
+use crate::solvers::{Bounds, SolveMode, SolverError, bracket_values};
+
+/// Brent's method.
+/// Combines inverse quadratic interpolation and the secant step with bisection,
+/// falling back to bisection whenever the interpolated step would converge slower
+/// than halving the bracket.
+pub fn brent<O>(
+    function: &O,
+    bounds: Bounds,
+    error_tol: f64,
+    itermax: usize,
+    mode: SolveMode,
+) -> Result<f64, SolverError>
+where
+    O: Objective,
+{
+    let function: &dyn Objective = match mode {
+        SolveMode::Root => function,
+        SolveMode::Extrema => &function.derivative_or_numerical()?,
+    };
+    let (mut fa, mut fb) = bracket_values(function, &bounds)?;
+
+    // `b` is the best estimate, `a` the previous one and `c` the point that
+    // brackets the root together with `b`
+    let mut a = bounds.lower;
+    let mut b = bounds.upper;
+    let mut c = b;
+    let mut fc = fb;
+    let mut d = b - a;
+    let mut e = d;
+
+    let mut iter = 0;
+    loop {
+        if fb * fc > 0.0 {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        // Convert the percentage tolerance into an absolute step size around `b`
+        let tol =
+            2.0 * f64::EPSILON * b.abs() + 0.5 * (error_tol / 100.0 * b.abs()).max(f64::EPSILON);
+        let half = (c - b) / 2.0;
+        if half.abs() <= tol || fb == 0.0 {
+            break;
+        }
+        if iter >= itermax {
+            return Err(SolverError::MaxIterationsReached);
+        }
+
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                // Secant step
+                (2.0 * half * s, 1.0 - s)
+            } else {
+                // Inverse quadratic interpolation
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * half * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            let min1 = 3.0 * half * q - (tol * q).abs();
+            let min2 = (e * q).abs();
+            if 2.0 * p < min1.min(min2) {
+                e = d;
+                d = p / q;
+            } else {
+                d = half;
+                e = d;
+            }
+        } else {
+            d = half;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol { d } else { tol.copysign(half) };
+        fb = function.eval(b)?;
+        iter += 1;
+    }
+
+    let poss_sol = function.eval(b)?;
+    if poss_sol.abs() < 1e-4 {
+        Ok(b)
+    } else {
+        Err(SolverError::NoConvergence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::{
+        IntermediatePolynomial, Polynomial, PolynomialTraits, SimplePolynomial,
+    };
+
+    const ERROR_TOL: f64 = 0.00001;
+
+    fn approx_eq(a: f64, b: f64, tol: f64) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn test_root_success() {
+        let parsed = SimplePolynomial::parse("x^2 - 4").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 1.0,
+            upper: 3.0,
+        };
+        let root = brent(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 2.0, ERROR_TOL));
+    }
+
+    #[test]
+    fn test_root_success_intermediate_poly() {
+        let parsed = IntermediatePolynomial::parse("x^3 - 2x - 5").unwrap();
+        let bounds = Bounds {
+            lower: 2.0,
+            init: 2.0,
+            upper: 3.0,
+        };
+        let root = brent(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 2.0945514815, 1e-6));
+    }
+
+    #[test]
+    fn test_transcendental_root() {
+        let parsed = Polynomial::parse("e^x - 3").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 1.0,
+            upper: 2.0,
+        };
+        let root = brent(&parsed, bounds, 1e-10, 50, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 3.0_f64.ln(), 1e-10));
+    }
+
+    #[test]
+    fn test_flat_function() {
+        // Interpolation stalls near the flat triple root, so bisection steps take over
+        let parsed = SimplePolynomial::parse("x^3 - 3x^2 + 3x - 1").unwrap();
+        let bounds = Bounds {
+            lower: -2.0,
+            init: 0.0,
+            upper: 4.0,
+        };
+        let root = brent(&parsed, bounds, 1e-8, 200, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 1.0, 1e-4));
+    }
+
+    #[test]
+    fn test_extrema_success() {
+        // f(x) = x^3 - 3x has a minimum at x = 1
+        let parsed = SimplePolynomial::parse("x^3 - 3x").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 0.5,
+            upper: 3.0,
+        };
+        let min_x = brent(&parsed, bounds, ERROR_TOL, 100, SolveMode::Extrema).unwrap();
+        assert!(approx_eq(min_x, 1.0, ERROR_TOL));
+    }
+
+    #[test]
+    fn test_no_sign_change() {
+        let parsed = SimplePolynomial::parse("x^2 + 10").unwrap();
+        let bounds = Bounds {
+            lower: -1.0,
+            init: 0.0,
+            upper: 1.0,
+        };
+        let result = brent(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root);
+        assert!(matches!(result, Err(SolverError::NoConvergence)));
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        let parsed = SimplePolynomial::parse("x^5 - 3x + 1").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 0.5,
+            upper: 1.0,
+        };
+        let result = brent(&parsed, bounds, 1e-12, 1, SolveMode::Root);
+        assert!(matches!(result, Err(SolverError::MaxIterationsReached)));
+    }
+}
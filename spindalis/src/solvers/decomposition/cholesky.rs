@@ -0,0 +1,133 @@
+// Cholesky–Banachiewicz algorithm, computing L one row at a time
+use crate::solvers::SolverError;
+use crate::solvers::decomposition::check_symmetric;
+use crate::utils::{Arr2D, Arr2DError, back_substitution, forward_substitution};
+
+/// Cholesky decomposition of a symmetric positive definite matrix, returning the
+/// lower triangular `L` with `A = LLᵀ`.
+///
+/// Fails with `NotPositiveDefinite` at the first row whose pivot is not positive.
+/// Semidefinite matrices, such as covariance matrices of dependent variables, fail
+/// this way too; use `ldl_decomposition` for those.
+pub fn cholesky_decomposition<M>(matrix: M) -> Result<Arr2D<f64>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    check_symmetric(&matrix)?;
+
+    let size = matrix.height;
+    let mut lower: Arr2D<f64> = Arr2D::full(0.0, size, size);
+    for i in 0..size {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+            if i == j {
+                let pivot = matrix[i][i] - sum;
+                if pivot.is_nan() || pivot <= 0.0 {
+                    return Err(SolverError::NotPositiveDefinite { row: i });
+                }
+                lower[i][i] = pivot.sqrt();
+            } else {
+                lower[i][j] = (matrix[i][j] - sum) / lower[j][j];
+            }
+        }
+    }
+    Ok(lower)
+}
+
+/// Solves `Ax = b` given the Cholesky factor `L` of `A`, with a forward substitution
+/// for `Ly = b` then a back substitution for `Lᵀx = y`.
+pub fn cholesky_solve(lower: &Arr2D<f64>, rhs: &[f64]) -> Result<Vec<f64>, SolverError> {
+    let size = lower.height;
+    if lower.width != size {
+        return Err(SolverError::NonSquareMatrix);
+    }
+    if rhs.len() != size {
+        return Err(SolverError::NumArgumentsMismatch {
+            num_rows: size,
+            rhs_len: rhs.len(),
+        });
+    }
+    if size == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut y = vec![0.0; size];
+    forward_substitution(lower, size, rhs, &mut y);
+    let mut solution = vec![0.0; size];
+    back_substitution(&lower.transpose(), size, &y, &mut solution);
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_TOL: f64 = 1e-12;
+
+    #[test]
+    fn test_known_solution() {
+        let matrix = Arr2D::from(&[[4, 12, -16], [12, 37, -43], [-16, -43, 98]]);
+        let result = cholesky_decomposition(&matrix).unwrap();
+        let expected = Arr2D::from(&[[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_reconstruction() {
+        let matrix = Arr2D::from(&[
+            [3.556, -1.778, 0.0],
+            [-1.778, 3.556, -1.778],
+            [0.0, -1.778, 3.556],
+        ]);
+        let lower = cholesky_decomposition(&matrix).unwrap();
+        let rebuilt = &lower * &lower.transpose();
+        for (a, b) in rebuilt
+            .into_iter()
+            .flatten()
+            .zip(matrix.into_iter().flatten())
+        {
+            assert!((a - b).abs() < ERROR_TOL);
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let matrix = Arr2D::from(&[[4, 12, -16], [12, 37, -43], [-16, -43, 98]]);
+        let lower = cholesky_decomposition(&matrix).unwrap();
+        // Right hand side for x = [1, -2, 3]
+        let solution = cholesky_solve(&lower, &[-68.0, -191.0, 364.0]).unwrap();
+        for (x, e) in solution.iter().zip([1.0, -2.0, 3.0]) {
+            assert!((x - e).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_not_positive_definite() {
+        let indefinite = Arr2D::from(&[[1.0, 2.0], [2.0, 1.0]]);
+        assert!(matches!(
+            cholesky_decomposition(&indefinite),
+            Err(SolverError::NotPositiveDefinite { row: 1 })
+        ));
+        let semidefinite = Arr2D::from(&[[1.0, 1.0], [1.0, 1.0]]);
+        assert!(matches!(
+            cholesky_decomposition(&semidefinite),
+            Err(SolverError::NotPositiveDefinite { row: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_non_symmetric_and_non_square() {
+        let matrix = Arr2D::from(&[[4.0, 1.0], [2.0, 3.0]]);
+        assert!(matches!(
+            cholesky_decomposition(&matrix),
+            Err(SolverError::NonSymmetricMatrix)
+        ));
+        let matrix = Arr2D::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert!(matches!(
+            cholesky_decomposition(&matrix),
+            Err(SolverError::NonSquareMatrix)
+        ));
+    }
+}
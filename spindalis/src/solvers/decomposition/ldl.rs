@@ -0,0 +1,275 @@
+// Bunch–Kaufman diagonal pivoting, following Golub and Van Loan, Matrix Computations,
+// section 4.4. Pivots are 1×1 or 2×2 blocks chosen so that the multipliers in L stay
+// bounded, which keeps the factorization stable for indefinite matrices as well.
+use crate::solvers::SolverError;
+use crate::solvers::decomposition::check_symmetric;
+use crate::utils::{Arr2D, Arr2DError, back_substitution, forward_substitution};
+
+pub type LDLResult = (Arr2D<f64>, Arr2D<f64>, Arr2D<f64>);
+
+// (1 + sqrt(17)) / 8, which minimises the worst-case element growth
+const ALPHA: f64 = 0.6403882032022076;
+
+/// LDLᵀ decomposition of a symmetric matrix with symmetric pivoting, returning
+/// `(L, D, P)` with `PAPᵀ = LDLᵀ`.
+///
+/// L is unit lower triangular and D is block diagonal with 1×1 and 2×2 blocks.
+/// Unlike Cholesky this works for indefinite and semidefinite matrices; a singular
+/// matrix gives zeros on the diagonal of D.
+pub fn ldl_decomposition<M>(matrix: M) -> Result<LDLResult, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let mut work: Arr2D<f64> = matrix.try_into()?;
+    check_symmetric(&work)?;
+
+    let size = work.height;
+    let mut lower: Arr2D<f64> = Arr2D::identity(size);
+    let mut diagonal: Arr2D<f64> = Arr2D::full(0.0, size, size);
+    let mut permutation: Arr2D<f64> = Arr2D::identity(size);
+
+    let mut k = 0;
+    while k < size {
+        // Largest entry below the diagonal in column k
+        let (row, lambda) =
+            ((k + 1)..size)
+                .map(|i| (i, work[i][k].abs()))
+                .fold(
+                    (k, 0.0),
+                    |best, cur| if cur.1 > best.1 { cur } else { best },
+                );
+        let a_kk = work[k][k].abs();
+
+        let block = if lambda == 0.0 || a_kk >= ALPHA * lambda {
+            1
+        } else {
+            // Largest off-diagonal entry in row `row` of the trailing matrix
+            let sigma = (k..size)
+                .filter(|&j| j != row)
+                .map(|j| work[row][j].abs())
+                .fold(0.0, f64::max);
+            if a_kk * sigma >= ALPHA * lambda * lambda {
+                1
+            } else if work[row][row].abs() >= ALPHA * sigma {
+                symmetric_swap(&mut work, &mut lower, &mut permutation, k, row);
+                1
+            } else {
+                symmetric_swap(&mut work, &mut lower, &mut permutation, k + 1, row);
+                2
+            }
+        };
+
+        if block == 1 {
+            let pivot = work[k][k];
+            diagonal[k][k] = pivot;
+            // A zero column needs no elimination
+            if pivot != 0.0 {
+                for i in (k + 1)..size {
+                    lower[i][k] = work[i][k] / pivot;
+                }
+                for i in (k + 1)..size {
+                    for j in (k + 1)..=i {
+                        work[i][j] -= lower[i][k] * work[j][k];
+                        work[j][i] = work[i][j];
+                    }
+                }
+            }
+        } else {
+            let (a, b, c) = (work[k][k], work[k + 1][k], work[k + 1][k + 1]);
+            diagonal[k][k] = a;
+            diagonal[k + 1][k] = b;
+            diagonal[k][k + 1] = b;
+            diagonal[k + 1][k + 1] = c;
+            // b dominates the block, so the determinant is non-zero
+            let det = a * c - b * b;
+            for i in (k + 2)..size {
+                let (x, y) = (work[i][k], work[i][k + 1]);
+                lower[i][k] = (x * c - y * b) / det;
+                lower[i][k + 1] = (y * a - x * b) / det;
+            }
+            for i in (k + 2)..size {
+                for j in (k + 2)..=i {
+                    work[i][j] -= lower[i][k] * work[j][k] + lower[i][k + 1] * work[j][k + 1];
+                    work[j][i] = work[i][j];
+                }
+            }
+        }
+        k += block;
+    }
+
+    Ok((lower, diagonal, permutation))
+}
+
+/// Solves `Ax = b` given the factors `(L, D, P)` from `ldl_decomposition`.
+/// Fails with `SingularMatrix` if D, and so A, is singular.
+pub fn ldl_solve(factors: &LDLResult, rhs: &[f64]) -> Result<Vec<f64>, SolverError> {
+    let (lower, diagonal, permutation) = factors;
+    let size = lower.height;
+    if rhs.len() != size {
+        return Err(SolverError::NumArgumentsMismatch {
+            num_rows: size,
+            rhs_len: rhs.len(),
+        });
+    }
+    if size == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Pb
+    let permuted: Vec<f64> = permutation
+        .rows()
+        .map(|row| row.iter().zip(rhs).map(|(p, b)| p * b).sum())
+        .collect();
+    let mut y = vec![0.0; size];
+    forward_substitution(lower, size, &permuted, &mut y);
+
+    // Solve each 1×1 or 2×2 block of D
+    let mut z = vec![0.0; size];
+    let mut i = 0;
+    while i < size {
+        if i + 1 < size && diagonal[i + 1][i] != 0.0 {
+            let (a, b, c) = (diagonal[i][i], diagonal[i + 1][i], diagonal[i + 1][i + 1]);
+            let det = a * c - b * b;
+            z[i] = (c * y[i] - b * y[i + 1]) / det;
+            z[i + 1] = (a * y[i + 1] - b * y[i]) / det;
+            i += 2;
+        } else {
+            if diagonal[i][i] == 0.0 {
+                return Err(SolverError::SingularMatrix);
+            }
+            z[i] = y[i] / diagonal[i][i];
+            i += 1;
+        }
+    }
+
+    let mut w = vec![0.0; size];
+    back_substitution(&lower.transpose(), size, &z, &mut w);
+    // x = Pᵀw
+    let mut solution = vec![0.0; size];
+    for (i, row) in permutation.rows().enumerate() {
+        for (j, p) in row.iter().enumerate() {
+            solution[j] += p * w[i];
+        }
+    }
+    Ok(solution)
+}
+
+// Swaps rows and columns `a` and `b` of the trailing matrix, along with the rows of
+// the multipliers already in L and the permutation
+fn symmetric_swap(
+    work: &mut Arr2D<f64>,
+    lower: &mut Arr2D<f64>,
+    permutation: &mut Arr2D<f64>,
+    a: usize,
+    b: usize,
+) {
+    if a == b {
+        return;
+    }
+    work.swap_rows(a, b);
+    for row in work.rows_mut() {
+        row.swap(a, b);
+    }
+    permutation.swap_rows(a, b);
+    // Only the columns before the current pivot have been filled in
+    let done = a.min(b);
+    for j in 0..done {
+        let tmp = lower[a][j];
+        lower[a][j] = lower[b][j];
+        lower[b][j] = tmp;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_TOL: f64 = 1e-10;
+
+    fn assert_factorization(matrix: &Arr2D<f64>) -> LDLResult {
+        let factors = ldl_decomposition(matrix).unwrap();
+        let (l, d, p) = &factors;
+        let lhs = &(p * matrix) * &p.transpose();
+        let rhs = &(l * d) * &l.transpose();
+        for (a, b) in lhs.into_iter().flatten().zip(rhs.into_iter().flatten()) {
+            assert!((a - b).abs() < ERROR_TOL, "{lhs} != {rhs}");
+        }
+        for i in 0..l.height {
+            assert_eq!(l[i][i], 1.0);
+            for j in (i + 1)..l.width {
+                assert_eq!(l[i][j], 0.0);
+            }
+        }
+        factors
+    }
+
+    #[test]
+    fn test_positive_definite() {
+        let matrix = Arr2D::from(&[
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+        let (_, d, _) = assert_factorization(&matrix);
+        for i in 0..3 {
+            assert!(d[i][i] > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_indefinite_needs_2x2_pivot() {
+        // Zero diagonal, so every 1×1 pivot would be zero
+        let matrix = Arr2D::from(&[[0.0, 1.0, 2.0], [1.0, 0.0, 3.0], [2.0, 3.0, 0.0]]);
+        let factors = assert_factorization(&matrix);
+        let (_, d, _) = &factors;
+        assert!((0..2).any(|i| d[i + 1][i] != 0.0));
+
+        // Right hand side for x = [1, 2, 3]
+        let solution = ldl_solve(&factors, &[8.0, 10.0, 8.0]).unwrap();
+        for (x, e) in solution.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((x - e).abs() < ERROR_TOL);
+        }
+    }
+
+    #[test]
+    fn test_semidefinite() {
+        // Covariance of perfectly correlated variables
+        let matrix = Arr2D::from(&[[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 0.0, 3.0]]);
+        let factors = assert_factorization(&matrix);
+        let (_, d, _) = &factors;
+        assert!((0..3).any(|i| d[i][i].abs() < ERROR_TOL));
+        assert!(matches!(
+            ldl_solve(&factors, &[1.0, 2.0, 3.0]),
+            Err(SolverError::SingularMatrix)
+        ));
+    }
+
+    #[test]
+    fn test_solve_larger() {
+        let matrix = Arr2D::from(&[
+            [2.0, -1.0, 0.0, 3.0],
+            [-1.0, -4.0, 1.0, 0.5],
+            [0.0, 1.0, 0.1, 2.0],
+            [3.0, 0.5, 2.0, -1.0],
+        ]);
+        let factors = assert_factorization(&matrix);
+        let expected = [1.0, -1.0, 2.0, 0.5];
+        let rhs: Vec<f64> = matrix
+            .rows()
+            .map(|row| row.iter().zip(expected).map(|(a, x)| a * x).sum())
+            .collect();
+        let solution = ldl_solve(&factors, &rhs).unwrap();
+        for (x, e) in solution.iter().zip(expected) {
+            assert!((x - e).abs() < ERROR_TOL);
+        }
+    }
+
+    #[test]
+    fn test_non_symmetric() {
+        let matrix = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        assert!(matches!(
+            ldl_decomposition(&matrix),
+            Err(SolverError::NonSymmetricMatrix)
+        ));
+    }
+}
@@ -1,2 +1,32 @@
+pub mod cholesky;
+pub mod ldl;
 pub mod lu;
 pub mod plu;
+pub mod qr;
+pub mod svd;
+
+use crate::solvers::SolverError;
+use crate::utils::Arr2D;
+
+// Entries further apart than this (relative to the largest entry) make a matrix
+// non-symmetric for the symmetric factorizations
+const SYMMETRY_TOL: f64 = 1e-10;
+
+// Shared check for the symmetric factorizations and eigen solvers
+pub(crate) fn check_symmetric(matrix: &Arr2D<f64>) -> Result<(), SolverError> {
+    if matrix.height != matrix.width {
+        return Err(SolverError::NonSquareMatrix);
+    }
+    let scale = matrix
+        .into_iter()
+        .flatten()
+        .fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    for i in 0..matrix.height {
+        for j in 0..i {
+            if (matrix[i][j] - matrix[j][i]).abs() > SYMMETRY_TOL * scale.max(1.0) {
+                return Err(SolverError::NonSymmetricMatrix);
+            }
+        }
+    }
+    Ok(())
+}
@@ -13,7 +13,20 @@ pub fn lu_pivot_decomposition<M>(matrix: M) -> Result<PLUResult, SolverError>
 where
     M: TryInto<Arr2D<f64>, Error = Arr2DError>,
 {
-    let matrix: Arr2D<f64> = matrix.try_into()?;
+    factorize(matrix.try_into()?, true)
+}
+
+/// Same as `lu_pivot_decomposition`, but without the singularity check. Small pivots
+/// are kept, and a column that is already zero below the diagonal is skipped, which
+/// leaves a zero on the diagonal of U. Only fails for non-square matrices.
+pub fn lu_pivot_decomposition_unchecked<M>(matrix: M) -> Result<PLUResult, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    factorize(matrix.try_into()?, false)
+}
+
+fn factorize(matrix: Arr2D<f64>, check_singular: bool) -> Result<PLUResult, SolverError> {
     if matrix.height != matrix.width {
         return Err(SolverError::NonSquareMatrix);
     }
@@ -42,14 +55,18 @@ where
             permutation.swap_rows(pivot_row, i);
         }
         // Check for singularity BEFORE division
-        if lu[i][i].abs() < f64::EPSILON {
+        if check_singular && lu[i][i].abs() < f64::EPSILON {
             return Err(SolverError::SingularMatrix);
         }
+        if lu[i][i] == 0.0 {
+            // Nothing left to eliminate in this column
+            continue;
+        }
 
         for k in (i + 1)..size {
             // Calculate the lower triangular matrix
             // Calculates multiplier needed to eliminate the element lu[k][i]
-            lu[k][i] /= lu[i][i]; // division by 0 stopped by singularity check
+            lu[k][i] /= lu[i][i]; // division by 0 stopped by the check above
 
             // Elimination step
             // lu[k][j] will form part of the final upper triangular matrix
@@ -126,4 +143,25 @@ mod tests {
         let result = lu_pivot_decomposition(&matrix).unwrap();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_unchecked_small_pivots() {
+        let matrix = &Arr2D::<f64>::identity(2) * 1e-17;
+        assert!(matches!(
+            lu_pivot_decomposition(&matrix),
+            Err(SolverError::SingularMatrix)
+        ));
+        let (lower, upper, permutation) = lu_pivot_decomposition_unchecked(&matrix).unwrap();
+        assert_eq!(lower, Arr2D::identity(2));
+        assert_eq!(upper, matrix);
+        assert_eq!(permutation, Arr2D::identity(2));
+    }
+
+    #[test]
+    fn test_unchecked_singular() {
+        let matrix = Arr2D::from(&[[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]]);
+        let (lower, upper, permutation) = lu_pivot_decomposition_unchecked(&matrix).unwrap();
+        assert_eq!(upper[1][1], 0.0);
+        assert_eq!(&permutation * &matrix, &lower * &upper);
+    }
 }
@@ -0,0 +1,331 @@
+// Householder QR decomposition, following Golub and Van Loan, Matrix Computations,
+// sections 5.1 to 5.4. Each column below the diagonal is zeroed with a reflection
+// H = I - 2vvᵀ, and Q is the product of the reflections.
+use crate::solvers::SolverError;
+use crate::utils::{Arr2D, Arr2DError, back_substitution};
+
+pub type QRResult = (Arr2D<f64>, Arr2D<f64>);
+pub type PQRResult = (Arr2D<f64>, Arr2D<f64>, Arr2D<f64>);
+
+// Diagonal entries of R smaller than this (relative to the largest) mark the
+// numerical rank in `qr_solve`
+const RANK_TOL: f64 = 1e-12;
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum QRMode {
+    /// Square m×m Q and m×n R
+    Full,
+    /// m×k Q and k×n R, where k = min(m, n)
+    Thin,
+}
+
+// Reflections and the reduced matrix from a Householder factorisation.
+// `reflectors[k]` acts on rows k.. and is `None` when column k was already reduced.
+struct Householder {
+    reflectors: Vec<Option<Vec<f64>>>,
+    reduced: Arr2D<f64>,
+    columns: Vec<usize>,
+}
+
+/// Householder QR decomposition, returning `(Q, R)` with `A = QR`.
+/// Q has orthonormal columns and R is upper triangular.
+pub fn qr_decomposition<M>(matrix: M, mode: QRMode) -> Result<QRResult, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    let factors = householder(matrix, false);
+    Ok((factors.q(mode), factors.r(mode)))
+}
+
+/// Householder QR decomposition with column pivoting, returning `(Q, R, P)` with
+/// `AP = QR`. Columns are taken in order of largest remaining norm, so the diagonal
+/// of R is non-increasing in magnitude and reveals the rank of A.
+pub fn qr_pivot_decomposition<M>(matrix: M, mode: QRMode) -> Result<PQRResult, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    let factors = householder(matrix, true);
+    let mut permutation = Arr2D::full(0.0, factors.columns.len(), factors.columns.len());
+    for (j, &col) in factors.columns.iter().enumerate() {
+        permutation[col][j] = 1.0;
+    }
+    Ok((factors.q(mode), factors.r(mode), permutation))
+}
+
+/// Least squares solution of `Ax = b`, minimising `||Ax - b||`.
+///
+/// Solves overdetermined systems without forming the normal equations `AᵀAx = Aᵀb`,
+/// which square the condition number of A. Column pivoting finds the numerical rank,
+/// so rank-deficient and underdetermined systems get a basic solution with the
+/// remaining unknowns set to zero.
+pub fn qr_solve<M>(matrix: M, rhs: &[f64]) -> Result<Vec<f64>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    if matrix.height != rhs.len() {
+        return Err(SolverError::NumArgumentsMismatch {
+            num_rows: matrix.height,
+            rhs_len: rhs.len(),
+        });
+    }
+    let width = matrix.width;
+    let factors = householder(matrix, true);
+
+    // Qᵀb, applying the reflections directly instead of forming Q
+    let mut qtb = rhs.to_vec();
+    for (k, reflector) in factors.reflectors.iter().enumerate() {
+        if let Some(v) = reflector {
+            let dot: f64 = v.iter().zip(&qtb[k..]).map(|(vi, bi)| vi * bi).sum();
+            for (vi, bi) in v.iter().zip(&mut qtb[k..]) {
+                *bi -= 2.0 * dot * vi;
+            }
+        }
+    }
+
+    let r = &factors.reduced;
+    let steps = factors.reflectors.len();
+    let largest = if steps > 0 { r[0][0].abs() } else { 0.0 };
+    let rank = (0..steps)
+        .take_while(|&i| r[i][i].abs() > RANK_TOL * largest)
+        .count();
+    if rank == 0 {
+        return Err(SolverError::SingularMatrix);
+    }
+
+    let mut basic = vec![0.0; rank];
+    back_substitution(r, rank, &qtb[..rank], &mut basic);
+    let mut solution = vec![0.0; width];
+    for (j, value) in basic.into_iter().enumerate() {
+        solution[factors.columns[j]] = value;
+    }
+    Ok(solution)
+}
+
+fn householder(mut reduced: Arr2D<f64>, pivot: bool) -> Householder {
+    let (height, width) = reduced.shape();
+    let steps = height.min(width);
+    let mut columns: Vec<usize> = (0..width).collect();
+    let mut reflectors = Vec::with_capacity(steps);
+
+    for k in 0..steps {
+        if pivot {
+            let norm = |j: usize| (k..height).map(|i| reduced[i][j].powi(2)).sum::<f64>();
+            let best = (k..width).fold(k, |best, j| if norm(j) > norm(best) { j } else { best });
+            if best != k {
+                for i in 0..height {
+                    let row = &mut reduced[i];
+                    row.swap(k, best);
+                }
+                columns.swap(k, best);
+            }
+        }
+
+        // The last row of a wide or square matrix is already reduced
+        if k + 1 == height {
+            reflectors.push(None);
+            continue;
+        }
+        let norm = (k..height)
+            .map(|i| reduced[i][k].powi(2))
+            .sum::<f64>()
+            .sqrt();
+        if norm == 0.0 {
+            reflectors.push(None);
+            continue;
+        }
+        // Reflect onto -sign(x0)||x|| e1 to avoid cancellation in v = x - alpha e1
+        let alpha = if reduced[k][k] >= 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = (k..height).map(|i| reduced[i][k]).collect();
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|vi| vi * vi).sum::<f64>().sqrt();
+        for vi in &mut v {
+            *vi /= v_norm;
+        }
+
+        for j in (k + 1)..width {
+            let dot: f64 = (k..height).map(|i| v[i - k] * reduced[i][j]).sum();
+            for i in k..height {
+                reduced[i][j] -= 2.0 * dot * v[i - k];
+            }
+        }
+        reduced[k][k] = alpha;
+        for i in (k + 1)..height {
+            reduced[i][k] = 0.0;
+        }
+        reflectors.push(Some(v));
+    }
+
+    Householder {
+        reflectors,
+        reduced,
+        columns,
+    }
+}
+
+impl Householder {
+    fn q(&self, mode: QRMode) -> Arr2D<f64> {
+        let height = self.reduced.height;
+        let width = match mode {
+            QRMode::Full => height,
+            QRMode::Thin => self.reflectors.len(),
+        };
+        let mut q = Arr2D::full(0.0, height, width);
+        for i in 0..width {
+            q[i][i] = 1.0;
+        }
+        // Q = H0 H1 ... applied to the leading columns of the identity
+        for (k, reflector) in self.reflectors.iter().enumerate().rev() {
+            let Some(v) = reflector else { continue };
+            for j in 0..width {
+                let dot: f64 = (k..height).map(|i| v[i - k] * q[i][j]).sum();
+                for i in k..height {
+                    q[i][j] -= 2.0 * dot * v[i - k];
+                }
+            }
+        }
+        q
+    }
+
+    fn r(&self, mode: QRMode) -> Arr2D<f64> {
+        match mode {
+            QRMode::Full => self.reduced.clone(),
+            QRMode::Thin => {
+                let (height, width) = (self.reflectors.len(), self.reduced.width);
+                let mut r = Arr2D::full(0.0, height, width);
+                for i in 0..height {
+                    r[i].copy_from_slice(&self.reduced[i]);
+                }
+                r
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Rounding;
+
+    const ERROR_TOL: f64 = 1e-10;
+
+    fn assert_close(result: &Arr2D<f64>, expected: &Arr2D<f64>) {
+        assert_eq!(result.shape(), expected.shape());
+        for (a, b) in result
+            .into_iter()
+            .flatten()
+            .zip(expected.into_iter().flatten())
+        {
+            assert!((a - b).abs() < ERROR_TOL, "{result} != {expected}");
+        }
+    }
+
+    fn assert_upper_triangular(r: &Arr2D<f64>) {
+        for i in 0..r.height {
+            for j in 0..i.min(r.width) {
+                assert_eq!(r[i][j], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_solution() {
+        let matrix = Arr2D::from(&[[12, -51, 4], [6, 167, -68], [-4, 24, -41]]);
+        let (q, r) = qr_decomposition(&matrix, QRMode::Full).unwrap();
+        let r_exp = Arr2D::from(&[[-14.0, -21.0, 14.0], [0.0, -175.0, 70.0], [0.0, 0.0, -35.0]]);
+
+        assert_close(&r, &r_exp);
+        assert_close(&(&q * &r), &Arr2D::try_from(&matrix).unwrap());
+        assert_close(&(&q.transpose() * &q), &Arr2D::identity(3));
+    }
+
+    #[test]
+    fn test_full_and_thin_shapes() {
+        let matrix = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+
+        let (q, r) = qr_decomposition(&matrix, QRMode::Full).unwrap();
+        assert_eq!((q.shape(), r.shape()), ((4, 4), (4, 2)));
+        assert_upper_triangular(&r);
+        assert_close(&(&q * &r), &matrix);
+        assert_close(&(&q.transpose() * &q), &Arr2D::identity(4));
+
+        let (q, r) = qr_decomposition(&matrix, QRMode::Thin).unwrap();
+        assert_eq!((q.shape(), r.shape()), ((4, 2), (2, 2)));
+        assert_close(&(&q * &r), &matrix);
+        assert_close(&(&q.transpose() * &q), &Arr2D::identity(2));
+    }
+
+    #[test]
+    fn test_wide_matrix() {
+        let matrix = Arr2D::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let (q, r) = qr_decomposition(&matrix, QRMode::Thin).unwrap();
+        assert_eq!((q.shape(), r.shape()), ((2, 2), (2, 3)));
+        assert_upper_triangular(&r);
+        assert_close(&(&q * &r), &matrix);
+    }
+
+    #[test]
+    fn test_column_pivoting() {
+        // The third column is the sum of the first two
+        let matrix = Arr2D::from(&[
+            [1.0, 0.0, 1.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 1.0, 2.0],
+            [2.0, 0.0, 2.0],
+        ]);
+        let (q, r, p) = qr_pivot_decomposition(&matrix, QRMode::Thin).unwrap();
+
+        assert_close(&(&q * &r), &(&matrix * &p));
+        assert!(r[0][0].abs() >= r[1][1].abs());
+        assert!(r[2][2].abs() < ERROR_TOL);
+        // The largest column comes first
+        assert_eq!(p.round_to_decimal(0)[2][0], 1.0);
+    }
+
+    #[test]
+    fn test_qr_solve_square() {
+        let matrix = Arr2D::from(&[[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+        let solution = qr_solve(&matrix, &[8.0, -11.0, -3.0]).unwrap();
+        for (x, e) in solution.iter().zip([2.0, 3.0, -1.0]) {
+            assert!((x - e).abs() < ERROR_TOL);
+        }
+    }
+
+    #[test]
+    fn test_qr_solve_overdetermined() {
+        // Least squares line through (0, 1), (1, 3), (2, 4), (3, 4)
+        let matrix = Arr2D::from(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let solution = qr_solve(&matrix, &[1.0, 3.0, 4.0, 4.0]).unwrap();
+        assert!((solution[0] - 1.5).abs() < ERROR_TOL);
+        assert!((solution[1] - 1.0).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_qr_solve_rank_deficient() {
+        let matrix = Arr2D::from(&[[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        let rhs = [2.0, 4.0, 6.0];
+        let solution = qr_solve(&matrix, &rhs).unwrap();
+        // Any solution with x + 2y = 2 fits exactly
+        assert!((solution[0] + 2.0 * solution[1] - 2.0).abs() < ERROR_TOL);
+        assert!(solution.contains(&0.0));
+    }
+
+    #[test]
+    fn test_qr_solve_errors() {
+        let matrix = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        assert!(matches!(
+            qr_solve(&matrix, &[1.0]),
+            Err(SolverError::NumArgumentsMismatch {
+                num_rows: 2,
+                rhs_len: 1
+            })
+        ));
+        let zero = Arr2D::full(0.0, 2, 2);
+        assert!(matches!(
+            qr_solve(&zero, &[1.0, 1.0]),
+            Err(SolverError::SingularMatrix)
+        ));
+    }
+}
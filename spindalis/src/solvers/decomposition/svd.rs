@@ -0,0 +1,440 @@
+// Golub–Reinsch SVD: Householder bidiagonalization (Golub–Kahan) followed by implicit
+// shifted QR on the bidiagonal matrix. Follows Golub and Reinsch, "Singular value
+// decomposition and least squares solutions" (1970), as laid out in Numerical
+// Recipes, section 2.6.
+use crate::solvers::SolverError;
+use crate::solvers::decomposition::qr::{QRMode, qr_decomposition};
+use crate::utils::{Arr2D, Arr2DError};
+
+pub type SVDResult = (Arr2D<f64>, Vec<f64>, Arr2D<f64>);
+
+// QR sweeps allowed for each singular value before giving up
+const MAX_SWEEPS: usize = 75;
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum SVDMode {
+    /// Square m×m U and n×n Vᵀ
+    Full,
+    /// m×k U and k×n Vᵀ, where k = min(m, n)
+    Thin,
+}
+
+/// Singular value decomposition, returning `(U, S, Vᵀ)` with `A = U diag(S) Vᵀ`.
+///
+/// The min(m, n) singular values in S are non-negative and sorted in decreasing order,
+/// and the columns of U and V are orthonormal. Inputs can be tall or wide.
+pub fn svd_decomposition<M>(matrix: M, mode: SVDMode) -> Result<SVDResult, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    let (height, width) = matrix.shape();
+    if height.min(width) == 0 {
+        return Ok(match mode {
+            SVDMode::Full => (Arr2D::identity(height), Vec::new(), Arr2D::identity(width)),
+            SVDMode::Thin => (
+                Arr2D::full(0.0, height, 0),
+                Vec::new(),
+                Arr2D::full(0.0, 0, width),
+            ),
+        });
+    }
+
+    // Golub–Reinsch needs at least as many rows as columns, so wide matrices are
+    // decomposed as Aᵀ = V S Uᵀ
+    let (u, singular_values, v) = if height >= width {
+        golub_reinsch(matrix)?
+    } else {
+        let (v, singular_values, u) = golub_reinsch(matrix.transpose())?;
+        (u, singular_values, v)
+    };
+
+    match mode {
+        SVDMode::Thin => Ok((u, singular_values, v.transpose())),
+        SVDMode::Full => Ok((
+            complete_basis(&u)?,
+            singular_values,
+            complete_basis(&v)?.transpose(),
+        )),
+    }
+}
+
+// Thin SVD of a matrix with height >= width, returning (U, S, V)
+fn golub_reinsch(mut a: Arr2D<f64>) -> Result<SVDResult, SolverError> {
+    let (m, n) = a.shape();
+    let mut w = vec![0.0; n];
+    let mut v = Arr2D::full(0.0, n, n);
+    let mut rv1 = vec![0.0; n];
+
+    // Householder reduction to bidiagonal form, with the diagonal in w and the
+    // superdiagonal in rv1
+    let (mut g, mut scale, mut anorm) = (0.0_f64, 0.0_f64, 0.0_f64);
+    let mut l = 0;
+    for i in 0..n {
+        l = i + 1;
+        rv1[i] = scale * g;
+        g = 0.0;
+        scale = 0.0;
+        let mut s = 0.0;
+        for k in i..m {
+            scale += a[k][i].abs();
+        }
+        if scale != 0.0 {
+            for k in i..m {
+                a[k][i] /= scale;
+                s += a[k][i] * a[k][i];
+            }
+            let f = a[i][i];
+            g = -s.sqrt().copysign(f);
+            let h = f * g - s;
+            a[i][i] = f - g;
+            for j in l..n {
+                let s: f64 = (i..m).map(|k| a[k][i] * a[k][j]).sum();
+                let f = s / h;
+                for k in i..m {
+                    a[k][j] += f * a[k][i];
+                }
+            }
+            for k in i..m {
+                a[k][i] *= scale;
+            }
+        }
+        w[i] = scale * g;
+
+        g = 0.0;
+        scale = 0.0;
+        let mut s = 0.0;
+        if i + 1 != n {
+            for k in l..n {
+                scale += a[i][k].abs();
+            }
+            if scale != 0.0 {
+                for k in l..n {
+                    a[i][k] /= scale;
+                    s += a[i][k] * a[i][k];
+                }
+                let f = a[i][l];
+                g = -s.sqrt().copysign(f);
+                let h = f * g - s;
+                a[i][l] = f - g;
+                for k in l..n {
+                    rv1[k] = a[i][k] / h;
+                }
+                for j in l..m {
+                    let s: f64 = (l..n).map(|k| a[j][k] * a[i][k]).sum();
+                    for k in l..n {
+                        a[j][k] += s * rv1[k];
+                    }
+                }
+                for k in l..n {
+                    a[i][k] *= scale;
+                }
+            }
+        }
+        anorm = anorm.max(w[i].abs() + rv1[i].abs());
+    }
+
+    // Accumulate the right-hand transformations into V
+    for i in (0..n).rev() {
+        if i + 1 < n {
+            if g != 0.0 {
+                // Double division avoids a possible underflow
+                for j in l..n {
+                    v[j][i] = (a[i][j] / a[i][l]) / g;
+                }
+                for j in l..n {
+                    let s: f64 = (l..n).map(|k| a[i][k] * v[k][j]).sum();
+                    for k in l..n {
+                        v[k][j] += s * v[k][i];
+                    }
+                }
+            }
+            for j in l..n {
+                v[i][j] = 0.0;
+                v[j][i] = 0.0;
+            }
+        }
+        v[i][i] = 1.0;
+        g = rv1[i];
+        l = i;
+    }
+
+    // Accumulate the left-hand transformations, turning `a` into U
+    for i in (0..n).rev() {
+        let l = i + 1;
+        let g = w[i];
+        for j in l..n {
+            a[i][j] = 0.0;
+        }
+        if g != 0.0 {
+            let g = 1.0 / g;
+            for j in l..n {
+                let s: f64 = (l..m).map(|k| a[k][i] * a[k][j]).sum();
+                let f = (s / a[i][i]) * g;
+                for k in i..m {
+                    a[k][j] += f * a[k][i];
+                }
+            }
+            for j in i..m {
+                a[j][i] *= g;
+            }
+        } else {
+            for j in i..m {
+                a[j][i] = 0.0;
+            }
+        }
+        a[i][i] += 1.0;
+    }
+
+    // Diagonalize the bidiagonal matrix with implicit shifted QR, one singular value
+    // at a time from the bottom
+    let negligible = |x: f64| x.abs() <= f64::EPSILON * anorm;
+    for k in (0..n).rev() {
+        let mut sweeps = 0;
+        loop {
+            // Find the start l of the unreduced block ending at k. rv1[0] is always
+            // zero, so the search stops at l = 0 at the latest.
+            let mut l = k;
+            let mut cancel = true;
+            while l > 0 {
+                if negligible(rv1[l]) {
+                    cancel = false;
+                    break;
+                }
+                if negligible(w[l - 1]) {
+                    break;
+                }
+                l -= 1;
+            }
+            if l == 0 {
+                cancel = false;
+            }
+
+            // w[l - 1] is negligible, so rv1[l] is chased out with Givens rotations
+            if cancel {
+                let nm = l - 1;
+                let (mut c, mut s) = (0.0, 1.0);
+                for i in l..=k {
+                    let f = s * rv1[i];
+                    rv1[i] *= c;
+                    if negligible(f) {
+                        break;
+                    }
+                    let g = w[i];
+                    let h = f.hypot(g);
+                    w[i] = h;
+                    c = g / h;
+                    s = -f / h;
+                    for j in 0..m {
+                        let (y, z) = (a[j][nm], a[j][i]);
+                        a[j][nm] = y * c + z * s;
+                        a[j][i] = z * c - y * s;
+                    }
+                }
+            }
+
+            let z = w[k];
+            if l == k {
+                // Converged, so make the singular value non-negative
+                if z < 0.0 {
+                    w[k] = -z;
+                    for j in 0..n {
+                        v[j][k] = -v[j][k];
+                    }
+                }
+                break;
+            }
+            if sweeps == MAX_SWEEPS {
+                return Err(SolverError::MaxIterationsReached);
+            }
+            sweeps += 1;
+
+            // Wilkinson shift from the bottom 2×2 minor
+            let nm = k - 1;
+            let mut x = w[l];
+            let y = w[nm];
+            let g = rv1[nm];
+            let h = rv1[k];
+            let mut f = ((y - z) * (y + z) + (g - h) * (g + h)) / (2.0 * h * y);
+            let g = f.hypot(1.0);
+            f = ((x - z) * (x + z) + h * ((y / (f + g.copysign(f))) - h)) / x;
+
+            // Next QR sweep, chasing the bulge down the bidiagonal
+            let (mut c, mut s) = (1.0, 1.0);
+            for j in l..=nm {
+                let i = j + 1;
+                let mut g = rv1[i];
+                let mut y = w[i];
+                let mut h = s * g;
+                g *= c;
+                let mut z = f.hypot(h);
+                rv1[j] = z;
+                c = f / z;
+                s = h / z;
+                f = x * c + g * s;
+                g = g * c - x * s;
+                h = y * s;
+                y *= c;
+                for jj in 0..n {
+                    let (x, z) = (v[jj][j], v[jj][i]);
+                    v[jj][j] = x * c + z * s;
+                    v[jj][i] = z * c - x * s;
+                }
+                z = f.hypot(h);
+                w[j] = z;
+                if z != 0.0 {
+                    c = f / z;
+                    s = h / z;
+                }
+                f = c * g + s * y;
+                x = c * y - s * g;
+                for jj in 0..m {
+                    let (y, z) = (a[jj][j], a[jj][i]);
+                    a[jj][j] = y * c + z * s;
+                    a[jj][i] = z * c - y * s;
+                }
+            }
+            rv1[l] = 0.0;
+            rv1[k] = f;
+            w[k] = x;
+        }
+    }
+
+    // Sort into decreasing order, moving the singular vectors along with the values
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| w[j].total_cmp(&w[i]));
+    let mut u = Arr2D::full(0.0, m, n);
+    let mut v_sorted = Arr2D::full(0.0, n, n);
+    for (new, &old) in order.iter().enumerate() {
+        for i in 0..m {
+            u[i][new] = a[i][old];
+        }
+        for i in 0..n {
+            v_sorted[i][new] = v[i][old];
+        }
+    }
+    let singular_values = order.iter().map(|&i| w[i]).collect();
+    Ok((u, singular_values, v_sorted))
+}
+
+// Extends a matrix with orthonormal columns to a square orthogonal matrix. The full
+// QR decomposition of the columns has R = diag(±1), so Q holds the same columns up to
+// sign followed by a basis for their orthogonal complement.
+fn complete_basis(columns: &Arr2D<f64>) -> Result<Arr2D<f64>, SolverError> {
+    let (height, width) = columns.shape();
+    if height == width {
+        return Ok(columns.clone());
+    }
+    let (mut q, _) = qr_decomposition(columns, QRMode::Full)?;
+    for i in 0..height {
+        q[i][..width].copy_from_slice(&columns[i]);
+    }
+    Ok(q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_TOL: f64 = 1e-10;
+
+    fn assert_close(result: &Arr2D<f64>, expected: &Arr2D<f64>) {
+        assert_eq!(result.shape(), expected.shape());
+        for (a, b) in result
+            .into_iter()
+            .flatten()
+            .zip(expected.into_iter().flatten())
+        {
+            assert!((a - b).abs() < ERROR_TOL, "{result} != {expected}");
+        }
+    }
+
+    // Checks A = U S Vᵀ and that U and V are orthonormal
+    fn assert_svd(matrix: &Arr2D<f64>, mode: SVDMode) -> SVDResult {
+        let (u, s, vt) = svd_decomposition(matrix, mode).unwrap();
+        let mut sigma = Arr2D::full(0.0, u.width, vt.height);
+        for (i, &value) in s.iter().enumerate() {
+            sigma[i][i] = value;
+        }
+        assert_close(&(&(&u * &sigma) * &vt), matrix);
+        assert_close(&(&u.transpose() * &u), &Arr2D::identity(u.width));
+        assert_close(&(&vt * &vt.transpose()), &Arr2D::identity(vt.height));
+        assert!(s.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert!(s.iter().all(|&value| value >= 0.0));
+        (u, s, vt)
+    }
+
+    #[test]
+    fn test_known_singular_values() {
+        let matrix = Arr2D::from(&[[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+        let (_, s, _) = assert_svd(&matrix, SVDMode::Thin);
+        assert!((s[0] - 5.0).abs() < ERROR_TOL);
+        assert!((s[1] - 3.0).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_tall_matrix() {
+        let matrix = Arr2D::from(&[
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 10.0],
+            [-1.0, 0.5, 2.0],
+        ]);
+        let (u, s, vt) = assert_svd(&matrix, SVDMode::Thin);
+        assert_eq!((u.shape(), s.len(), vt.shape()), ((4, 3), 3, (3, 3)));
+        let (u, s, vt) = assert_svd(&matrix, SVDMode::Full);
+        assert_eq!((u.shape(), s.len(), vt.shape()), ((4, 4), 3, (3, 3)));
+    }
+
+    #[test]
+    fn test_wide_matrix() {
+        let matrix = Arr2D::from(&[[1.0, 0.0, 2.0, -1.0], [3.0, 1.0, 0.0, 4.0]]);
+        let (u, s, vt) = assert_svd(&matrix, SVDMode::Thin);
+        assert_eq!((u.shape(), s.len(), vt.shape()), ((2, 2), 2, (2, 4)));
+        let (u, s, vt) = assert_svd(&matrix, SVDMode::Full);
+        assert_eq!((u.shape(), s.len(), vt.shape()), ((2, 2), 2, (4, 4)));
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        // The third row is the sum of the first two
+        let matrix = Arr2D::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [5.0, 7.0, 9.0]]);
+        let (_, s, _) = assert_svd(&matrix, SVDMode::Full);
+        assert!(s[2] < ERROR_TOL);
+        assert!(s[1] > 0.1);
+    }
+
+    #[test]
+    fn test_diagonal_and_vector() {
+        let matrix = Arr2D::from(&[[0.0, 0.0], [0.0, -3.0]]);
+        let (_, s, _) = assert_svd(&matrix, SVDMode::Thin);
+        assert_eq!(s, vec![3.0, 0.0]);
+
+        let column = Arr2D::from(&[[3.0], [4.0]]);
+        let (_, s, _) = assert_svd(&column, SVDMode::Full);
+        assert!((s[0] - 5.0).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_integer_input() {
+        let matrix = vec![vec![2, 0], vec![0, 1]];
+        let (_, s, _) = svd_decomposition(&matrix, SVDMode::Thin).unwrap();
+        assert_eq!(s, vec![2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_larger_matrices() {
+        // Deterministic pseudo-random entries in [-1, 1)
+        let mut seed = 12345_u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+        };
+        let flat: Vec<f64> = (0..56).map(|_| next()).collect();
+        let tall = Arr2D::from_flat(&flat, 0.0, 8, 7).unwrap();
+        assert_svd(&tall, SVDMode::Full);
+        assert_svd(&tall.transpose(), SVDMode::Full);
+    }
+}
@@ -0,0 +1,176 @@
+// Variants of the power method that iterate with (A - σI)⁻¹ instead of A, so that they
+// converge to the eigenvalue closest to the shift σ rather than the dominant one
+use crate::convergence::{SolveReport, SolverOptions};
+use crate::solvers::SolverError;
+use crate::solvers::decomposition::plu::{PLUResult, lu_pivot_decomposition};
+use crate::solvers::eigen::power_method::{Eigenpair, vector_iteration};
+use crate::utils::{Arr2D, Arr2DError, back_substitution, forward_substitution};
+
+/// Inverse iteration, giving the eigenvalue of smallest magnitude and its eigenvector.
+/// Equivalent to `shifted_inverse_iteration` with a shift of zero, so a singular
+/// matrix fails with `SingularMatrix`.
+pub fn inverse_iteration<M>(
+    matrix: M,
+    initial: Option<&[f64]>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Eigenpair>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    shifted_inverse_iteration(matrix, 0.0, initial, options)
+}
+
+/// Shifted inverse iteration, giving the eigenvalue closest to `shift` and its
+/// eigenvector.
+///
+/// `A - σI` is factorized once with `lu_pivot_decomposition` and each iteration solves
+/// a system with the factors. Fails with `SingularMatrix` if the shift is (too close
+/// to) an eigenvalue.
+pub fn shifted_inverse_iteration<M>(
+    matrix: M,
+    shift: f64,
+    initial: Option<&[f64]>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Eigenpair>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    if matrix.height != matrix.width {
+        return Err(SolverError::NonSquareMatrix);
+    }
+    let factors = lu_pivot_decomposition(&unit_scaled(shifted(&matrix, shift)))?;
+    vector_iteration(&matrix, initial, options, |x, _| Ok(plu_solve(&factors, x)))
+}
+
+/// Rayleigh quotient iteration, which uses the current Rayleigh quotient as the shift
+/// of every inverse iteration step.
+///
+/// Convergence is cubic for symmetric matrices, but the eigenpair it settles on depends
+/// on the initial vector. `A - σI` is factorized again on every iteration; once it is
+/// singular to working precision the shift is an eigenvalue and the iteration stops.
+pub fn rayleigh_quotient_iteration<M>(
+    matrix: M,
+    initial: Option<&[f64]>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Eigenpair>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    vector_iteration(&matrix, initial, options, |x, shift| {
+        match lu_pivot_decomposition(&shifted(&matrix, shift)) {
+            Ok(factors) => Ok(plu_solve(&factors, x)),
+            // Leaving the vector unchanged repeats the shift, which meets the tolerances
+            Err(SolverError::SingularMatrix) => Ok(x.clone()),
+            Err(e) => Err(e),
+        }
+    })
+}
+
+// A - σI
+fn shifted(matrix: &Arr2D<f64>, shift: f64) -> Arr2D<f64> {
+    let mut shifted = matrix.clone();
+    for i in 0..shifted.height {
+        shifted[i][i] -= shift;
+    }
+    shifted
+}
+
+// Scales a matrix so that its largest entry is ±1. `lu_pivot_decomposition` rejects
+// pivots below an absolute threshold, which would otherwise fail for any matrix with
+// small entries. The scale drops out when the iterate is normalised.
+fn unit_scaled(matrix: Arr2D<f64>) -> Arr2D<f64> {
+    let scale = matrix
+        .into_iter()
+        .flatten()
+        .fold(0.0, |largest: f64, a| largest.max(a.abs()));
+    if scale == 0.0 {
+        matrix
+    } else {
+        &matrix / scale
+    }
+}
+
+// Solves (A - σI)y = x given PA = LU for the shifted matrix
+fn plu_solve(factors: &PLUResult, rhs: &Arr2D<f64>) -> Arr2D<f64> {
+    let (lower, upper, permutation) = factors;
+    let size = lower.height;
+    let permuted: Vec<f64> = (permutation * rhs).into_iter().flatten().copied().collect();
+    let mut y = vec![0.0; size];
+    forward_substitution(lower, size, &permuted, &mut y);
+    let mut solution = vec![0.0; size];
+    back_substitution(upper, size, &y, &mut solution);
+    // Not empty, since the matrix is square and was factorized
+    Arr2D::from_flat(solution, 0.0, size, 1).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> SolverOptions<'static> {
+        SolverOptions {
+            rel_tol: 1e-10,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_inverse_iteration() {
+        // Smallest eigenvalue and eigenvector
+        let matrix = Arr2D::from(&[[2, 8, 10], [8, 4, 5], [10, 5, 7]]);
+        let report = inverse_iteration(&matrix, None, &mut options()).unwrap();
+        assert!(report.converged());
+        let (eigenvalue, eigenvector) = report.solution;
+        assert!((eigenvalue - 0.29424).abs() < 1e-5);
+        let expected = [0.04117, 1.0, -0.80702];
+        for (x, e) in eigenvector.into_iter().flatten().zip(expected) {
+            assert!((x - e).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_shifted_inverse_iteration() {
+        let matrix = Arr2D::from(&[
+            [3.556, -1.778, 0.0],
+            [-1.778, 3.556, -1.778],
+            [0.0, -1.778, 3.556],
+        ]);
+        // The eigenvalues are 3.556 and 3.556 ± 1.778√2, so this picks the middle one
+        let report = shifted_inverse_iteration(&matrix, 3.0, None, &mut options()).unwrap();
+        assert!(report.converged());
+        let (eigenvalue, _) = report.solution;
+        assert!((eigenvalue - 3.556).abs() < 1e-8);
+
+        // Exactly on an eigenvalue
+        let diagonal = Arr2D::from(&[[1.0, 0.0], [0.0, 2.0]]);
+        assert!(matches!(
+            shifted_inverse_iteration(&diagonal, 2.0, None, &mut options()),
+            Err(SolverError::SingularMatrix)
+        ));
+    }
+
+    #[test]
+    fn test_small_scale_matrix() {
+        // Well conditioned, but every pivot is far below f64::EPSILON
+        let matrix = &Arr2D::from(&[[2.0, 1.0], [1.0, 3.0]]) * 1e-17;
+        let smallest = (5.0 - 5f64.sqrt()) / 2.0 * 1e-17;
+        let report = inverse_iteration(&matrix, None, &mut options()).unwrap();
+        assert!(report.converged());
+        assert!((report.solution.0 - smallest).abs() < 1e-25);
+        let report = shifted_inverse_iteration(&matrix, 3e-17, None, &mut options()).unwrap();
+        assert!((report.solution.0 - (5.0 + 5f64.sqrt()) / 2.0 * 1e-17).abs() < 1e-25);
+    }
+
+    #[test]
+    fn test_rayleigh_quotient_iteration() {
+        let matrix = Arr2D::from(&[[2.0, 8.0, 10.0], [8.0, 4.0, 5.0], [10.0, 5.0, 7.0]]);
+        let report = rayleigh_quotient_iteration(&matrix, None, &mut options()).unwrap();
+        assert!(report.converged());
+        assert!(report.iterations < 10);
+        let (eigenvalue, eigenvector) = report.solution;
+        let residual = &(&matrix * &eigenvector) - &(&eigenvector * eigenvalue);
+        assert!(residual.into_iter().flatten().all(|r| r.abs() < 1e-8));
+    }
+}
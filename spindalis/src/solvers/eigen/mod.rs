@@ -1 +1,4 @@
+pub mod inverse_iteration;
 pub mod power_method;
+pub mod qr_algorithm;
+pub mod symmetric;
@@ -1,37 +1,225 @@
+use crate::convergence::{Iteration, SolveReport, SolverOptions, Termination};
+use crate::solvers::SolverError;
+use crate::solvers::decomposition::check_symmetric;
 use crate::utils::{Arr2D, Arr2DError};
 
-pub fn power_method<M>(matrix: M, es: f64) -> Result<(f64, Arr2D<f64>), Arr2DError>
+pub type Eigenpair = (f64, Arr2D<f64>);
+
+// Smallest residual bound, relative to ‖A‖, required before accepting an eigenpair
+const RESIDUAL_FLOOR: f64 = 1.5e-8;
+
+/// Dominant eigenvalue of an n×n matrix and its eigenvector, scaled so that its largest
+/// entry is 1.
+///
+/// `es` is the relative error as a fraction. Iteration starts from `initial`, or from a
+/// vector of ones, and fails with `MaxIterationsReached` after `itermax` iterations.
+/// When the two largest eigenvalues have the same magnitude, such as ±λ, there is no
+/// dominant eigenvector and it fails with `NoConvergence` instead.
+pub fn power_method<M>(
+    matrix: M,
+    es: f64,
+    initial: Option<&[f64]>,
+    itermax: usize,
+) -> Result<Eigenpair, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    // `es` is a fraction, while the shared relative tolerance is a percentage
+    let mut options = SolverOptions {
+        rel_tol: es * 100.0,
+        itermax,
+        ..Default::default()
+    };
+    let report = power_method_with_options(matrix, initial, &mut options)?;
+    unconverged_error(&report)?;
+    Ok(report.solution)
+}
+
+/// Power method with shared stopping criteria.
+/// The residual is the largest entry of `|Ax - λx|` for the normalised eigenvector `x`.
+///
+/// Meeting `abs_tol` or `rel_tol` only shows that the eigenvalue has settled, so the
+/// iteration also waits for the residual to be within the square root of that
+/// tolerance, relative to `‖A‖`. If it never is, the report ends with
+/// `Termination::NoConvergence`.
+pub fn power_method_with_options<M>(
+    matrix: M,
+    initial: Option<&[f64]>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Eigenpair>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    vector_iteration(&matrix, initial, options, |x, _| Ok(&matrix * x))
+}
+
+/// The `k` eigenpairs of largest magnitude of a symmetric matrix, by the power method
+/// with Hotelling deflation.
+///
+/// After each eigenpair is found, `λvvᵀ` (with `v` of unit length) is subtracted so
+/// that the next power iteration converges to the following eigenvalue. Deflation
+/// this way only preserves the remaining eigenpairs for symmetric matrices, so other
+/// matrices fail with `NonSymmetricMatrix`. `k` is capped at the size of the matrix.
+pub fn power_method_top_k<M>(
+    matrix: M,
+    k: usize,
+    options: &mut SolverOptions,
+) -> Result<Vec<Eigenpair>, SolverError>
 where
     M: TryInto<Arr2D<f64>, Error = Arr2DError>,
 {
     let matrix: Arr2D<f64> = matrix.try_into()?;
-    if matrix.height != matrix.width || matrix.height == 0 || matrix.width == 0 {
-        return Err(Arr2DError::NonSquareMatrix);
+    check_symmetric(&matrix)?;
+
+    let size = matrix.height;
+    // A vector of ones is orthogonal to every antisymmetric eigenvector, so start from
+    // a ramp instead
+    let initial: Vec<f64> = (1..=size).map(|i| i as f64).collect();
+    let mut deflated = matrix;
+    let mut pairs = Vec::with_capacity(k.min(size));
+    for _ in 0..k.min(size) {
+        let report = power_method_with_options(&deflated, Some(&initial), options)?;
+        unconverged_error(&report)?;
+        let (value, vector) = report.solution;
+        let length = vector
+            .into_iter()
+            .flatten()
+            .map(|x| x * x)
+            .sum::<f64>()
+            .sqrt();
+        let unit = &vector / length;
+        deflated -= &(&unit * &unit.transpose()) * value;
+        pairs.push((value, vector));
+    }
+    Ok(pairs)
+}
+
+// Shared loop for the power method and its variants. `apply` maps the current vector
+// and eigenvalue estimate to the next unnormalised vector, and the eigenvalue is the
+// Rayleigh quotient of each normalised vector.
+pub(super) fn vector_iteration<F>(
+    matrix: &Arr2D<f64>,
+    initial: Option<&[f64]>,
+    options: &mut SolverOptions,
+    mut apply: F,
+) -> Result<SolveReport<Eigenpair>, SolverError>
+where
+    F: FnMut(&Arr2D<f64>, f64) -> Result<Arr2D<f64>, SolverError>,
+{
+    if matrix.height != matrix.width || matrix.height == 0 {
+        return Err(SolverError::NonSquareMatrix);
     }
-    let initial_eigenvector = Arr2D::from(&[[1.0], [1.0], [1.0]]);
-    let mut eigenvector = &matrix * initial_eigenvector;
-    // Arr2D.max() only returns None if the matrix is empty
-    let mut eigenvalue = eigenvector.max().unwrap(); // Matrix won't be empty here
-    eigenvector = eigenvector / eigenvalue; // Normalised Eigenvector
-    loop {
-        eigenvector = &matrix * eigenvector;
-        let normalisation_value = eigenvector.max().unwrap(); // Matrix also won't be empty here
-        let normalised_eigenvector = &eigenvector / normalisation_value;
+    let size = matrix.height;
+    let initial_eigenvector = match initial {
+        Some(vector) if vector.len() != size => {
+            return Err(SolverError::NumArgumentsMismatch {
+                num_rows: size,
+                rhs_len: vector.len(),
+            });
+        }
+        Some(vector) => Arr2D::from_flat(vector, 0.0, size, 1)?,
+        None => Arr2D::full(1.0, size, 1),
+    };
+    let matrix_norm = matrix
+        .rows()
+        .map(|row| row.iter().map(|a| a.abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let mut eigenvector = normalise(&initial_eigenvector)?;
+    let mut eigenvalue = rayleigh_quotient(matrix, &eigenvector);
+    let mut iter = 0;
+    let mut history = Vec::new();
+    let (termination, residual) = loop {
+        let normalised_eigenvector = normalise(&apply(&eigenvector, eigenvalue)?)?;
 
         // Rayleigh quotient for faster convergence
-        let numerator = &normalised_eigenvector.transpose() * (&matrix * &normalised_eigenvector); // x_k^T * (A * x_k)
-        let denominator = &normalised_eigenvector.transpose() * &normalised_eigenvector; // x_k^T * x_k
-        let next_eigenvalue = numerator.as_scalar_unchecked() / denominator.as_scalar_unchecked(); // convert to f64
+        let product = matrix * &normalised_eigenvector; // A * x_k
+        let next_eigenvalue = rayleigh_quotient(matrix, &normalised_eigenvector);
 
         let ea = ((next_eigenvalue - eigenvalue) / next_eigenvalue).abs();
+        let residual = product
+            .rows()
+            .zip(normalised_eigenvector.rows())
+            .map(|(ax, x)| (ax[0] - next_eigenvalue * x[0]).abs())
+            .fold(0.0, f64::max);
+        iter += 1;
+        history.push(ea * 100.0);
 
+        let iteration = Iteration {
+            iteration: iter,
+            x: next_eigenvalue,
+            step: (next_eigenvalue - eigenvalue).abs(),
+            error: ea * 100.0,
+            residual,
+        };
         eigenvalue = next_eigenvalue;
         eigenvector = normalised_eigenvector;
-        if ea < es {
-            break;
+        match options.termination(&iteration) {
+            Some(termination)
+                if residual_settled(termination, options, &iteration, matrix_norm) =>
+            {
+                break (termination, residual);
+            }
+            // With eigenvalues ±λ the vector alternates between two directions that
+            // share a Rayleigh quotient, so the eigenvalue settles while Ax ≠ λx
+            Some(_) if iter >= options.itermax => break (Termination::NoConvergence, residual),
+            _ => {}
         }
+    };
+    Ok(SolveReport {
+        solution: (eigenvalue, eigenvector),
+        iterations: iter,
+        residual,
+        history,
+        termination,
+    })
+}
+
+// Whether the residual backs up a convergence claim based on the eigenvalue alone
+fn residual_settled(
+    termination: Termination,
+    options: &SolverOptions,
+    iteration: &Iteration,
+    matrix_norm: f64,
+) -> bool {
+    let claimed = match termination {
+        Termination::RelativeTolerance => options.rel_tol / 100.0,
+        Termination::AbsoluteTolerance => options.abs_tol / iteration.x.abs(),
+        _ => return true,
+    };
+    iteration.residual <= claimed.sqrt().max(RESIDUAL_FLOOR) * matrix_norm
+}
+
+// Errors for a report that stopped without converging
+fn unconverged_error(report: &SolveReport<Eigenpair>) -> Result<(), SolverError> {
+    match report.termination {
+        Termination::NoConvergence => Err(SolverError::NoConvergence),
+        _ if !report.converged() => Err(SolverError::MaxIterationsReached),
+        _ => Ok(()),
     }
-    Ok((eigenvalue, eigenvector))
+}
+
+// Scales a vector so that its largest entry is 1, or its smallest entry when none are
+// positive. A zero vector has no direction to converge on.
+fn normalise(vector: &Arr2D<f64>) -> Result<Arr2D<f64>, SolverError> {
+    // Neither is None, since the vector is not empty
+    let largest = vector.max().unwrap();
+    let scale = if largest > 0.0 {
+        largest
+    } else {
+        vector.min().unwrap()
+    };
+    if scale == 0.0 || scale.is_nan() {
+        return Err(SolverError::NoConvergence);
+    }
+    Ok(vector / scale)
+}
+
+// x^T * (A * x) / x^T * x
+fn rayleigh_quotient(matrix: &Arr2D<f64>, vector: &Arr2D<f64>) -> f64 {
+    let numerator = (&vector.transpose() * &(matrix * vector)).as_scalar_unchecked();
+    let denominator = (&vector.transpose() * vector).as_scalar_unchecked();
+    numerator / denominator
 }
 
 #[cfg(test)]
@@ -49,7 +237,7 @@ mod tests {
         ]);
         let expected = (6.070, Arr2D::from(&[[1.0], [-1.414], [1.0]]));
 
-        let (mut eigenvalue, mut eigenvector) = power_method(&matrix, 1e-10).unwrap();
+        let (mut eigenvalue, mut eigenvector) = power_method(&matrix, 1e-10, None, 1000).unwrap();
         eigenvalue = (eigenvalue * 10_f64.powi(2)).round() / 10_f64.powi(2);
         eigenvector = eigenvector.round_to_decimal(3);
         let result = (eigenvalue, eigenvector);
@@ -72,7 +260,8 @@ mod tests {
             Arr2D::from(&[[0.707], [1.0], [0.707]]),
         );
 
-        let (converged_value, mut eigenvector) = power_method(&inverse_res, 1e-10).unwrap();
+        let (converged_value, mut eigenvector) =
+            power_method(&inverse_res, 1e-10, None, 1000).unwrap();
         let mut eigenvalue = 1_f64 / converged_value;
         eigenvalue = (eigenvalue * 10_f64.powi(5)).round() / 10_f64.powi(5);
         eigenvector = eigenvector.round_to_decimal(3);
@@ -86,7 +275,7 @@ mod tests {
         let matrix = Arr2D::from(&[[2, 8, 10], [8, 4, 5], [10, 5, 7]]);
         let expected = (19.88, Arr2D::from(&[[0.9035], [0.7698], [1.0]]));
 
-        let (mut eigenvalue, mut eigenvector) = power_method(&matrix, 1e-10).unwrap();
+        let (mut eigenvalue, mut eigenvector) = power_method(&matrix, 1e-10, None, 1000).unwrap();
         eigenvalue = (eigenvalue * 10_f64.powi(2)).round() / 10_f64.powi(2);
         eigenvector = eigenvector.round_to_decimal(4);
         let result = (eigenvalue, eigenvector);
@@ -100,11 +289,99 @@ mod tests {
         let expected = (0.29, Arr2D::from(&[[0.04117], [1.0], [-0.80702]]));
 
         let inverse_res = matrix.inverse().unwrap();
-        let (converged_value, mut eigenvector) = power_method(&inverse_res, 1e-10).unwrap();
+        let (converged_value, mut eigenvector) =
+            power_method(&inverse_res, 1e-10, None, 1000).unwrap();
         let mut eigenvalue = 1_f64 / converged_value;
         eigenvalue = (eigenvalue * 10_f64.powi(2)).round() / 10_f64.powi(2);
         eigenvector = eigenvector.round_to_decimal(5);
         let result = (eigenvalue, eigenvector);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_larger_matrix() {
+        // Discrete Laplacian, with largest eigenvalue 2 - 2cos(5π / 6) = 2 + √3
+        let matrix = Arr2D::from(&[
+            [2.0, -1.0, 0.0, 0.0, 0.0],
+            [-1.0, 2.0, -1.0, 0.0, 0.0],
+            [0.0, -1.0, 2.0, -1.0, 0.0],
+            [0.0, 0.0, -1.0, 2.0, -1.0],
+            [0.0, 0.0, 0.0, -1.0, 2.0],
+        ]);
+        // Ones are orthogonal to the dominant eigenvector, which alternates in sign
+        let initial = [1.0, 0.0, 0.0, 0.0, 0.0];
+        let (eigenvalue, eigenvector) = power_method(&matrix, 1e-12, Some(&initial), 1000).unwrap();
+        assert!((eigenvalue - (2.0 + 3.0_f64.sqrt())).abs() < 1e-8);
+        assert_eq!(eigenvector.shape(), (5, 1));
+    }
+
+    #[test]
+    fn test_iteration_cap_and_invalid_input() {
+        // Complex eigenvalues 1 ± i√2 have the same magnitude, so the iteration never settles
+        let matrix = Arr2D::from(&[[1.0, -2.0], [1.0, 1.0]]);
+        assert!(matches!(
+            power_method(&matrix, 1e-10, Some(&[1.0, 0.0]), 50),
+            Err(SolverError::MaxIterationsReached)
+        ));
+        assert!(matches!(
+            power_method(&matrix, 1e-10, Some(&[1.0, 0.0, 0.0]), 50),
+            Err(SolverError::NumArgumentsMismatch {
+                num_rows: 2,
+                rhs_len: 3
+            })
+        ));
+        let matrix = Arr2D::from(&[[1.0, 2.0, 3.0]]);
+        assert!(matches!(
+            power_method(&matrix, 1e-10, None, 50),
+            Err(SolverError::NonSquareMatrix)
+        ));
+    }
+
+    #[test]
+    fn test_opposite_eigenvalues() {
+        // The vector alternates between two directions with the same Rayleigh quotient
+        let diagonal = Arr2D::from(&[[1.0, 0.0], [0.0, -1.0]]);
+        assert!(matches!(
+            power_method(&diagonal, 1e-10, Some(&[1.0, 2.0]), 1000),
+            Err(SolverError::NoConvergence)
+        ));
+        let mut options = SolverOptions {
+            rel_tol: 1e-10,
+            ..Default::default()
+        };
+        let report = power_method_with_options(&diagonal, Some(&[1.0, 2.0]), &mut options).unwrap();
+        assert_eq!(report.termination, Termination::NoConvergence);
+
+        for matrix in [diagonal, Arr2D::from(&[[0.0, 1.0], [1.0, 0.0]])] {
+            assert!(matches!(
+                power_method_top_k(&matrix, 2, &mut SolverOptions::default()),
+                Err(SolverError::NoConvergence)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_top_k() {
+        let matrix = Arr2D::from(&[[2.0, 8.0, 10.0], [8.0, 4.0, 5.0], [10.0, 5.0, 7.0]]);
+        let mut options = SolverOptions {
+            rel_tol: 1e-10,
+            ..Default::default()
+        };
+        let pairs = power_method_top_k(&matrix, 3, &mut options).unwrap();
+        let expected = crate::solvers::eigen::symmetric::symmetric_eigen(&matrix).unwrap();
+        let mut expected: Vec<f64> = expected.iter().map(|(value, _)| *value).collect();
+        expected.sort_by(|a, b| b.abs().total_cmp(&a.abs()));
+        assert_eq!(pairs.len(), 3);
+        for ((value, vector), expected) in pairs.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-6);
+            let residual = &(&matrix * vector) - &(vector * *value);
+            assert!(residual.into_iter().flatten().all(|r| r.abs() < 1e-4));
+        }
+
+        let non_symmetric = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        assert!(matches!(
+            power_method_top_k(&non_symmetric, 1, &mut options),
+            Err(SolverError::NonSymmetricMatrix)
+        ));
+    }
 }
@@ -0,0 +1,543 @@
+// Francis double-shift QR algorithm on an upper Hessenberg matrix, followed by back
+// substitution on the real Schur form for the eigenvectors. Adapted from `hqr2` in
+// EISPACK, by way of the JAMA linear algebra package.
+use crate::reduction::matrix::hessenberg_reduction;
+use crate::solvers::SolverError;
+use crate::utils::{Arr2D, Arr2DError};
+use spindalis_core::complex::Complex;
+
+/// An eigenvalue and its unit-length eigenvector as a column vector
+pub type ComplexEigenpair = (Complex, Arr2D<Complex>);
+
+// QR sweeps allowed for each eigenvalue (or conjugate pair) before giving up
+const MAX_SWEEPS: usize = 100;
+
+/// All eigenvalues and eigenvectors of a square matrix.
+///
+/// The matrix is reduced to Hessenberg form with `hessenberg_reduction` and then
+/// passed to `hessenberg_eigen`. Eigenpairs are sorted by decreasing eigenvalue
+/// magnitude, and complex conjugate pairs are adjacent.
+pub fn eigen_decomposition<M>(matrix: M) -> Result<Vec<ComplexEigenpair>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    let (h, q) = hessenberg_reduction(&matrix)?;
+    hessenberg_eigen(&h, &q)
+}
+
+/// All eigenvalues of a square matrix, sorted by decreasing magnitude.
+/// See [`eigen_decomposition`].
+pub fn eigenvalues<M>(matrix: M) -> Result<Vec<Complex>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    Ok(eigen_decomposition(matrix)?
+        .into_iter()
+        .map(|(value, _)| value)
+        .collect())
+}
+
+/// Eigenpairs from the output `(H, Q)` of `hessenberg_reduction`, where `A = QHQᵀ`.
+///
+/// Francis double-shift QR steps reduce H to real Schur form, with 1×1 blocks for real
+/// eigenvalues and 2×2 blocks for complex conjugate pairs. The eigenvectors of the
+/// Schur form are found by back substitution and mapped back through Q.
+pub fn hessenberg_eigen(
+    hessenberg: &Arr2D<f64>,
+    q: &Arr2D<f64>,
+) -> Result<Vec<ComplexEigenpair>, SolverError> {
+    if hessenberg.height != hessenberg.width || q.shape() != hessenberg.shape() {
+        return Err(SolverError::NonSquareMatrix);
+    }
+    let nn = hessenberg.height;
+    let mut h = hessenberg.clone();
+    let mut v = q.clone();
+    let mut d = vec![0.0; nn];
+    let mut e = vec![0.0; nn];
+    let eps = f64::EPSILON;
+
+    let mut norm = 0.0;
+    for i in 0..nn {
+        for j in i.saturating_sub(1)..nn {
+            norm += h[i][j].abs();
+        }
+    }
+
+    let (mut p, mut q, mut r, mut s, mut z): (f64, f64, f64, f64, f64);
+    let (mut w, mut x, mut y);
+    let mut exshift = 0.0;
+    let mut sweeps = 0;
+    // Eigenvalues are deflated from the bottom, so rows remaining..nn are done
+    let mut remaining = nn;
+    while remaining > 0 {
+        let n = remaining - 1;
+
+        // Look for a single small subdiagonal element
+        let mut l = n;
+        while l > 0 {
+            s = h[l - 1][l - 1].abs() + h[l][l].abs();
+            if s == 0.0 {
+                s = norm;
+            }
+            if h[l][l - 1].abs() <= eps * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == n {
+            // One real root
+            h[n][n] += exshift;
+            d[n] = h[n][n];
+            e[n] = 0.0;
+            remaining -= 1;
+            sweeps = 0;
+        } else if l + 1 == n {
+            // Two roots from the trailing 2×2 block
+            w = h[n][n - 1] * h[n - 1][n];
+            p = (h[n - 1][n - 1] - h[n][n]) / 2.0;
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[n][n] += exshift;
+            h[n - 1][n - 1] += exshift;
+            x = h[n][n];
+
+            if q >= 0.0 {
+                // Real pair, split off with a rotation
+                z = if p >= 0.0 { p + z } else { p - z };
+                d[n - 1] = x + z;
+                d[n] = d[n - 1];
+                if z != 0.0 {
+                    d[n] = x - w / z;
+                }
+                e[n - 1] = 0.0;
+                e[n] = 0.0;
+                x = h[n][n - 1];
+                s = x.abs() + z.abs();
+                p = x / s;
+                q = z / s;
+                r = (p * p + q * q).sqrt();
+                p /= r;
+                q /= r;
+
+                for j in (n - 1)..nn {
+                    z = h[n - 1][j];
+                    h[n - 1][j] = q * z + p * h[n][j];
+                    h[n][j] = q * h[n][j] - p * z;
+                }
+                for i in 0..=n {
+                    z = h[i][n - 1];
+                    h[i][n - 1] = q * z + p * h[i][n];
+                    h[i][n] = q * h[i][n] - p * z;
+                }
+                for i in 0..nn {
+                    z = v[i][n - 1];
+                    v[i][n - 1] = q * z + p * v[i][n];
+                    v[i][n] = q * v[i][n] - p * z;
+                }
+            } else {
+                // Complex conjugate pair
+                d[n - 1] = x + p;
+                d[n] = x + p;
+                e[n - 1] = z;
+                e[n] = -z;
+            }
+            remaining -= 2;
+            sweeps = 0;
+        } else {
+            // No convergence yet, so form the shift
+            x = h[n][n];
+            y = h[n - 1][n - 1];
+            w = h[n][n - 1] * h[n - 1][n];
+
+            // Exceptional shifts break cycles that the standard shift can fall into
+            if sweeps == 10 {
+                exshift += x;
+                for i in 0..=n {
+                    h[i][i] -= x;
+                }
+                s = h[n][n - 1].abs() + h[n - 1][n - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            if sweeps == 30 {
+                s = (y - x) / 2.0;
+                s = s * s + w;
+                if s > 0.0 {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / 2.0 + s);
+                    for i in 0..=n {
+                        h[i][i] -= s;
+                    }
+                    exshift += s;
+                    x = 0.964;
+                    y = x;
+                    w = x;
+                }
+            }
+            if sweeps == MAX_SWEEPS {
+                return Err(SolverError::MaxIterationsReached);
+            }
+            sweeps += 1;
+
+            // Look for two consecutive small subdiagonal elements
+            let mut m = n - 2;
+            loop {
+                z = h[m][m];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[m + 1][m] + h[m][m + 1];
+                q = h[m + 1][m + 1] - z - r - s;
+                r = h[m + 2][m + 1];
+                s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                if h[m][m - 1].abs() * (q.abs() + r.abs())
+                    < eps * (p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs()))
+                {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in (m + 2)..=n {
+                h[i][i - 2] = 0.0;
+                if i > m + 2 {
+                    h[i][i - 3] = 0.0;
+                }
+            }
+
+            // Double QR step on rows l..=n and columns m..=n
+            for k in m..n {
+                let notlast = k + 1 != n;
+                if k != m {
+                    p = h[k][k - 1];
+                    q = h[k + 1][k - 1];
+                    r = if notlast { h[k + 2][k - 1] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == 0.0 {
+                        continue;
+                    }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+                s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == 0.0 {
+                    continue;
+                }
+                if k != m {
+                    h[k][k - 1] = -s * x;
+                } else if l != m {
+                    h[k][k - 1] = -h[k][k - 1];
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q /= p;
+                r /= p;
+
+                for j in k..nn {
+                    p = h[k][j] + q * h[k + 1][j];
+                    if notlast {
+                        p += r * h[k + 2][j];
+                        h[k + 2][j] -= p * z;
+                    }
+                    h[k][j] -= p * x;
+                    h[k + 1][j] -= p * y;
+                }
+                for i in 0..=n.min(k + 3) {
+                    p = x * h[i][k] + y * h[i][k + 1];
+                    if notlast {
+                        p += z * h[i][k + 2];
+                        h[i][k + 2] -= p * r;
+                    }
+                    h[i][k] -= p;
+                    h[i][k + 1] -= p * q;
+                }
+                for i in 0..nn {
+                    p = x * v[i][k] + y * v[i][k + 1];
+                    if notlast {
+                        p += z * v[i][k + 2];
+                        v[i][k + 2] -= p * r;
+                    }
+                    v[i][k] -= p;
+                    v[i][k + 1] -= p * q;
+                }
+            }
+        }
+    }
+
+    if norm != 0.0 {
+        schur_eigenvectors(&mut h, &d, &e, norm);
+        // Back transformation to the eigenvectors of the original matrix
+        for j in (0..nn).rev() {
+            for i in 0..nn {
+                v[i][j] = (0..=j).map(|k| v[i][k] * h[k][j]).sum();
+            }
+        }
+    }
+
+    // Column j of V is a real eigenvector, or the real part of a complex one whose
+    // imaginary part is in the column next to it
+    let mut pairs = Vec::with_capacity(nn);
+    for j in 0..nn {
+        let value = Complex::new(d[j], e[j]);
+        let mut vector = Arr2D::full(Complex::ZERO, nn, 1);
+        for i in 0..nn {
+            vector[i][0] = if e[j] > 0.0 {
+                Complex::new(v[i][j], v[i][j + 1])
+            } else if e[j] < 0.0 {
+                Complex::new(v[i][j - 1], -v[i][j])
+            } else {
+                Complex::from(v[i][j])
+            };
+        }
+        let length = vector
+            .into_iter()
+            .flatten()
+            .map(|c| c.norm_sqr())
+            .sum::<f64>()
+            .sqrt();
+        if length > 0.0 {
+            vector = vector.map(|&c| c / length);
+        }
+        pairs.push((value, vector));
+    }
+    pairs.sort_by(|a, b| b.0.abs().total_cmp(&a.0.abs()));
+    Ok(pairs)
+}
+
+// Overwrites the real Schur form `h` with the eigenvectors of the Schur form, by back
+// substitution from the last eigenvalue up
+fn schur_eigenvectors(h: &mut Arr2D<f64>, d: &[f64], e: &[f64], norm: f64) {
+    let nn = h.height;
+    let eps = f64::EPSILON;
+    let (mut r, mut s, mut z) = (0.0, 0.0, 0.0);
+
+    for n in (0..nn).rev() {
+        let p = d[n];
+        let q = e[n];
+
+        if q == 0.0 {
+            // Real vector
+            let mut l = n;
+            h[n][n] = 1.0;
+            for i in (0..n).rev() {
+                let w = h[i][i] - p;
+                r = (l..=n).map(|j| h[i][j] * h[j][n]).sum();
+                if e[i] < 0.0 {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+                l = i;
+                if e[i] == 0.0 {
+                    h[i][n] = if w != 0.0 { -r / w } else { -r / (eps * norm) };
+                } else {
+                    // Solve the real 2×2 system
+                    let x = h[i][i + 1];
+                    let y = h[i + 1][i];
+                    let q = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                    let t = (x * s - z * r) / q;
+                    h[i][n] = t;
+                    h[i + 1][n] = if x.abs() > z.abs() {
+                        (-r - w * t) / x
+                    } else {
+                        (-s - y * t) / z
+                    };
+                }
+
+                // Overflow control
+                let t = h[i][n].abs();
+                if (eps * t) * t > 1.0 {
+                    for j in i..=n {
+                        h[j][n] /= t;
+                    }
+                }
+            }
+        } else if q < 0.0 {
+            // Complex vector, with the real part in column n - 1 and the imaginary
+            // part in column n
+            let mut l = n - 1;
+            if h[n][n - 1].abs() > h[n - 1][n].abs() {
+                h[n - 1][n - 1] = q / h[n][n - 1];
+                h[n - 1][n] = -(h[n][n] - p) / h[n][n - 1];
+            } else {
+                let c = Complex::new(0.0, -h[n - 1][n]) / Complex::new(h[n - 1][n - 1] - p, q);
+                h[n - 1][n - 1] = c.re;
+                h[n - 1][n] = c.im;
+            }
+            h[n][n - 1] = 0.0;
+            h[n][n] = 1.0;
+            for i in (0..n - 1).rev() {
+                let ra: f64 = (l..=n).map(|j| h[i][j] * h[j][n - 1]).sum();
+                let sa: f64 = (l..=n).map(|j| h[i][j] * h[j][n]).sum();
+                let w = h[i][i] - p;
+
+                if e[i] < 0.0 {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+                l = i;
+                if e[i] == 0.0 {
+                    let c = Complex::new(-ra, -sa) / Complex::new(w, q);
+                    h[i][n - 1] = c.re;
+                    h[i][n] = c.im;
+                } else {
+                    // Solve the complex 2×2 system
+                    let x = h[i][i + 1];
+                    let y = h[i + 1][i];
+                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                    let vi = (d[i] - p) * 2.0 * q;
+                    if vr == 0.0 && vi == 0.0 {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+                    let c = Complex::new(x * r - z * ra + q * sa, x * s - z * sa - q * ra)
+                        / Complex::new(vr, vi);
+                    h[i][n - 1] = c.re;
+                    h[i][n] = c.im;
+                    if x.abs() > z.abs() + q.abs() {
+                        h[i + 1][n - 1] = (-ra - w * h[i][n - 1] + q * h[i][n]) / x;
+                        h[i + 1][n] = (-sa - w * h[i][n] - q * h[i][n - 1]) / x;
+                    } else {
+                        let c = Complex::new(-r - y * h[i][n - 1], -s - y * h[i][n])
+                            / Complex::new(z, q);
+                        h[i + 1][n - 1] = c.re;
+                        h[i + 1][n] = c.im;
+                    }
+                }
+
+                // Overflow control
+                let t = h[i][n - 1].abs().max(h[i][n].abs());
+                if (eps * t) * t > 1.0 {
+                    for j in i..=n {
+                        h[j][n - 1] /= t;
+                        h[j][n] /= t;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_TOL: f64 = 1e-9;
+
+    // Checks Av = λv for every pair
+    fn assert_eigenpairs(matrix: &Arr2D<f64>, pairs: &[ComplexEigenpair]) {
+        assert_eq!(pairs.len(), matrix.height);
+        for (value, vector) in pairs {
+            for i in 0..matrix.height {
+                let av = (0..matrix.width)
+                    .fold(Complex::ZERO, |acc, j| acc + vector[j][0] * matrix[i][j]);
+                let diff = av - *value * vector[i][0];
+                assert!(diff.abs() < ERROR_TOL, "A v != {value} v");
+            }
+            let length: f64 = vector.into_iter().flatten().map(|c| c.norm_sqr()).sum();
+            assert!((length - 1.0).abs() < ERROR_TOL);
+        }
+    }
+
+    #[test]
+    fn test_symmetric() {
+        let matrix = Arr2D::from(&[[2.0, 8.0, 10.0], [8.0, 4.0, 5.0], [10.0, 5.0, 7.0]]);
+        let pairs = eigen_decomposition(&matrix).unwrap();
+        assert_eigenpairs(&matrix, &pairs);
+        // Matches the dominant eigenvalue from the power method
+        assert!((pairs[0].0.re - 19.88).abs() < 0.01);
+        assert!(pairs.iter().all(|(value, _)| value.im == 0.0));
+        let trace: f64 = pairs.iter().map(|(value, _)| value.re).sum();
+        assert!((trace - 13.0).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_rotation_has_complex_pair() {
+        // Rotation by 90 degrees in the xy-plane, scaled by 2 along z
+        let matrix = Arr2D::from(&[[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 2.0]]);
+        let pairs = eigen_decomposition(&matrix).unwrap();
+        assert_eigenpairs(&matrix, &pairs);
+        assert!((pairs[0].0 - Complex::from(2.0)).abs() < ERROR_TOL);
+        assert!((pairs[1].0.abs() - 1.0).abs() < ERROR_TOL);
+        assert!(pairs[1].0.re.abs() < ERROR_TOL);
+        assert_eq!(pairs[1].0, pairs[2].0.conj());
+    }
+
+    #[test]
+    fn test_non_symmetric() {
+        let matrix = Arr2D::from(&[
+            [4.0, -2.0, 1.0, 3.0],
+            [1.0, 0.5, -1.0, 2.0],
+            [-3.0, 2.0, 1.0, 0.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ]);
+        let pairs = eigen_decomposition(&matrix).unwrap();
+        assert_eigenpairs(&matrix, &pairs);
+        let trace = pairs
+            .iter()
+            .fold(Complex::ZERO, |acc, (value, _)| acc + *value);
+        assert!((trace - Complex::from(4.5)).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_companion_matrix() {
+        // Companion matrix of x^3 - 6x^2 + 11x - 6, with roots 1, 2 and 3
+        let matrix = Arr2D::from(&[[6.0, -11.0, 6.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        let values = eigenvalues(&matrix).unwrap();
+        for (value, expected) in values.iter().zip([3.0, 2.0, 1.0]) {
+            assert!((value.re - expected).abs() < ERROR_TOL);
+            assert_eq!(value.im, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_from_hessenberg_output() {
+        let matrix = Arr2D::from(&[[1.0, 5.0, 7.0], [3.0, 0.0, 6.0], [4.0, 3.0, 1.0]]);
+        let (h, q) = hessenberg_reduction(&matrix).unwrap();
+        let pairs = hessenberg_eigen(&h, &q).unwrap();
+        assert_eigenpairs(&matrix, &pairs);
+    }
+
+    #[test]
+    fn test_small_matrices() {
+        let single = Arr2D::from(&[[5.0]]);
+        let pairs = eigen_decomposition(&single).unwrap();
+        assert_eq!(pairs[0].0, Complex::from(5.0));
+        assert_eigenpairs(&single, &pairs);
+
+        let defective = Arr2D::from(&[[1.0, 1.0], [0.0, 1.0]]);
+        let values = eigenvalues(&defective).unwrap();
+        assert!(values.iter().all(|v| (*v - Complex::ONE).abs() < ERROR_TOL));
+
+        let non_square = Arr2D::from(&[[1.0, 2.0]]);
+        assert!(matches!(
+            eigen_decomposition(&non_square),
+            Err(SolverError::NonSquareMatrix)
+        ));
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        // Every subdiagonal entry is already zero, so each eigenvalue deflates at once
+        for size in [2, 3, 5] {
+            let zero = Arr2D::full(0.0, size, size);
+            let pairs = eigen_decomposition(&zero).unwrap();
+            assert!(pairs.iter().all(|(value, _)| *value == Complex::ZERO));
+            assert_eigenpairs(&zero, &pairs);
+        }
+    }
+}
@@ -0,0 +1,329 @@
+// Eigensolvers for real symmetric matrices. The cyclic Jacobi method is simple and
+// very accurate for small matrices, while Householder tridiagonalization followed by
+// implicit QL iterations (`tqli` in Numerical Recipes) scales better.
+use crate::reduction::matrix::hessenberg_reduction;
+use crate::solvers::SolverError;
+use crate::solvers::decomposition::check_symmetric;
+use crate::solvers::eigen::power_method::Eigenpair;
+use crate::utils::{Arr2D, Arr2DError};
+
+// Matrices up to this size use Jacobi rotations when no method is given
+const JACOBI_MAX_SIZE: usize = 10;
+// Full sweeps over the off-diagonal entries before Jacobi gives up
+const MAX_JACOBI_SWEEPS: usize = 50;
+// QL iterations allowed for each eigenvalue
+const MAX_QL_ITERATIONS: usize = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymmetricMethod {
+    /// Cyclic Jacobi rotations on the full matrix
+    Jacobi,
+    /// Householder reduction to tridiagonal form, then implicit QL iterations
+    TridiagonalQL,
+}
+
+/// Eigenpairs of a symmetric matrix, sorted by decreasing eigenvalue.
+///
+/// Uses the Jacobi method for matrices up to 10×10 and the tridiagonal QL method for
+/// larger ones. See [`symmetric_eigen_with_method`].
+pub fn symmetric_eigen<M>(matrix: M) -> Result<Vec<Eigenpair>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    let method = if matrix.height <= JACOBI_MAX_SIZE {
+        SymmetricMethod::Jacobi
+    } else {
+        SymmetricMethod::TridiagonalQL
+    };
+    symmetric_eigen_with_method(&matrix, method)
+}
+
+/// Eigenpairs of a symmetric matrix using the given method, sorted by decreasing
+/// eigenvalue.
+///
+/// The eigenvectors are orthonormal n×1 columns, with the sign chosen so that the
+/// entry of largest magnitude is positive. Fails with `NonSymmetricMatrix` if the
+/// matrix is not symmetric, and `MaxIterationsReached` if the iterations stall.
+pub fn symmetric_eigen_with_method<M>(
+    matrix: M,
+    method: SymmetricMethod,
+) -> Result<Vec<Eigenpair>, SolverError>
+where
+    M: TryInto<Arr2D<f64>, Error = Arr2DError>,
+{
+    let matrix: Arr2D<f64> = matrix.try_into()?;
+    check_symmetric(&matrix)?;
+
+    let (values, vectors) = match method {
+        SymmetricMethod::Jacobi => jacobi(&matrix)?,
+        SymmetricMethod::TridiagonalQL => tridiagonal_ql(&matrix)?,
+    };
+
+    let size = matrix.height;
+    let mut pairs: Vec<Eigenpair> = values
+        .into_iter()
+        .enumerate()
+        .map(|(j, value)| {
+            let mut vector = Arr2D::full(0.0, size, 1);
+            for i in 0..size {
+                vector[i][0] = vectors[i][j];
+            }
+            let largest = (0..size).fold(0.0, |best: f64, i| {
+                if vector[i][0].abs() > best.abs() {
+                    vector[i][0]
+                } else {
+                    best
+                }
+            });
+            if largest < 0.0 {
+                vector = -vector;
+            }
+            (value, vector)
+        })
+        .collect();
+    pairs.sort_by(|a, b| b.0.total_cmp(&a.0));
+    Ok(pairs)
+}
+
+// Eigenvalues and the matrix with the eigenvectors as columns, by cyclic Jacobi
+// rotations that each zero one off-diagonal entry
+fn jacobi(matrix: &Arr2D<f64>) -> Result<(Vec<f64>, Arr2D<f64>), SolverError> {
+    let size = matrix.height;
+    let mut a = matrix.clone();
+    let mut v: Arr2D<f64> = Arr2D::identity(size);
+    let norm = a.into_iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+
+    let mut sweeps = 0;
+    loop {
+        let off: f64 = (0..size)
+            .flat_map(|i| (0..i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        if off.sqrt() <= f64::EPSILON * norm {
+            break;
+        }
+        if sweeps == MAX_JACOBI_SWEEPS {
+            return Err(SolverError::MaxIterationsReached);
+        }
+        sweeps += 1;
+
+        for p in 0..size {
+            for q in (p + 1)..size {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                // Rotation angle that zeroes a[p][q], taking the smaller root for stability
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = 1.0_f64.copysign(theta) / (theta.abs() + theta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = t * c;
+
+                for k in 0..size {
+                    let (akp, akq) = (a[k][p], a[k][q]);
+                    a[k][p] = c * akp - s * akq;
+                    a[k][q] = s * akp + c * akq;
+                }
+                for k in 0..size {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = c * apk - s * aqk;
+                    a[q][k] = s * apk + c * aqk;
+                }
+                a[p][q] = 0.0;
+                a[q][p] = 0.0;
+                for k in 0..size {
+                    let (vkp, vkq) = (v[k][p], v[k][q]);
+                    v[k][p] = c * vkp - s * vkq;
+                    v[k][q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    Ok(((0..size).map(|i| a[i][i]).collect(), v))
+}
+
+// Eigenvalues and the matrix with the eigenvectors as columns, by implicit QL
+// iterations with Wilkinson shifts on the tridiagonal form
+fn tridiagonal_ql(matrix: &Arr2D<f64>) -> Result<(Vec<f64>, Arr2D<f64>), SolverError> {
+    let size = matrix.height;
+    // For a symmetric matrix the Hessenberg form is tridiagonal
+    let (t, mut z) = hessenberg_reduction(matrix)?;
+    let mut d: Vec<f64> = (0..size).map(|i| t[i][i]).collect();
+    let mut e: Vec<f64> = (0..size)
+        .map(|i| {
+            if i + 1 < size {
+                (t[i + 1][i] + t[i][i + 1]) / 2.0
+            } else {
+                0.0
+            }
+        })
+        .collect();
+
+    for l in 0..size {
+        let mut iterations = 0;
+        loop {
+            // Look for a small subdiagonal element to split the matrix
+            let mut m = l;
+            while m + 1 < size {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            if iterations == MAX_QL_ITERATIONS {
+                return Err(SolverError::MaxIterationsReached);
+            }
+            iterations += 1;
+
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    // Recover from underflow by restarting on the split matrix
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                for k in 0..size {
+                    let f = z[k][i + 1];
+                    z[k][i + 1] = s * z[k][i] + c * f;
+                    z[k][i] = c * z[k][i] - s * f;
+                }
+            }
+            if underflow {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+
+    Ok((d, z))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_TOL: f64 = 1e-10;
+
+    // Checks Av = λv, orthonormality and the ordering of the eigenpairs
+    fn assert_eigenpairs(matrix: &Arr2D<f64>, pairs: &[Eigenpair]) {
+        assert_eq!(pairs.len(), matrix.height);
+        for window in pairs.windows(2) {
+            assert!(window[0].0 >= window[1].0);
+        }
+        for (i, (value, vector)) in pairs.iter().enumerate() {
+            let residual = &(matrix * vector) - &(vector * *value);
+            assert!(residual.into_iter().flatten().all(|r| r.abs() < ERROR_TOL));
+            for (j, (_, other)) in pairs.iter().enumerate() {
+                let dot = (&vector.transpose() * other).as_scalar_unchecked();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < ERROR_TOL);
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_eigenvalues() {
+        let matrix = Arr2D::from(&[[2.0, 1.0], [1.0, 2.0]]);
+        let pairs = symmetric_eigen(&matrix).unwrap();
+        assert_eigenpairs(&matrix, &pairs);
+        assert!((pairs[0].0 - 3.0).abs() < ERROR_TOL);
+        assert!((pairs[1].0 - 1.0).abs() < ERROR_TOL);
+        let root_half = 0.5_f64.sqrt();
+        assert!((pairs[0].1[0][0] - root_half).abs() < ERROR_TOL);
+        assert!((pairs[0].1[1][0] - root_half).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_methods_agree() {
+        let matrix = Arr2D::from(&[[2.0, 8.0, 10.0], [8.0, 4.0, 5.0], [10.0, 5.0, 7.0]]);
+        let jacobi = symmetric_eigen_with_method(&matrix, SymmetricMethod::Jacobi).unwrap();
+        let ql = symmetric_eigen_with_method(&matrix, SymmetricMethod::TridiagonalQL).unwrap();
+        assert_eigenpairs(&matrix, &jacobi);
+        assert_eigenpairs(&matrix, &ql);
+        for ((a, u), (b, v)) in jacobi.iter().zip(&ql) {
+            assert!((a - b).abs() < ERROR_TOL);
+            assert!(
+                u.into_iter()
+                    .flatten()
+                    .zip(v.into_iter().flatten())
+                    .all(|(x, y)| (x - y).abs() < 1e-8)
+            );
+        }
+    }
+
+    #[test]
+    fn test_large_matrix() {
+        // Discrete Laplacian, with eigenvalues 2 - 2cos(kπ / (n + 1))
+        let size = 20;
+        let mut matrix = Arr2D::full(0.0, size, size);
+        for i in 0..size {
+            matrix[i][i] = 2.0;
+            if i + 1 < size {
+                matrix[i][i + 1] = -1.0;
+                matrix[i + 1][i] = -1.0;
+            }
+        }
+        let pairs = symmetric_eigen(&matrix).unwrap();
+        assert_eigenpairs(&matrix, &pairs);
+        for (k, (value, _)) in pairs.iter().enumerate() {
+            let angle = (size - k) as f64 * std::f64::consts::PI / (size + 1) as f64;
+            assert!((value - (2.0 - 2.0 * angle.cos())).abs() < ERROR_TOL);
+        }
+    }
+
+    #[test]
+    fn test_repeated_eigenvalues() {
+        let matrix = Arr2D::from(&[
+            [2.0, 0.0, 0.0, 0.0],
+            [0.0, 3.0, 1.0, 0.0],
+            [0.0, 1.0, 3.0, 0.0],
+            [0.0, 0.0, 0.0, 4.0],
+        ]);
+        for method in [SymmetricMethod::Jacobi, SymmetricMethod::TridiagonalQL] {
+            let pairs = symmetric_eigen_with_method(&matrix, method).unwrap();
+            assert_eigenpairs(&matrix, &pairs);
+            let values: Vec<f64> = pairs.iter().map(|(value, _)| *value).collect();
+            for (value, expected) in values.iter().zip([4.0, 4.0, 2.0, 2.0]) {
+                assert!((value - expected).abs() < ERROR_TOL);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let matrix = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        assert!(matches!(
+            symmetric_eigen(&matrix),
+            Err(SolverError::NonSymmetricMatrix)
+        ));
+        let matrix = Arr2D::from(&[[1.0, 2.0, 3.0]]);
+        assert!(matches!(
+            symmetric_eigen(&matrix),
+            Err(SolverError::NonSquareMatrix)
+        ));
+    }
+}
@@ -0,0 +1,318 @@
+use crate::objective::{Derivative, Objective};
+use crate::solvers::{Bounds, SolverError, interval_roots};
+
+// Pieces the interval is split into when searching for stationary points
+const SEGMENTS: usize = 200;
+// Second derivatives this small (relative to the function's size) are treated as zero
+const CURVATURE_TOL: f64 = 1e-8;
+// Multiple of the rounding error in a numerical second difference that counts as zero
+const NOISE_FACTOR: f64 = 16.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtremumKind {
+    Minimum,
+    Maximum,
+    /// A stationary point that is neither, such as `x^3` at 0
+    Inflection,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extremum {
+    pub x: f64,
+    pub value: f64,
+    pub kind: ExtremumKind,
+}
+
+/// Classifies a stationary point, such as one returned with `SolveMode::Extrema`.
+///
+/// Uses the sign of the second derivative, taken analytically when the objective has
+/// one and from a central second difference otherwise. When the second derivative
+/// vanishes, as for `x^4` at 0, the function values on either side of `x` decide
+/// instead.
+pub fn classify_extremum<O>(function: &O, x: f64) -> Result<ExtremumKind, SolverError>
+where
+    O: Objective,
+{
+    let value = function.eval(x)?;
+    let (second, tol) = match function.derivative_or_numerical()? {
+        Derivative::Analytical(derivative) => (
+            derivative.derivative_or_numerical()?.eval(x)?,
+            CURVATURE_TOL * value.abs().max(1.0),
+        ),
+        Derivative::Numerical(_) => second_difference(function, x, value)?,
+    };
+    if second.abs() > tol {
+        return Ok(if second > 0.0 {
+            ExtremumKind::Minimum
+        } else {
+            ExtremumKind::Maximum
+        });
+    }
+
+    let h = f64::EPSILON.cbrt() * x.abs().max(1.0);
+    let left = function.eval(x - h)? - value;
+    let right = function.eval(x + h)? - value;
+    Ok(if left > 0.0 && right > 0.0 {
+        ExtremumKind::Minimum
+    } else if left < 0.0 && right < 0.0 {
+        ExtremumKind::Maximum
+    } else {
+        ExtremumKind::Inflection
+    })
+}
+
+// (f(x + h) - 2f(x) + f(x - h)) / h² and the rounding error it can hold. Nesting two
+// central differences would use the first derivative's smaller step, and the
+// resulting noise of about |f|·eps/h² swamps the curvature at flat points.
+fn second_difference<O>(function: &O, x: f64, value: f64) -> Result<(f64, f64), SolverError>
+where
+    O: Objective,
+{
+    // Rounding the step to a representable offset keeps x ± h exactly symmetric
+    let h = f64::EPSILON.sqrt().sqrt() * x.abs().max(1.0);
+    let h = (x + h) - x;
+    let left = function.eval(x - h)?;
+    let right = function.eval(x + h)?;
+    let scale = left.abs().max(right.abs()).max(value.abs());
+    Ok((
+        (left - 2.0 * value + right) / (h * h),
+        NOISE_FACTOR * f64::EPSILON * scale / (h * h),
+    ))
+}
+
+/// Finds and classifies every stationary point in `[lower, upper]`.
+///
+/// The stationary points are the roots of the derivative, found with
+/// `interval_roots`, so the same caveats about `segments` apply.
+pub fn classified_extrema<O>(
+    function: &O,
+    lower: f64,
+    upper: f64,
+    segments: usize,
+    error_tol: f64,
+    itermax: usize,
+) -> Result<Vec<Extremum>, SolverError>
+where
+    O: Objective,
+{
+    let derivative = function.derivative_or_numerical()?;
+    interval_roots(&derivative, lower, upper, segments, error_tol, itermax)?
+        .into_iter()
+        .map(|x| {
+            Ok(Extremum {
+                x,
+                value: function.eval(x)?,
+                kind: classify_extremum(function, x)?,
+            })
+        })
+        .collect()
+}
+
+/// Global minimum of a function over `[bounds.lower, bounds.upper]`.
+///
+/// Compares the function at every stationary point in the interval, at both
+/// endpoints and at `bounds.init`. The result is only a stationary point when the
+/// minimum is inside the interval, but its kind is always `Minimum`.
+pub fn global_min<O>(
+    function: &O,
+    bounds: Bounds,
+    error_tol: f64,
+    itermax: usize,
+) -> Result<Extremum, SolverError>
+where
+    O: Objective,
+{
+    global_extremum(function, bounds, error_tol, itermax, ExtremumKind::Minimum)
+}
+
+/// Global maximum of a function over `[bounds.lower, bounds.upper]`.
+/// See [`global_min`].
+pub fn global_max<O>(
+    function: &O,
+    bounds: Bounds,
+    error_tol: f64,
+    itermax: usize,
+) -> Result<Extremum, SolverError>
+where
+    O: Objective,
+{
+    global_extremum(function, bounds, error_tol, itermax, ExtremumKind::Maximum)
+}
+
+fn global_extremum<O>(
+    function: &O,
+    bounds: Bounds,
+    error_tol: f64,
+    itermax: usize,
+    kind: ExtremumKind,
+) -> Result<Extremum, SolverError>
+where
+    O: Objective,
+{
+    if bounds.init < bounds.lower || bounds.init > bounds.upper {
+        return Err(SolverError::XInitOutOfBounds);
+    }
+    let derivative = function.derivative_or_numerical()?;
+    let mut candidates = interval_roots(
+        &derivative,
+        bounds.lower,
+        bounds.upper,
+        SEGMENTS,
+        error_tol,
+        itermax,
+    )?;
+    candidates.extend([bounds.lower, bounds.init, bounds.upper]);
+
+    let mut best: Option<(f64, f64)> = None;
+    for x in candidates {
+        let value = function.eval(x)?;
+        let better = match best {
+            None => true,
+            Some((_, best_value)) => match kind {
+                ExtremumKind::Maximum => value > best_value,
+                _ => value < best_value,
+            },
+        };
+        if better {
+            best = Some((x, value));
+        }
+    }
+    // The endpoints are always candidates
+    let (x, value) = best.unwrap();
+    Ok(Extremum { x, value, kind })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::{Polynomial, PolynomialTraits, SimplePolynomial};
+    use crate::solvers::{SolveMode, newton_raphson_method};
+
+    const ERROR_TOL: f64 = 1e-10;
+
+    fn bounds(lower: f64, upper: f64) -> Bounds {
+        Bounds {
+            lower,
+            init: (lower + upper) / 2.0,
+            upper,
+        }
+    }
+
+    #[test]
+    fn test_classify_polynomial() {
+        let parsed = SimplePolynomial::parse("x^3 - 3x").unwrap();
+        assert_eq!(
+            classify_extremum(&parsed, -1.0).unwrap(),
+            ExtremumKind::Maximum
+        );
+        assert_eq!(
+            classify_extremum(&parsed, 1.0).unwrap(),
+            ExtremumKind::Minimum
+        );
+    }
+
+    #[test]
+    fn test_classify_flat_points() {
+        let quartic = SimplePolynomial::parse("x^4").unwrap();
+        assert_eq!(
+            classify_extremum(&quartic, 0.0).unwrap(),
+            ExtremumKind::Minimum
+        );
+        let cubic = SimplePolynomial::parse("x^3").unwrap();
+        assert_eq!(
+            classify_extremum(&cubic, 0.0).unwrap(),
+            ExtremumKind::Inflection
+        );
+    }
+
+    #[test]
+    fn test_classify_newton_extremum() {
+        let parsed = Polynomial::parse("cos(x)").unwrap();
+        let x = newton_raphson_method(&parsed, 3.0, 100, ERROR_TOL, SolveMode::Extrema).unwrap();
+        assert!((x - std::f64::consts::PI).abs() < 1e-8);
+        assert_eq!(
+            classify_extremum(&parsed, x).unwrap(),
+            ExtremumKind::Minimum
+        );
+    }
+
+    #[test]
+    fn test_classify_closure() {
+        let f = |x: f64| (x - 2.0).powi(2) + 1.0;
+        assert_eq!(classify_extremum(&f, 2.0).unwrap(), ExtremumKind::Minimum);
+        let g = |x: f64| -x.cosh();
+        assert_eq!(classify_extremum(&g, 0.0).unwrap(), ExtremumKind::Maximum);
+    }
+
+    #[test]
+    fn test_classify_shifted_cubic_closure() {
+        // Rounding in a numerical second derivative used to pick a side at these
+        for a in [1.0, 1.1, 3.7, 10.3] {
+            let f = |x: f64| (x - a).powi(3) * 1000.0 + 5.0;
+            assert_eq!(classify_extremum(&f, a).unwrap(), ExtremumKind::Inflection);
+            let g = |x: f64| (x - a).powi(2) * 1000.0 + 5.0;
+            assert_eq!(classify_extremum(&g, a).unwrap(), ExtremumKind::Minimum);
+        }
+    }
+
+    #[test]
+    fn test_classified_extrema() {
+        let parsed = SimplePolynomial::parse("x^4 - 2x^2").unwrap();
+        let extrema = classified_extrema(&parsed, -2.0, 2.0, 40, ERROR_TOL, 100).unwrap();
+        let kinds: Vec<ExtremumKind> = extrema.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ExtremumKind::Minimum,
+                ExtremumKind::Maximum,
+                ExtremumKind::Minimum
+            ]
+        );
+        assert!((extrema[0].x + 1.0).abs() < 1e-8);
+        assert!((extrema[0].value + 1.0).abs() < 1e-8);
+        assert!(extrema[1].x.abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_global_interior() {
+        let parsed = SimplePolynomial::parse("x^4 - 2x^2 + 0.5x").unwrap();
+        let min = global_min(&parsed, bounds(-2.0, 2.0), ERROR_TOL, 100).unwrap();
+        // The left well is deeper because of the linear term
+        assert!(min.x < -1.0 && min.x > -1.2);
+        assert_eq!(min.kind, ExtremumKind::Minimum);
+        let max = global_max(&parsed, bounds(-1.5, 1.5), ERROR_TOL, 100).unwrap();
+        assert!((max.value - parsed.eval(max.x).unwrap()).abs() < 1e-12);
+        assert_eq!(max.x, 1.5);
+    }
+
+    #[test]
+    fn test_global_endpoint() {
+        let f = |x: f64| x.exp();
+        let min = global_min(&f, bounds(-1.0, 1.0), ERROR_TOL, 100).unwrap();
+        let max = global_max(&f, bounds(-1.0, 1.0), ERROR_TOL, 100).unwrap();
+        assert_eq!(min.x, -1.0);
+        assert_eq!(max.x, 1.0);
+    }
+
+    #[test]
+    fn test_global_transcendental() {
+        let parsed = Polynomial::parse("sin(x)").unwrap();
+        let max = global_max(&parsed, bounds(0.0, 10.0), ERROR_TOL, 100).unwrap();
+        assert!((max.value - 1.0).abs() < 1e-12);
+        assert!((max.x - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_global_init_out_of_bounds() {
+        let f = |x: f64| x * x;
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 5.0,
+            upper: 1.0,
+        };
+        assert!(matches!(
+            global_min(&f, bounds, ERROR_TOL, 100),
+            Err(SolverError::XInitOutOfBounds)
+        ));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::objective::Objective;
+use crate::solvers::{Bounds, SolveMode, SolverError, approx_error, bracket_values};
+
+/// Regula falsi with the Illinois modification.
+/// Whenever the same bound is kept twice in a row, its function value is halved so
+/// the interpolated point moves past it instead of creeping in from one side.
+pub fn false_position<O>(
+    function: &O,
+    bounds: Bounds,
+    error_tol: f64,
+    itermax: usize,
+    mode: SolveMode,
+) -> Result<f64, SolverError>
+where
+    O: Objective,
+{
+    let function: &dyn Objective = match mode {
+        SolveMode::Root => function,
+        SolveMode::Extrema => &function.derivative_or_numerical()?,
+    };
+    let (mut f_lower, mut f_upper) = bracket_values(function, &bounds)?;
+    if f_lower == 0.0 {
+        return Ok(bounds.lower);
+    }
+    if f_upper == 0.0 {
+        return Ok(bounds.upper);
+    }
+
+    let mut iter = 0;
+    let mut lower_bound = bounds.lower;
+    let mut upper_bound = bounds.upper;
+    let mut x_curr = bounds.init;
+    // -1 when the upper bound was replaced last, 1 for the lower bound
+    let mut side = 0;
+    loop {
+        let x_old = x_curr;
+        x_curr = (lower_bound * f_upper - upper_bound * f_lower) / (f_upper - f_lower);
+        let f_curr = function.eval(x_curr)?;
+        let mut approx_err = approx_error(x_curr, x_old);
+
+        if f_curr * f_upper > 0.0 {
+            upper_bound = x_curr;
+            f_upper = f_curr;
+            if side == -1 {
+                f_lower /= 2.0;
+            }
+            side = -1;
+        } else if f_curr * f_lower > 0.0 {
+            lower_bound = x_curr;
+            f_lower = f_curr;
+            if side == 1 {
+                f_upper /= 2.0;
+            }
+            side = 1;
+        } else {
+            approx_err = 0.0;
+        }
+        iter += 1;
+        if approx_err < error_tol || iter >= itermax {
+            break;
+        }
+    }
+    if iter >= itermax {
+        return Err(SolverError::MaxIterationsReached);
+    }
+
+    let poss_sol = function.eval(x_curr)?;
+    if poss_sol.abs() < 1e-4 {
+        Ok(x_curr)
+    } else {
+        Err(SolverError::NoConvergence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::{IntermediatePolynomial, PolynomialTraits, SimplePolynomial};
+
+    const ERROR_TOL: f64 = 0.00001;
+
+    fn approx_eq(a: f64, b: f64, tol: f64) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn test_root_success() {
+        let parsed = SimplePolynomial::parse("x^2 - 4").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 1.0,
+            upper: 3.0,
+        };
+        let root = false_position(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 2.0, ERROR_TOL));
+    }
+
+    #[test]
+    fn test_root_success_intermediate_poly() {
+        let parsed = IntermediatePolynomial::parse("x^3 - 2x - 5").unwrap();
+        let bounds = Bounds {
+            lower: 2.0,
+            init: 2.0,
+            upper: 3.0,
+        };
+        let root = false_position(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 2.0945514815, 1e-6));
+    }
+
+    #[test]
+    fn test_illinois_beats_one_sided_convergence() {
+        // Plain regula falsi keeps the upper bound fixed here and needs hundreds of steps
+        let parsed = SimplePolynomial::parse("x^10 - 1").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 0.5,
+            upper: 1.3,
+        };
+        let root = false_position(&parsed, bounds, 1e-8, 50, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 1.0, 1e-8));
+    }
+
+    #[test]
+    fn test_extrema_success() {
+        // f(x) = -x^2 + 4x + 1 has a maximum at x = 2
+        let parsed = SimplePolynomial::parse("-1x^2 + 4x + 1").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 1.0,
+            upper: 5.0,
+        };
+        let max_x = false_position(&parsed, bounds, ERROR_TOL, 100, SolveMode::Extrema).unwrap();
+        assert!(approx_eq(max_x, 2.0, ERROR_TOL));
+    }
+
+    #[test]
+    fn test_root_on_bound() {
+        let parsed = SimplePolynomial::parse("x^2 - 1").unwrap();
+        let bounds = Bounds {
+            lower: -1.0,
+            init: -0.5,
+            upper: 0.0,
+        };
+        let root = false_position(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root).unwrap();
+        assert_eq!(root, -1.0);
+    }
+
+    #[test]
+    fn test_no_sign_change() {
+        let parsed = SimplePolynomial::parse("x^2 + 10").unwrap();
+        let bounds = Bounds {
+            lower: -1.0,
+            init: 0.0,
+            upper: 1.0,
+        };
+        let result = false_position(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root);
+        assert!(matches!(result, Err(SolverError::NoConvergence)));
+    }
+
+    #[test]
+    fn test_invalid_init() {
+        let parsed = SimplePolynomial::parse("x^2 - 4").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 4.0,
+            upper: 3.0,
+        };
+        let result = false_position(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root);
+        assert!(matches!(result, Err(SolverError::XInitOutOfBounds)));
+    }
+}
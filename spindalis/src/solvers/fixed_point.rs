@@ -0,0 +1,91 @@
+use crate::objective::Objective;
+use crate::solvers::SolverError;
+
+/// Simple fixed-point iteration.
+/// Finds `x` such that `x = g(x)`, where `g` is a rearrangement of `f(x) = 0`.
+/// The iteration converges when `|g'(x)| < 1` near the fixed point, and diverging
+/// iterates are reported as `NoConvergence`.
+///
+/// Unlike the other solvers this takes `g` rather than `f`, so there is no
+/// `SolveMode`: to find an extremum, pass a rearrangement of `f'(x) = 0` instead.
+pub fn fixed_point_iteration<O>(
+    function: &O,
+    x_init: f64,
+    itermax: usize,
+    error_tol: f64,
+) -> Result<f64, SolverError>
+where
+    O: Objective,
+{
+    let mut iter = 0;
+    let mut x_curr = x_init;
+    let mut approx_err = 100_f64;
+    loop {
+        let xr_old = x_curr;
+        x_curr = function.eval(xr_old)?;
+        if !x_curr.is_finite() {
+            return Err(SolverError::NoConvergence);
+        }
+        iter += 1;
+        if x_curr != 0 as f64 {
+            approx_err = ((x_curr - xr_old).abs() / x_curr) * 100.0;
+        }
+        if approx_err.abs() < error_tol || iter >= itermax {
+            break;
+        }
+    }
+    if iter >= itermax {
+        return Err(SolverError::MaxIterationsReached);
+    }
+    Ok(x_curr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::{
+        IntermediatePolynomial, Polynomial, PolynomialTraits, SimplePolynomial,
+    };
+
+    const ERROR_TOL: f64 = 0.0001;
+
+    #[test]
+    fn test_fixed_point() {
+        // x = e^(-x) has its fixed point at 0.56714
+        let parsed = Polynomial::parse("e^(-x)").unwrap();
+        let x = fixed_point_iteration(&parsed, 0.0, 100, ERROR_TOL).unwrap();
+        assert!((x - 0.5671432904).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_root_via_rearrangement() {
+        // x^2 - 2x - 3 = 0 rearranged as x = (2x + 3)^(1/2)
+        let parsed = Polynomial::parse("(2x + 3)^0.5").unwrap();
+        let x = fixed_point_iteration(&parsed, 4.0, 100, ERROR_TOL).unwrap();
+        assert!((x - 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_intermediate_poly() {
+        // x = x^(-1) + 0.5 has its fixed point at (1 + 17^(1/2)) / 4
+        let parsed = IntermediatePolynomial::parse("x^-1 + 0.5").unwrap();
+        let x = fixed_point_iteration(&parsed, 1.0, 100, ERROR_TOL).unwrap();
+        assert!((x - (1.0 + 17.0_f64.sqrt()) / 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_diverges() {
+        // g'(x) = 2 at the fixed point x = 1, so iterates run away from it
+        let parsed = SimplePolynomial::parse("x^2").unwrap();
+        let result = fixed_point_iteration(&parsed, 1.5, 100, ERROR_TOL);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        // g'(x) = -1 so the iterates oscillate between 1 and 3 forever
+        let parsed = SimplePolynomial::parse("4 - x").unwrap();
+        let result = fixed_point_iteration(&parsed, 1.0, 100, ERROR_TOL);
+        assert!(matches!(result, Err(SolverError::MaxIterationsReached)));
+    }
+}
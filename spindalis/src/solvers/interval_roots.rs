@@ -0,0 +1,274 @@
+use crate::objective::Objective;
+use crate::polynomials::{PolynomialError, SimplePolynomial};
+use crate::solvers::{Bounds, SolveMode, SolverError, brent};
+
+// A stationary point counts as an even-multiplicity root when |f| there is below
+// this fraction of the largest |f| seen on the grid
+const TOUCH_TOL: f64 = 1e-9;
+// Roots closer than this (relative to their size) are reported once
+const MERGE_TOL: f64 = 1e-7;
+
+/// Finds every real root of a function in `[lower, upper]`.
+///
+/// The interval is split into `segments` equal pieces. Each piece where the function
+/// changes sign is refined with Brent's method. Roots of even multiplicity, where
+/// the function touches zero without crossing it, are found the same way from
+/// the sign changes of the derivative. A root can still be missed if two of them
+/// fall in the same piece, so use enough segments to separate them.
+///
+/// Sign changes across a pole are discarded. Roots are returned sorted.
+pub fn interval_roots<O>(
+    function: &O,
+    lower: f64,
+    upper: f64,
+    segments: usize,
+    error_tol: f64,
+    itermax: usize,
+) -> Result<Vec<f64>, SolverError>
+where
+    O: Objective,
+{
+    let (lower, upper) = (lower.min(upper), lower.max(upper));
+    let segments = segments.max(1);
+    let width = (upper - lower) / segments as f64;
+    let grid: Vec<f64> = (0..=segments)
+        .map(|i| {
+            if i == segments {
+                upper
+            } else {
+                lower + width * i as f64
+            }
+        })
+        .collect();
+
+    let mut roots = Vec::new();
+    let values = grid
+        .iter()
+        .map(|&x| function.eval(x))
+        .collect::<Result<Vec<f64>, PolynomialError>>()?;
+    roots.extend(bracketed_roots(
+        function, &grid, &values, error_tol, itermax,
+    )?);
+
+    // Stationary points where the function is zero are roots it only touches
+    let scale = values.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    let touch_tol = TOUCH_TOL * if scale > 0.0 { scale } else { 1.0 };
+    let derivative = function.derivative_or_numerical()?;
+    let slopes = grid
+        .iter()
+        .map(|&x| derivative.eval(x))
+        .collect::<Result<Vec<f64>, PolynomialError>>()?;
+    for x in bracketed_roots(&derivative, &grid, &slopes, error_tol, itermax)? {
+        if function.eval(x)?.abs() <= touch_tol {
+            roots.push(x);
+        }
+    }
+
+    Ok(sorted_unique(roots))
+}
+
+/// Finds every distinct real root of a `SimplePolynomial` in `[lower, upper]`.
+///
+/// Roots are isolated with the Sturm sequence of the polynomial's square-free part,
+/// which counts the roots in an interval exactly. Intervals are halved until each
+/// one holds a single root, which is then refined with Brent's method. Because the
+/// square-free part only has simple roots, roots of any multiplicity are found.
+pub fn interval_roots_sturm(
+    polynomial: &SimplePolynomial,
+    lower: f64,
+    upper: f64,
+    error_tol: f64,
+    itermax: usize,
+) -> Result<Vec<f64>, SolverError> {
+    if polynomial.degree().is_none() {
+        // Every point is a root of the zero polynomial
+        return Err(SolverError::FunctionError(PolynomialError::ZeroPolynomial));
+    }
+    let (lower, upper) = (lower.min(upper), lower.max(upper));
+    let derivative = crate::derivatives::simple_derivative(polynomial);
+    let (square_free, _) = polynomial.div_rem(&polynomial.gcd(&derivative))?;
+    let square_free = square_free.monic();
+
+    let mut roots = Vec::new();
+    // Sturm counts cover (lower, upper], so the lower bound is checked separately
+    if square_free.eval(lower)? == 0.0 {
+        roots.push(lower);
+    }
+
+    let sequence = square_free.sturm_sequence();
+    let count = |a, b| SimplePolynomial::count_real_roots_with(&sequence, a, b);
+    let mut intervals = vec![(lower, upper, count(lower, upper))];
+    while let Some((a, b, roots_inside)) = intervals.pop() {
+        if roots_inside == 0 {
+            continue;
+        }
+        let mid = (a + b) / 2.0;
+        if roots_inside == 1 {
+            roots.push(refine(&square_free, a, b, error_tol, itermax)?);
+        } else if b - a <= f64::EPSILON * a.abs().max(b.abs()).max(1.0) {
+            // Roots too close together to separate in floating point
+            roots.push(mid);
+        } else {
+            let left = count(a, mid);
+            intervals.push((a, mid, left));
+            intervals.push((mid, b, roots_inside.saturating_sub(left)));
+        }
+    }
+
+    Ok(sorted_unique(roots))
+}
+
+// Refines each grid piece whose endpoints have opposite signs, plus grid points
+// that are exact roots
+fn bracketed_roots<O>(
+    function: &O,
+    grid: &[f64],
+    values: &[f64],
+    error_tol: f64,
+    itermax: usize,
+) -> Result<Vec<f64>, SolverError>
+where
+    O: Objective,
+{
+    let mut roots = Vec::new();
+    for (i, &value) in values.iter().enumerate() {
+        if value == 0.0 {
+            roots.push(grid[i]);
+        }
+        if i + 1 < values.len() && value * values[i + 1] < 0.0 {
+            let bounds = Bounds {
+                lower: grid[i],
+                init: (grid[i] + grid[i + 1]) / 2.0,
+                upper: grid[i + 1],
+            };
+            match brent(function, bounds, error_tol, itermax, SolveMode::Root) {
+                Ok(root) => roots.push(root),
+                // The sign change was a pole or jump rather than a root
+                Err(SolverError::NoConvergence) => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    Ok(roots)
+}
+
+// Brent's method on the single simple root in (a, b]
+fn refine(
+    polynomial: &SimplePolynomial,
+    a: f64,
+    b: f64,
+    error_tol: f64,
+    itermax: usize,
+) -> Result<f64, SolverError> {
+    if polynomial.eval(b)? == 0.0 {
+        return Ok(b);
+    }
+    let bounds = Bounds {
+        lower: a,
+        init: (a + b) / 2.0,
+        upper: b,
+    };
+    brent(polynomial, bounds, error_tol, itermax, SolveMode::Root)
+}
+
+fn sorted_unique(mut roots: Vec<f64>) -> Vec<f64> {
+    roots.sort_by(f64::total_cmp);
+    roots.dedup_by(|b, a| (*b - *a).abs() <= MERGE_TOL * a.abs().max(1.0));
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::{IntermediatePolynomial, Polynomial, PolynomialTraits};
+
+    const ERROR_TOL: f64 = 1e-10;
+
+    fn assert_roots(found: &[f64], expected: &[f64], tol: f64) {
+        assert_eq!(found.len(), expected.len(), "found {found:?}");
+        for (x, e) in found.iter().zip(expected) {
+            assert!(
+                (x - e).abs() < tol,
+                "found {found:?}, expected {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_simple_roots() {
+        let parsed = SimplePolynomial::parse("x^3 - 6x^2 + 11x - 6").unwrap();
+        let roots = interval_roots(&parsed, 0.0, 4.0, 50, ERROR_TOL, 100).unwrap();
+        assert_roots(&roots, &[1.0, 2.0, 3.0], 1e-8);
+    }
+
+    #[test]
+    fn test_even_multiplicity() {
+        // (x - 1)^2 (x + 2) touches zero at 1 and crosses at -2
+        let parsed = IntermediatePolynomial::parse("x^3 - 3x + 2").unwrap();
+        let roots = interval_roots(&parsed, -3.0, 3.0, 60, ERROR_TOL, 100).unwrap();
+        assert_roots(&roots, &[-2.0, 1.0], 1e-6);
+    }
+
+    #[test]
+    fn test_transcendental() {
+        let parsed = Polynomial::parse("sin(x)").unwrap();
+        let roots = interval_roots(&parsed, -1.0, 10.0, 100, ERROR_TOL, 100).unwrap();
+        let pi = std::f64::consts::PI;
+        assert_roots(&roots, &[0.0, pi, 2.0 * pi, 3.0 * pi], 1e-8);
+    }
+
+    #[test]
+    fn test_closure_with_touching_root() {
+        let f = |x: f64| x.cos() + 1.0;
+        let roots = interval_roots(&f, 0.0, 6.0, 40, ERROR_TOL, 100).unwrap();
+        assert_roots(&roots, &[std::f64::consts::PI], 1e-6);
+    }
+
+    #[test]
+    fn test_pole_is_not_a_root() {
+        let f = |x: f64| 1.0 / (x - 0.5);
+        let roots = interval_roots(&f, 0.0, 1.0, 10, ERROR_TOL, 100).unwrap();
+        assert!(roots.is_empty());
+    }
+
+    #[test]
+    fn test_sturm_close_roots() {
+        // Roots 1e-3 apart are too close for a coarse grid but Sturm separates them
+        let parsed = SimplePolynomial::parse("x^2 - 2.001x + 1.001").unwrap();
+        let grid = interval_roots(&parsed, 0.0, 3.0, 3, ERROR_TOL, 100).unwrap();
+        assert!(grid.len() < 2);
+        let roots = interval_roots_sturm(&parsed, 0.0, 3.0, ERROR_TOL, 100).unwrap();
+        assert_roots(&roots, &[1.0, 1.001], 1e-9);
+    }
+
+    #[test]
+    fn test_sturm_multiple_roots() {
+        // (x - 1)^3 (x + 2)^2 x
+        let parsed = SimplePolynomial::parse("x^6 + x^5 - 5x^4 - x^3 + 8x^2 - 4x").unwrap();
+        let roots = interval_roots_sturm(&parsed, -5.0, 5.0, ERROR_TOL, 100).unwrap();
+        assert_roots(&roots, &[-2.0, 0.0, 1.0], 1e-6);
+    }
+
+    #[test]
+    fn test_sturm_root_on_bounds() {
+        let parsed = SimplePolynomial::parse("x^2 - 1").unwrap();
+        let roots = interval_roots_sturm(&parsed, -1.0, 1.0, ERROR_TOL, 100).unwrap();
+        assert_roots(&roots, &[-1.0, 1.0], 1e-12);
+    }
+
+    #[test]
+    fn test_sturm_no_roots() {
+        let parsed = SimplePolynomial::parse("x^4 + 1").unwrap();
+        let roots = interval_roots_sturm(&parsed, -10.0, 10.0, ERROR_TOL, 100).unwrap();
+        assert!(roots.is_empty());
+    }
+
+    #[test]
+    fn test_zero_polynomial() {
+        let parsed = SimplePolynomial::parse("0").unwrap();
+        assert!(matches!(
+            interval_roots_sturm(&parsed, -1.0, 1.0, ERROR_TOL, 100),
+            Err(SolverError::FunctionError(PolynomialError::ZeroPolynomial))
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+// Biconjugate gradient stabilized method of van der Vorst, with right preconditioning
+// so that the residual it tracks is the residual of the original system
+use crate::convergence::{SolveReport, SolverOptions, Termination};
+use crate::solvers::SolverError;
+use crate::solvers::iterative::preconditioner::Preconditioner;
+use crate::solvers::iterative::{
+    LinearOperator, Monitor, axpy, dot, initial_guess, norm, residual,
+};
+
+/// BiCGSTAB for general (non-symmetric) square systems.
+///
+/// Each iteration costs two products with A and two applications of the
+/// preconditioner, with short recurrences so the memory use does not grow. The method
+/// can break down when an inner product vanishes, in which case it stops with
+/// `Termination::NoConvergence`; restarting from the returned solution or switching
+/// to `gmres` usually helps.
+pub fn bicgstab<A>(
+    operator: &A,
+    rhs: &[f64],
+    x_init: Option<&[f64]>,
+    preconditioner: Option<&dyn Preconditioner>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Vec<f64>>, SolverError>
+where
+    A: LinearOperator + ?Sized,
+{
+    let mut x = initial_guess(operator, rhs, x_init)?;
+    let precondition = |r: &[f64]| match preconditioner {
+        Some(m) => m.apply(r),
+        None => r.to_vec(),
+    };
+    let mut monitor = Monitor::new(options, rhs, &x);
+
+    let mut r = residual(operator, rhs, &x);
+    if norm(&r) == 0.0 {
+        return Ok(monitor.finish(x, Termination::FunctionTolerance));
+    }
+    // Shadow residual, fixed for the whole run
+    let r_hat = r.clone();
+    let size = x.len();
+    let (mut rho, mut alpha, mut omega) = (1.0, 1.0, 1.0);
+    let mut v = vec![0.0; size];
+    let mut p = vec![0.0; size];
+    loop {
+        let rho_next = dot(&r_hat, &r);
+        if rho_next == 0.0 || omega == 0.0 {
+            return Ok(monitor.finish(x, Termination::NoConvergence));
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        for i in 0..size {
+            p[i] = r[i] + beta * (p[i] - omega * v[i]);
+        }
+
+        let p_hat = precondition(&p);
+        v = operator.apply(&p_hat);
+        let r_hat_v = dot(&r_hat, &v);
+        if r_hat_v == 0.0 {
+            return Ok(monitor.finish(x, Termination::NoConvergence));
+        }
+        alpha = rho / r_hat_v;
+        let mut s = r.clone();
+        axpy(-alpha, &v, &mut s);
+        axpy(alpha, &p_hat, &mut x);
+
+        // The half step already solved the system
+        if norm(&s) == 0.0 {
+            let termination = monitor
+                .check(&x, 0.0)
+                .unwrap_or(Termination::FunctionTolerance);
+            return Ok(monitor.finish(x, termination));
+        }
+
+        let s_hat = precondition(&s);
+        let t = operator.apply(&s_hat);
+        let tt = dot(&t, &t);
+        omega = if tt == 0.0 { 0.0 } else { dot(&t, &s) / tt };
+        axpy(omega, &s_hat, &mut x);
+        r = s;
+        axpy(-omega, &t, &mut r);
+        if let Some(termination) = monitor.check(&x, norm(&r)) {
+            return Ok(monitor.finish(x, termination));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::preconditioner::JacobiPreconditioner;
+    use crate::solvers::iterative::test_systems::{
+        assert_close, convection_diffusion, manufactured,
+    };
+    use crate::utils::Arr2D;
+
+    fn options() -> SolverOptions<'static> {
+        SolverOptions {
+            rel_tol: 0.0,
+            fn_tol: 1e-12,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_convection_diffusion() {
+        let matrix = convection_diffusion(60);
+        let (rhs, exact) = manufactured(&matrix);
+        let report = bicgstab(&matrix, &rhs, None, None, &mut options()).unwrap();
+        assert!(report.converged());
+        assert_close(&report.solution, &exact, 1e-9);
+        assert_eq!(report.history.len(), report.iterations);
+    }
+
+    #[test]
+    fn test_preconditioned_and_initial_guess() {
+        let mut matrix = convection_diffusion(40);
+        for i in 0..40 {
+            matrix[i][i] *= 1.0 + i as f64;
+        }
+        let (rhs, exact) = manufactured(&matrix);
+        let jacobi = JacobiPreconditioner::new(&matrix).unwrap();
+        let report = bicgstab(&matrix, &rhs, None, Some(&jacobi), &mut options()).unwrap();
+        assert!(report.converged());
+        assert_close(&report.solution, &exact, 1e-9);
+
+        // Starting from the solution needs no iterations
+        let report = bicgstab(&matrix, &rhs, Some(&exact), None, &mut options()).unwrap();
+        assert!(report.iterations <= 1);
+    }
+
+    #[test]
+    fn test_non_square() {
+        let matrix = Arr2D::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert!(matches!(
+            bicgstab(&matrix, &[1.0, 2.0], None, None, &mut options()),
+            Err(SolverError::NonSquareMatrix)
+        ));
+    }
+}
@@ -0,0 +1,152 @@
+use crate::convergence::{SolveReport, SolverOptions, Termination};
+use crate::solvers::SolverError;
+use crate::solvers::iterative::preconditioner::Preconditioner;
+use crate::solvers::iterative::{
+    LinearOperator, Monitor, axpy, dot, initial_guess, norm, residual,
+};
+
+/// Preconditioned conjugate gradient method for symmetric positive definite systems.
+///
+/// Each iteration costs one product with A and one application of the preconditioner,
+/// and in exact arithmetic the method finishes in at most n iterations. The
+/// preconditioner must also be symmetric positive definite. If a search direction has
+/// `pᵀAp <= 0` the matrix is not positive definite and the method stops with
+/// `Termination::NoConvergence`.
+pub fn conjugate_gradient<A>(
+    operator: &A,
+    rhs: &[f64],
+    x_init: Option<&[f64]>,
+    preconditioner: Option<&dyn Preconditioner>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Vec<f64>>, SolverError>
+where
+    A: LinearOperator + ?Sized,
+{
+    let mut x = initial_guess(operator, rhs, x_init)?;
+    let precondition = |r: &[f64]| match preconditioner {
+        Some(m) => m.apply(r),
+        None => r.to_vec(),
+    };
+    let mut monitor = Monitor::new(options, rhs, &x);
+
+    let mut r = residual(operator, rhs, &x);
+    if norm(&r) == 0.0 {
+        return Ok(monitor.finish(x, Termination::FunctionTolerance));
+    }
+    let mut z = precondition(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    loop {
+        let ap = operator.apply(&p);
+        let curvature = dot(&p, &ap);
+        if curvature <= 0.0 || curvature.is_nan() {
+            return Ok(monitor.finish(x, Termination::NoConvergence));
+        }
+        let alpha = rz / curvature;
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &ap, &mut r);
+        if let Some(termination) = monitor.check(&x, norm(&r)) {
+            return Ok(monitor.finish(x, termination));
+        }
+
+        z = precondition(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for (p, z) in p.iter_mut().zip(&z) {
+            *p = z + beta * *p;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::FnOperator;
+    use crate::solvers::iterative::preconditioner::{JacobiPreconditioner, SsorPreconditioner};
+    use crate::solvers::iterative::test_systems::{assert_close, manufactured, poisson};
+    use crate::utils::{Arr2D, CscMatrix, CsrMatrix};
+
+    fn options() -> SolverOptions<'static> {
+        SolverOptions {
+            rel_tol: 0.0,
+            fn_tol: 1e-12,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diffusion() {
+        let matrix = poisson(50);
+        let (rhs, exact) = manufactured(&matrix);
+        let report = conjugate_gradient(&matrix, &rhs, None, None, &mut options()).unwrap();
+        assert!(report.converged());
+        assert!(report.iterations <= 50);
+        assert_close(&report.solution, &exact, 1e-9);
+    }
+
+    #[test]
+    fn test_sparse_matrices() {
+        let dense = poisson(50);
+        let (rhs, exact) = manufactured(&dense);
+        let csr = CsrMatrix::from(&dense);
+        let jacobi = JacobiPreconditioner::new(&csr).unwrap();
+        let report = conjugate_gradient(&csr, &rhs, None, Some(&jacobi), &mut options()).unwrap();
+        assert!(report.converged());
+        assert_close(&report.solution, &exact, 1e-9);
+
+        let csc = CscMatrix::from(&dense);
+        let report = conjugate_gradient(&csc, &rhs, None, None, &mut options()).unwrap();
+        assert!(report.converged());
+        assert_close(&report.solution, &exact, 1e-9);
+    }
+
+    #[test]
+    fn test_matrix_free() {
+        // The same diffusion operator as a three point stencil
+        let size = 50;
+        let stencil = FnOperator::new(size, |x: &[f64]| {
+            (0..x.len())
+                .map(|i| {
+                    let left = if i > 0 { x[i - 1] } else { 0.0 };
+                    let right = x.get(i + 1).copied().unwrap_or(0.0);
+                    2.0 * x[i] - left - right
+                })
+                .collect()
+        });
+        let (rhs, exact) = manufactured(&poisson(size));
+        let report = conjugate_gradient(&stencil, &rhs, None, None, &mut options()).unwrap();
+        assert!(report.converged());
+        assert_close(&report.solution, &exact, 1e-9);
+    }
+
+    #[test]
+    fn test_preconditioners() {
+        // Badly scaled diagonal, which the Jacobi preconditioner undoes
+        let size = 40;
+        let mut matrix = poisson(size);
+        for i in 0..size {
+            matrix[i][i] += (i * i) as f64;
+        }
+        let (rhs, exact) = manufactured(&matrix);
+        let plain = conjugate_gradient(&matrix, &rhs, None, None, &mut options()).unwrap();
+        let jacobi = JacobiPreconditioner::new(&matrix).unwrap();
+        let with_jacobi =
+            conjugate_gradient(&matrix, &rhs, None, Some(&jacobi), &mut options()).unwrap();
+        let ssor = SsorPreconditioner::new(&matrix, 1.2).unwrap();
+        let with_ssor =
+            conjugate_gradient(&matrix, &rhs, None, Some(&ssor), &mut options()).unwrap();
+        assert!(with_jacobi.converged() && with_ssor.converged());
+        assert_close(&with_jacobi.solution, &exact, 1e-9);
+        assert_close(&with_ssor.solution, &exact, 1e-9);
+        assert!(with_jacobi.iterations < plain.iterations);
+        assert!(with_ssor.iterations < plain.iterations);
+    }
+
+    #[test]
+    fn test_not_positive_definite() {
+        let matrix = Arr2D::from(&[[1.0, 2.0], [2.0, 1.0]]);
+        let report = conjugate_gradient(&matrix, &[1.0, -1.0], None, None, &mut options());
+        assert_eq!(report.unwrap().termination, Termination::NoConvergence);
+    }
+}
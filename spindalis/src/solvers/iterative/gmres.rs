@@ -0,0 +1,170 @@
+// Restarted GMRES with right preconditioning, following Saad, Iterative Methods for
+// Sparse Linear Systems, algorithm 9.5. The Arnoldi basis uses modified Gram–Schmidt
+// and the small least squares problem is kept triangular with Givens rotations.
+use crate::convergence::{SolveReport, SolverOptions, Termination};
+use crate::solvers::SolverError;
+use crate::solvers::iterative::preconditioner::Preconditioner;
+use crate::solvers::iterative::{
+    LinearOperator, Monitor, axpy, dot, initial_guess, norm, residual,
+};
+
+/// GMRES(m) for general square systems, restarted every `restart` iterations.
+///
+/// Each iteration minimises the residual over a growing Krylov subspace, so the
+/// residual history never increases within a cycle. Storage and work per iteration
+/// grow with the subspace, which restarting bounds at `restart` vectors; a small
+/// `restart` can stall on hard problems. A `restart` of 0 is treated as no restarts.
+pub fn gmres<A>(
+    operator: &A,
+    rhs: &[f64],
+    restart: usize,
+    x_init: Option<&[f64]>,
+    preconditioner: Option<&dyn Preconditioner>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Vec<f64>>, SolverError>
+where
+    A: LinearOperator + ?Sized,
+{
+    let mut x = initial_guess(operator, rhs, x_init)?;
+    let size = x.len();
+    let restart = if restart == 0 {
+        size
+    } else {
+        restart.min(size)
+    };
+    let precondition = |r: &[f64]| match preconditioner {
+        Some(m) => m.apply(r),
+        None => r.to_vec(),
+    };
+    let mut monitor = Monitor::new(options, rhs, &x);
+
+    loop {
+        let r = residual(operator, rhs, &x);
+        let beta = norm(&r);
+        if beta == 0.0 {
+            return Ok(monitor.finish(x, Termination::FunctionTolerance));
+        }
+
+        let mut basis: Vec<Vec<f64>> = vec![r.iter().map(|r| r / beta).collect()];
+        // Upper Hessenberg matrix, stored by column and reduced to triangular in place
+        let mut hessenberg: Vec<Vec<f64>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(f64, f64)> = Vec::with_capacity(restart);
+        let mut g = vec![0.0; restart + 1];
+        g[0] = beta;
+        let x_start = x.clone();
+
+        for j in 0..restart {
+            let mut w = operator.apply(&precondition(&basis[j]));
+            let mut column = vec![0.0; j + 2];
+            for (i, v) in basis.iter().enumerate() {
+                column[i] = dot(&w, v);
+                axpy(-column[i], v, &mut w);
+            }
+            column[j + 1] = norm(&w);
+
+            // Earlier rotations, then a new one to zero the subdiagonal entry
+            for (i, (c, s)) in rotations.iter().enumerate() {
+                let (a, b) = (column[i], column[i + 1]);
+                column[i] = c * a + s * b;
+                column[i + 1] = -s * a + c * b;
+            }
+            let radius = column[j].hypot(column[j + 1]);
+            let (c, s) = if radius == 0.0 {
+                (1.0, 0.0)
+            } else {
+                (column[j] / radius, column[j + 1] / radius)
+            };
+            let subdiagonal = column[j + 1];
+            column[j] = radius;
+            column[j + 1] = 0.0;
+            g[j + 1] = -s * g[j];
+            g[j] *= c;
+            rotations.push((c, s));
+            hessenberg.push(column);
+
+            // x = x_0 + M⁻¹Vy, where Ry = g
+            let mut y = vec![0.0; j + 1];
+            for i in (0..=j).rev() {
+                let sum: f64 = ((i + 1)..=j).map(|k| hessenberg[k][i] * y[k]).sum();
+                y[i] = if hessenberg[i][i] == 0.0 {
+                    0.0
+                } else {
+                    (g[i] - sum) / hessenberg[i][i]
+                };
+            }
+            let mut update = vec![0.0; size];
+            for (v, y) in basis.iter().zip(&y) {
+                axpy(*y, v, &mut update);
+            }
+            x = x_start.clone();
+            axpy(1.0, &precondition(&update), &mut x);
+
+            if let Some(termination) = monitor.check(&x, g[j + 1].abs()) {
+                return Ok(monitor.finish(x, termination));
+            }
+            // The Krylov subspace is invariant, so x is exact up to rounding
+            if subdiagonal == 0.0 {
+                break;
+            }
+            basis.push(w.iter().map(|w| w / subdiagonal).collect());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::preconditioner::JacobiPreconditioner;
+    use crate::solvers::iterative::test_systems::{
+        assert_close, convection_diffusion, manufactured, poisson,
+    };
+
+    fn options() -> SolverOptions<'static> {
+        SolverOptions {
+            rel_tol: 0.0,
+            fn_tol: 1e-12,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_full_gmres() {
+        let matrix = convection_diffusion(30);
+        let (rhs, exact) = manufactured(&matrix);
+        let report = gmres(&matrix, &rhs, 0, None, None, &mut options()).unwrap();
+        assert!(report.converged());
+        assert!(report.iterations <= 30);
+        assert_close(&report.solution, &exact, 1e-9);
+        // Residuals never increase without restarts
+        for pair in report.history.windows(2) {
+            assert!(pair[1] <= pair[0] * (1.0 + 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_restarted() {
+        let matrix = convection_diffusion(60);
+        let (rhs, exact) = manufactured(&matrix);
+        let jacobi = JacobiPreconditioner::new(&matrix).unwrap();
+        let report = gmres(&matrix, &rhs, 10, None, Some(&jacobi), &mut options()).unwrap();
+        assert!(report.converged());
+        assert_close(&report.solution, &exact, 1e-9);
+
+        // The residual estimate from the rotations matches the true residual
+        let true_residual = norm(&residual(&matrix, &rhs, &report.solution)) / norm(&rhs);
+        assert!((true_residual - report.residual).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_iteration_cap() {
+        let matrix = poisson(100);
+        let (rhs, _) = manufactured(&matrix);
+        let mut options = SolverOptions {
+            itermax: 5,
+            ..options()
+        };
+        let report = gmres(&matrix, &rhs, 2, None, None, &mut options).unwrap();
+        assert_eq!(report.termination, Termination::MaxIterationsReached);
+        assert_eq!(report.iterations, 5);
+    }
+}
@@ -0,0 +1,283 @@
+pub mod bicgstab;
+pub mod conjugate_gradient;
+pub mod gmres;
+pub mod preconditioner;
+pub mod stationary;
+
+use crate::convergence::{Iteration, SolveReport, SolverOptions, Termination};
+use crate::solvers::SolverError;
+use crate::utils::{Arr2D, CscMatrix, CsrMatrix};
+
+/// A linear map `x -> Ax`, which is all the Krylov methods need from a matrix.
+///
+/// Implement this for operators that are cheaper to apply than to store, such as a
+/// stencil, or use [`FnOperator`] to wrap a closure.
+pub trait LinearOperator {
+    /// Number of rows and columns. The solvers require a square operator.
+    fn shape(&self) -> (usize, usize);
+    fn apply(&self, x: &[f64]) -> Vec<f64>;
+}
+
+/// An operator whose rows can be read entry by entry, as needed by the stationary
+/// methods and the preconditioners built from the matrix.
+pub trait RowOperator: LinearOperator {
+    /// `(column, value)` for the entries of row `i`. Zeros may be left out.
+    fn row_entries(&self, i: usize) -> Vec<(usize, f64)>;
+}
+
+impl LinearOperator for Arr2D<f64> {
+    fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.rows()
+            .map(|row| row.iter().zip(x).map(|(a, x)| a * x).sum())
+            .collect()
+    }
+}
+
+impl RowOperator for Arr2D<f64> {
+    fn row_entries(&self, i: usize) -> Vec<(usize, f64)> {
+        self[i]
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| **a != 0.0)
+            .map(|(j, a)| (j, *a))
+            .collect()
+    }
+}
+
+impl LinearOperator for CsrMatrix {
+    fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        // The solvers check the sizes before applying the operator
+        self.mul_vec(x).unwrap_or_default()
+    }
+}
+
+impl RowOperator for CsrMatrix {
+    fn row_entries(&self, i: usize) -> Vec<(usize, f64)> {
+        let (indices, values) = self.row(i);
+        indices
+            .iter()
+            .copied()
+            .zip(values.iter().copied())
+            .collect()
+    }
+}
+
+// CscMatrix is deliberately not a RowOperator: reading one row of a CSC matrix scans
+// every column, which would make each stationary sweep cost O(n·nnz). Convert with
+// `to_csr` for `jacobi`, `sor` and the preconditioners.
+impl LinearOperator for CscMatrix {
+    fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.mul_vec(x).unwrap_or_default()
+    }
+}
+
+/// A matrix-free operator of the given size, applied by calling a closure
+pub struct FnOperator<F>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    pub size: usize,
+    pub function: F,
+}
+
+impl<F> FnOperator<F>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    pub fn new(size: usize, function: F) -> Self {
+        Self { size, function }
+    }
+}
+
+impl<F> LinearOperator for FnOperator<F>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.size, self.size)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        (self.function)(x)
+    }
+}
+
+// Checks the sizes of the system and returns the starting vector
+fn initial_guess<A>(
+    operator: &A,
+    rhs: &[f64],
+    x_init: Option<&[f64]>,
+) -> Result<Vec<f64>, SolverError>
+where
+    A: LinearOperator + ?Sized,
+{
+    let (height, width) = operator.shape();
+    if height != width {
+        return Err(SolverError::NonSquareMatrix);
+    }
+    if rhs.len() != height {
+        return Err(SolverError::NumArgumentsMismatch {
+            num_rows: height,
+            rhs_len: rhs.len(),
+        });
+    }
+    match x_init {
+        Some(x) if x.len() != height => Err(SolverError::NumArgumentsMismatch {
+            num_rows: height,
+            rhs_len: x.len(),
+        }),
+        Some(x) => Ok(x.to_vec()),
+        None => Ok(vec![0.0; height]),
+    }
+}
+
+// b - Ax
+fn residual<A>(operator: &A, rhs: &[f64], x: &[f64]) -> Vec<f64>
+where
+    A: LinearOperator + ?Sized,
+{
+    rhs.iter()
+        .zip(operator.apply(x))
+        .map(|(b, ax)| b - ax)
+        .collect()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+// y += alpha * x
+fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    for (y, x) in y.iter_mut().zip(x) {
+        *y += alpha * x;
+    }
+}
+
+// Feeds each iterate of a linear solver to the stopping criteria and collects the
+// residual history. The residual passed to `SolverOptions` is `‖b - Ax‖ / ‖b‖`.
+struct Monitor<'o, 'a> {
+    options: &'o mut SolverOptions<'a>,
+    rhs_norm: f64,
+    previous: Vec<f64>,
+    history: Vec<f64>,
+    iterations: usize,
+}
+
+impl<'o, 'a> Monitor<'o, 'a> {
+    fn new(options: &'o mut SolverOptions<'a>, rhs: &[f64], x: &[f64]) -> Self {
+        let rhs_norm = norm(rhs);
+        Self {
+            options,
+            // With b = 0 the absolute residual is used instead
+            rhs_norm: if rhs_norm == 0.0 { 1.0 } else { rhs_norm },
+            previous: x.to_vec(),
+            history: Vec::new(),
+            iterations: 0,
+        }
+    }
+
+    fn relative(&self, residual_norm: f64) -> f64 {
+        residual_norm / self.rhs_norm
+    }
+
+    // Records one iteration and decides whether to stop
+    fn check(&mut self, x: &[f64], residual_norm: f64) -> Option<Termination> {
+        self.iterations += 1;
+        let residual = self.relative(residual_norm);
+        let step = x
+            .iter()
+            .zip(&self.previous)
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>()
+            .sqrt();
+        let x_norm = norm(x);
+        let error = if x_norm == 0.0 {
+            if step == 0.0 { 0.0 } else { 100.0 }
+        } else {
+            step / x_norm * 100.0
+        };
+        self.history.push(residual);
+        self.previous = x.to_vec();
+        if !residual.is_finite() {
+            return Some(Termination::NoConvergence);
+        }
+        self.options.termination(&Iteration {
+            iteration: self.iterations,
+            x: x_norm,
+            step,
+            error,
+            residual,
+        })
+    }
+
+    fn finish(self, solution: Vec<f64>, termination: Termination) -> SolveReport<Vec<f64>> {
+        SolveReport {
+            residual: self.history.last().copied().unwrap_or(0.0),
+            solution,
+            iterations: self.iterations,
+            history: self.history,
+            termination,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_systems {
+    use crate::utils::Arr2D;
+
+    // 1D diffusion with Dirichlet boundaries, which is symmetric positive definite
+    pub fn poisson(size: usize) -> Arr2D<f64> {
+        tridiagonal(size, -1.0, 2.0, -1.0)
+    }
+
+    // 1D convection-diffusion, which is not symmetric
+    pub fn convection_diffusion(size: usize) -> Arr2D<f64> {
+        tridiagonal(size, -1.3, 2.5, -0.7)
+    }
+
+    fn tridiagonal(size: usize, lower: f64, diagonal: f64, upper: f64) -> Arr2D<f64> {
+        let mut matrix = Arr2D::full(0.0, size, size);
+        for i in 0..size {
+            matrix[i][i] = diagonal;
+            if i + 1 < size {
+                matrix[i + 1][i] = lower;
+                matrix[i][i + 1] = upper;
+            }
+        }
+        matrix
+    }
+
+    // Right hand side and exact solution of a test system
+    pub fn manufactured(matrix: &Arr2D<f64>) -> (Vec<f64>, Vec<f64>) {
+        let exact: Vec<f64> = (0..matrix.height)
+            .map(|i| (i as f64 * 0.3).sin() + 1.0)
+            .collect();
+        let rhs = matrix
+            .rows()
+            .map(|row| row.iter().zip(&exact).map(|(a, x)| a * x).sum())
+            .collect();
+        (rhs, exact)
+    }
+
+    pub fn assert_close(solution: &[f64], exact: &[f64], tol: f64) {
+        for (x, e) in solution.iter().zip(exact) {
+            assert!((x - e).abs() < tol, "{x} != {e}");
+        }
+    }
+}
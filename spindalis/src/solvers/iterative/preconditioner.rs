@@ -0,0 +1,154 @@
+use crate::solvers::SolverError;
+use crate::solvers::iterative::RowOperator;
+
+/// An approximation `M` of the system matrix that is cheap to invert. The Krylov
+/// methods converge faster on `M⁻¹A`, which is closer to the identity than `A`.
+pub trait Preconditioner {
+    /// Returns `M⁻¹r`
+    fn apply(&self, r: &[f64]) -> Vec<f64>;
+}
+
+/// Diagonal (Jacobi) preconditioner, `M = diag(A)`
+pub struct JacobiPreconditioner {
+    inverse_diagonal: Vec<f64>,
+}
+
+impl JacobiPreconditioner {
+    /// Fails with `SingularMatrix` if the diagonal has a zero
+    pub fn new<A>(matrix: &A) -> Result<Self, SolverError>
+    where
+        A: RowOperator + ?Sized,
+    {
+        let inverse_diagonal = diagonal(matrix)?.iter().map(|d| 1.0 / d).collect();
+        Ok(Self { inverse_diagonal })
+    }
+}
+
+impl Preconditioner for JacobiPreconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        r.iter()
+            .zip(&self.inverse_diagonal)
+            .map(|(r, d)| r * d)
+            .collect()
+    }
+}
+
+/// Symmetric successive over-relaxation preconditioner,
+/// `M = (D + ωL) D⁻¹ (D + ωU) / (ω(2 - ω))`, applied with a forward and a backward
+/// triangular sweep. It stays symmetric for symmetric A, so it can be used with the
+/// conjugate gradient method. `omega` must be between 0 and 2.
+pub struct SsorPreconditioner {
+    rows: Vec<Vec<(usize, f64)>>,
+    diagonal: Vec<f64>,
+    omega: f64,
+}
+
+impl SsorPreconditioner {
+    /// Fails with `SingularMatrix` if the diagonal has a zero
+    pub fn new<A>(matrix: &A, omega: f64) -> Result<Self, SolverError>
+    where
+        A: RowOperator + ?Sized,
+    {
+        let diagonal = diagonal(matrix)?;
+        let rows = (0..diagonal.len()).map(|i| matrix.row_entries(i)).collect();
+        Ok(Self {
+            rows,
+            diagonal,
+            omega,
+        })
+    }
+}
+
+impl Preconditioner for SsorPreconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        let size = self.diagonal.len();
+        let omega = self.omega;
+
+        // (D + ωL) y = ω(2 - ω) r
+        let mut y = vec![0.0; size];
+        for i in 0..size {
+            let lower: f64 = self.rows[i]
+                .iter()
+                .filter(|(j, _)| *j < i)
+                .map(|(j, a)| a * y[*j])
+                .sum();
+            y[i] = (omega * (2.0 - omega) * r[i] - omega * lower) / self.diagonal[i];
+        }
+        // (D + ωU) z = D y
+        let mut z = vec![0.0; size];
+        for i in (0..size).rev() {
+            let upper: f64 = self.rows[i]
+                .iter()
+                .filter(|(j, _)| *j > i)
+                .map(|(j, a)| a * z[*j])
+                .sum();
+            z[i] = (self.diagonal[i] * y[i] - omega * upper) / self.diagonal[i];
+        }
+        z
+    }
+}
+
+// Diagonal entries of a square operator, none of which may be zero
+pub(super) fn diagonal<A>(matrix: &A) -> Result<Vec<f64>, SolverError>
+where
+    A: RowOperator + ?Sized,
+{
+    let (height, width) = matrix.shape();
+    if height != width {
+        return Err(SolverError::NonSquareMatrix);
+    }
+    (0..height)
+        .map(|i| {
+            let d: f64 = matrix
+                .row_entries(i)
+                .iter()
+                .filter(|(j, _)| *j == i)
+                .map(|(_, a)| a)
+                .sum();
+            if d == 0.0 {
+                Err(SolverError::SingularMatrix)
+            } else {
+                Ok(d)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Arr2D;
+
+    #[test]
+    fn test_jacobi_preconditioner() {
+        let matrix = Arr2D::from(&[[4.0, 1.0], [1.0, 2.0]]);
+        let preconditioner = JacobiPreconditioner::new(&matrix).unwrap();
+        assert_eq!(preconditioner.apply(&[4.0, 4.0]), vec![1.0, 2.0]);
+
+        let matrix = Arr2D::from(&[[0.0, 1.0], [1.0, 2.0]]);
+        assert!(matches!(
+            JacobiPreconditioner::new(&matrix),
+            Err(SolverError::SingularMatrix)
+        ));
+    }
+
+    #[test]
+    fn test_ssor_inverts_triangular_product() {
+        // With ω = 1, M = (D + L) D⁻¹ (D + U), so M⁻¹(Mx) = x
+        let matrix = Arr2D::from(&[[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        let preconditioner = SsorPreconditioner::new(&matrix, 1.0).unwrap();
+        let x = [1.0, -2.0, 3.0];
+        // (D + U) x, then D⁻¹, then (D + L)
+        let upper = [4.0 * 1.0 + 1.0 * -2.0, 3.0 * -2.0 + 1.0 * 3.0, 2.0 * 3.0];
+        let scaled = [upper[0] / 4.0, upper[1] / 3.0, upper[2] / 2.0];
+        let product = [
+            4.0 * scaled[0],
+            1.0 * scaled[0] + 3.0 * scaled[1],
+            1.0 * scaled[1] + 2.0 * scaled[2],
+        ];
+        let result = preconditioner.apply(&product);
+        for (a, b) in result.iter().zip(x) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+}
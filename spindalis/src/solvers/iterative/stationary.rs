@@ -0,0 +1,181 @@
+// Stationary methods split A into its diagonal D, strictly lower part L and strictly
+// upper part U, and repeat a fixed update until the residual is small. They converge
+// for strictly diagonally dominant matrices, and Gauss–Seidel and SOR (0 < ω < 2) also
+// converge for symmetric positive definite ones.
+use crate::convergence::{SolveReport, SolverOptions, Termination};
+use crate::solvers::SolverError;
+use crate::solvers::iterative::preconditioner::diagonal;
+use crate::solvers::iterative::{Monitor, RowOperator, initial_guess, norm, residual};
+
+/// Jacobi iteration, `x_{k+1} = D⁻¹(b - (L + U)x_k)`.
+///
+/// Every entry is updated from the previous iterate, so the method needs a second
+/// vector but each sweep could run in parallel. Fails with `SingularMatrix` if the
+/// diagonal has a zero.
+pub fn jacobi<A>(
+    matrix: &A,
+    rhs: &[f64],
+    x_init: Option<&[f64]>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Vec<f64>>, SolverError>
+where
+    A: RowOperator + ?Sized,
+{
+    let mut x = initial_guess(matrix, rhs, x_init)?;
+    let diagonal = diagonal(matrix)?;
+    let mut monitor = Monitor::new(options, rhs, &x);
+    loop {
+        let next: Vec<f64> = (0..x.len())
+            .map(|i| {
+                let off_diagonal: f64 = matrix
+                    .row_entries(i)
+                    .iter()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(j, a)| a * x[*j])
+                    .sum();
+                (rhs[i] - off_diagonal) / diagonal[i]
+            })
+            .collect();
+        x = next;
+        let residual_norm = norm(&residual(matrix, rhs, &x));
+        if let Some(termination) = monitor.check(&x, residual_norm) {
+            return Ok(monitor.finish(x, termination));
+        }
+    }
+}
+
+/// Gauss–Seidel iteration, which is SOR with `ω = 1`. Each entry is updated in place,
+/// so later rows already use the new values.
+pub fn gauss_seidel<A>(
+    matrix: &A,
+    rhs: &[f64],
+    x_init: Option<&[f64]>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Vec<f64>>, SolverError>
+where
+    A: RowOperator + ?Sized,
+{
+    sor(matrix, rhs, 1.0, x_init, options)
+}
+
+/// Successive over-relaxation, blending each Gauss–Seidel update with the previous
+/// value as `x_i = (1 - ω)x_i + ω x_i^GS`.
+///
+/// `ω > 1` over-relaxes and can converge much faster than Gauss–Seidel on diffusion
+/// problems. The method cannot converge for `ω` outside (0, 2), so those return
+/// straight away with `Termination::NoConvergence`.
+pub fn sor<A>(
+    matrix: &A,
+    rhs: &[f64],
+    omega: f64,
+    x_init: Option<&[f64]>,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<Vec<f64>>, SolverError>
+where
+    A: RowOperator + ?Sized,
+{
+    let mut x = initial_guess(matrix, rhs, x_init)?;
+    let diagonal = diagonal(matrix)?;
+    let mut monitor = Monitor::new(options, rhs, &x);
+    if omega <= 0.0 || omega >= 2.0 {
+        return Ok(monitor.finish(x, Termination::NoConvergence));
+    }
+    loop {
+        for i in 0..x.len() {
+            let off_diagonal: f64 = matrix
+                .row_entries(i)
+                .iter()
+                .filter(|(j, _)| *j != i)
+                .map(|(j, a)| a * x[*j])
+                .sum();
+            let gauss_seidel = (rhs[i] - off_diagonal) / diagonal[i];
+            x[i] = (1.0 - omega) * x[i] + omega * gauss_seidel;
+        }
+        let residual_norm = norm(&residual(matrix, rhs, &x));
+        if let Some(termination) = monitor.check(&x, residual_norm) {
+            return Ok(monitor.finish(x, termination));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::test_systems::{assert_close, manufactured, poisson};
+    use crate::utils::{Arr2D, CsrMatrix};
+
+    fn options() -> SolverOptions<'static> {
+        SolverOptions {
+            rel_tol: 0.0,
+            fn_tol: 1e-10,
+            itermax: 20_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diagonally_dominant() {
+        let matrix = Arr2D::from(&[[10.0, -1.0, 2.0], [-1.0, 11.0, -1.0], [2.0, -1.0, 10.0]]);
+        let rhs = [6.0, 25.0, -11.0];
+        let exact = matrix.inverse().unwrap();
+        let exact: Vec<f64> = exact
+            .rows()
+            .map(|row| row.iter().zip(rhs).map(|(a, b)| a * b).sum())
+            .collect();
+
+        let jacobi = jacobi(&matrix, &rhs, None, &mut options()).unwrap();
+        let gauss_seidel = gauss_seidel(&matrix, &rhs, None, &mut options()).unwrap();
+        assert!(jacobi.converged() && gauss_seidel.converged());
+        assert_close(&jacobi.solution, &exact, 1e-9);
+        assert_close(&gauss_seidel.solution, &exact, 1e-9);
+        // Gauss–Seidel uses the new values straight away
+        assert!(gauss_seidel.iterations < jacobi.iterations);
+    }
+
+    #[test]
+    fn test_sparse_matrix() {
+        let dense = poisson(30);
+        let sparse = CsrMatrix::from(&dense);
+        let (rhs, exact) = manufactured(&dense);
+        let from_sparse = gauss_seidel(&sparse, &rhs, None, &mut options()).unwrap();
+        let from_dense = gauss_seidel(&dense, &rhs, None, &mut options()).unwrap();
+        assert!(from_sparse.converged());
+        assert_close(&from_sparse.solution, &exact, 1e-7);
+        // The same sweeps, in the same order
+        assert_eq!(from_sparse.iterations, from_dense.iterations);
+    }
+
+    #[test]
+    fn test_sor_on_diffusion() {
+        let matrix = poisson(30);
+        let (rhs, exact) = manufactured(&matrix);
+        let gauss_seidel = gauss_seidel(&matrix, &rhs, None, &mut options()).unwrap();
+        let sor = sor(&matrix, &rhs, 1.8, None, &mut options()).unwrap();
+        assert!(sor.converged());
+        assert_close(&sor.solution, &exact, 1e-7);
+        assert!(sor.iterations < gauss_seidel.iterations / 4);
+
+        // The residual history has one entry per sweep and ends below the tolerance
+        assert_eq!(sor.history.len(), sor.iterations);
+        assert!(*sor.history.last().unwrap() <= 1e-10);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let matrix = Arr2D::from(&[[0.0, 1.0], [1.0, 0.0]]);
+        assert!(matches!(
+            jacobi(&matrix, &[1.0, 1.0], None, &mut options()),
+            Err(SolverError::SingularMatrix)
+        ));
+        let matrix = Arr2D::from(&[[2.0, 1.0], [1.0, 2.0]]);
+        assert!(matches!(
+            gauss_seidel(&matrix, &[1.0], None, &mut options()),
+            Err(SolverError::NumArgumentsMismatch {
+                num_rows: 2,
+                rhs_len: 1
+            })
+        ));
+        let report = sor(&matrix, &[1.0, 1.0], 2.5, None, &mut options()).unwrap();
+        assert_eq!(report.termination, Termination::NoConvergence);
+    }
+}
@@ -1,13 +1,38 @@
 pub mod bisection;
+pub mod brent;
 pub mod decomposition;
 pub mod eigen;
+pub mod extrema;
+pub mod false_position;
+pub mod fixed_point;
 pub mod gaussian_elim;
+pub mod interval_roots;
+pub mod iterative;
+pub mod nonlinear_system;
 pub mod nrm;
+pub mod polynomial_roots;
+pub mod ridders;
+pub mod secant;
+pub mod steffensen;
 
-pub use bisection::bisection;
+pub use bisection::{bisection, bisection_with_options};
+pub use brent::brent;
+pub use extrema::{
+    Extremum, ExtremumKind, classified_extrema, classify_extremum, global_max, global_min,
+};
+pub use false_position::false_position;
+pub use fixed_point::fixed_point_iteration;
 pub use gaussian_elim::gaussian_elimination;
-pub use nrm::newton_raphson_method;
+pub use interval_roots::{interval_roots, interval_roots_sturm};
+pub use nonlinear_system::{broyden_system, damped_newton_system, newton_system, system_variables};
+pub use nrm::{newton_raphson_method, newton_raphson_with_options};
+pub use polynomial_roots::{PolynomialRoot, polynomial_roots};
+pub use ridders::ridders;
+pub use secant::secant_method;
+pub use steffensen::steffensen_method;
 
+use crate::convergence::{SolveReport, Termination};
+use crate::objective::Objective;
 use crate::polynomials::PolynomialError;
 use crate::utils::Arr2DError;
 
@@ -24,9 +49,17 @@ pub enum SolverError {
     XInitOutOfBounds,
     NonSquareMatrix,
     SingularMatrix,
+    NonSymmetricMatrix,
+    /// Cholesky found a pivot that was not positive at the given row
+    NotPositiveDefinite {
+        row: usize,
+    },
     InvalidVector(Arr2DError),
     FunctionError(PolynomialError),
-    NumArgumentsMismatch { num_rows: usize, rhs_len: usize },
+    NumArgumentsMismatch {
+        num_rows: usize,
+        rhs_len: usize,
+    },
 }
 
 impl From<Arr2DError> for SolverError {
@@ -41,9 +74,45 @@ impl From<PolynomialError> for SolverError {
     }
 }
 
-// Bounds for bisection method
+// Bounds for bracketing methods
 pub struct Bounds {
     pub lower: f64,
     pub init: f64,
     pub upper: f64,
 }
+
+// Shared set up for the bracketing methods other than bisection.
+// Checks that `init` is within the bounds and that the function changes sign
+// across them, returning the function values at the lower and upper bounds.
+fn bracket_values<O>(function: &O, bounds: &Bounds) -> Result<(f64, f64), SolverError>
+where
+    O: Objective + ?Sized,
+{
+    if bounds.init < bounds.lower || bounds.init > bounds.upper {
+        return Err(SolverError::XInitOutOfBounds);
+    }
+    let f_lower = function.eval(bounds.lower)?;
+    let f_upper = function.eval(bounds.upper)?;
+    if f_lower * f_upper > 0.0 {
+        return Err(SolverError::NoConvergence);
+    }
+    Ok((f_lower, f_upper))
+}
+
+// Approximate relative error as a percentage, matching bisection and Newton-Raphson
+fn approx_error(x_curr: f64, x_old: f64) -> f64 {
+    if x_curr == 0.0 {
+        return if x_old == 0.0 { 0.0 } else { 100.0 };
+    }
+    ((x_curr - x_old) / x_curr).abs() * 100.0
+}
+
+// Unwraps a report from one of the `_with_options` solvers into the plain result
+// returned by the positional-argument versions
+fn report_solution(report: SolveReport<f64>) -> Result<f64, SolverError> {
+    match report.termination {
+        Termination::MaxIterationsReached => Err(SolverError::MaxIterationsReached),
+        Termination::NoConvergence | Termination::Cancelled => Err(SolverError::NoConvergence),
+        _ => Ok(report.solution),
+    }
+}
@@ -0,0 +1,394 @@
+use crate::derivatives::partial_derivative;
+use crate::polynomials::{IntermediatePolynomial, PolynomialTraits};
+use crate::solvers::SolverError;
+use crate::solvers::decomposition::plu::lu_pivot_decomposition;
+use crate::utils::{Arr2D, back_substitution, forward_substitution};
+
+// Sufficient decrease constant for the backtracking line search
+const ARMIJO: f64 = 1e-4;
+// Smallest step fraction tried before the line search gives up
+const MIN_STEP: f64 = 1e-10;
+
+/// The unknowns of a system of equations, in the order used for initial guesses
+/// and solutions: the union of every equation's variables, sorted by name.
+pub fn system_variables(system: &[IntermediatePolynomial]) -> Vec<String> {
+    let mut variables: Vec<String> = system
+        .iter()
+        .flat_map(|equation| equation.variables.iter().cloned())
+        .collect();
+    variables.sort();
+    variables.dedup();
+    variables
+}
+
+/// Newton-Raphson method for a square system of nonlinear equations `F(x) = 0`.
+///
+/// The Jacobian is built once with `partial_derivative` and each step solves
+/// `J(x) dx = -F(x)` with a pivoted LU decomposition. `x_init` and the returned
+/// solution follow the variable order given by [`system_variables`].
+pub fn newton_system(
+    system: &[IntermediatePolynomial],
+    x_init: &[f64],
+    itermax: usize,
+    error_tol: f64,
+) -> Result<Vec<f64>, SolverError> {
+    let system = System::new(system, x_init)?;
+    if system.variables.is_empty() {
+        return Ok(Vec::new());
+    }
+    let jacobian = system.jacobian();
+
+    let mut iter = 0;
+    let mut x_curr = x_init.to_vec();
+    let mut approx_err = 100_f64;
+    loop {
+        let residual = system.eval(&x_curr)?;
+        let step = solve_step(&system.eval_jacobian(&jacobian, &x_curr)?, &residual)?;
+        for (x, dx) in x_curr.iter_mut().zip(&step) {
+            *x += dx;
+        }
+        iter += 1;
+        approx_err = step_error(&x_curr, &step).unwrap_or(approx_err);
+        if approx_err < error_tol || iter >= itermax {
+            break;
+        }
+    }
+    if iter >= itermax {
+        return Err(SolverError::MaxIterationsReached);
+    }
+    Ok(x_curr)
+}
+
+/// Damped Newton-Raphson method for a square system of nonlinear equations.
+///
+/// Takes the same steps as [`newton_system`], but shortens each one by halving
+/// until `|F|²` decreases enough (the Armijo condition). This keeps the iteration
+/// from overshooting when the initial guess is far from the solution.
+pub fn damped_newton_system(
+    system: &[IntermediatePolynomial],
+    x_init: &[f64],
+    itermax: usize,
+    error_tol: f64,
+) -> Result<Vec<f64>, SolverError> {
+    let system = System::new(system, x_init)?;
+    if system.variables.is_empty() {
+        return Ok(Vec::new());
+    }
+    let jacobian = system.jacobian();
+
+    let mut iter = 0;
+    let mut x_curr = x_init.to_vec();
+    let mut residual = system.eval(&x_curr)?;
+    let mut approx_err = 100_f64;
+    loop {
+        let step = solve_step(&system.eval_jacobian(&jacobian, &x_curr)?, &residual)?;
+        let merit = norm_sqr(&residual);
+
+        // Backtrack along the Newton direction
+        let mut lambda = 1.0;
+        let (x_next, residual_next) = loop {
+            let x_next: Vec<f64> = x_curr
+                .iter()
+                .zip(&step)
+                .map(|(x, dx)| x + lambda * dx)
+                .collect();
+            let residual_next = system.eval(&x_next)?;
+            if norm_sqr(&residual_next) <= (1.0 - 2.0 * ARMIJO * lambda) * merit {
+                break (x_next, residual_next);
+            }
+            lambda /= 2.0;
+            if lambda < MIN_STEP {
+                return Err(SolverError::NoConvergence);
+            }
+        };
+
+        let taken: Vec<f64> = step.iter().map(|dx| lambda * dx).collect();
+        x_curr = x_next;
+        residual = residual_next;
+        iter += 1;
+        approx_err = step_error(&x_curr, &taken).unwrap_or(approx_err);
+        if approx_err < error_tol || iter >= itermax {
+            break;
+        }
+    }
+    if iter >= itermax {
+        return Err(SolverError::MaxIterationsReached);
+    }
+    Ok(x_curr)
+}
+
+/// Broyden's quasi-Newton method for a square system of nonlinear equations.
+///
+/// The Jacobian is only evaluated at `x_init`. Every later step updates the
+/// approximation `B` with the rank-one correction
+/// `B += (dF - B dx) dxᵀ / (dxᵀ dx)`, so each iteration costs a single evaluation
+/// of the system.
+pub fn broyden_system(
+    system: &[IntermediatePolynomial],
+    x_init: &[f64],
+    itermax: usize,
+    error_tol: f64,
+) -> Result<Vec<f64>, SolverError> {
+    let system = System::new(system, x_init)?;
+    if system.variables.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut approx_jacobian = system.eval_jacobian(&system.jacobian(), x_init)?;
+    let size = x_init.len();
+
+    let mut iter = 0;
+    let mut x_curr = x_init.to_vec();
+    let mut residual = system.eval(&x_curr)?;
+    let mut approx_err = 100_f64;
+    loop {
+        let step = solve_step(&approx_jacobian, &residual)?;
+        for (x, dx) in x_curr.iter_mut().zip(&step) {
+            *x += dx;
+        }
+        let residual_next = system.eval(&x_curr)?;
+        iter += 1;
+        approx_err = step_error(&x_curr, &step).unwrap_or(approx_err);
+        if approx_err < error_tol || iter >= itermax {
+            break;
+        }
+
+        let step_sqr = norm_sqr(&step);
+        for i in 0..size {
+            let predicted: f64 = (0..size).map(|j| approx_jacobian[i][j] * step[j]).sum();
+            let correction = (residual_next[i] - residual[i] - predicted) / step_sqr;
+            for j in 0..size {
+                approx_jacobian[i][j] += correction * step[j];
+            }
+        }
+        residual = residual_next;
+    }
+    if iter >= itermax {
+        return Err(SolverError::MaxIterationsReached);
+    }
+    Ok(x_curr)
+}
+
+// A system of equations paired with the order of its unknowns
+struct System<'a> {
+    equations: &'a [IntermediatePolynomial],
+    variables: Vec<String>,
+}
+
+impl<'a> System<'a> {
+    fn new(equations: &'a [IntermediatePolynomial], x_init: &[f64]) -> Result<Self, SolverError> {
+        let variables = system_variables(equations);
+        if equations.len() != variables.len() {
+            return Err(SolverError::NonSquareMatrix);
+        }
+        if x_init.len() != variables.len() {
+            return Err(SolverError::NumArgumentsMismatch {
+                num_rows: equations.len(),
+                rhs_len: x_init.len(),
+            });
+        }
+        Ok(Self {
+            equations,
+            variables,
+        })
+    }
+
+    fn point<'b>(&'b self, x: &[f64]) -> Vec<(&'b str, f64)> {
+        self.variables
+            .iter()
+            .map(String::as_str)
+            .zip(x.iter().copied())
+            .collect()
+    }
+
+    fn eval(&self, x: &[f64]) -> Result<Vec<f64>, SolverError> {
+        let point = self.point(x);
+        let mut values = Vec::with_capacity(self.equations.len());
+        for equation in self.equations {
+            values.push(equation.eval_multivariate(&point)?);
+        }
+        Ok(values)
+    }
+
+    // Row i holds the partial derivatives of equation i
+    fn jacobian(&self) -> Vec<Vec<IntermediatePolynomial>> {
+        self.equations
+            .iter()
+            .map(|equation| {
+                self.variables
+                    .iter()
+                    .map(|var| partial_derivative(&equation.terms, var))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn eval_jacobian(
+        &self,
+        jacobian: &[Vec<IntermediatePolynomial>],
+        x: &[f64],
+    ) -> Result<Arr2D<f64>, SolverError> {
+        let point = self.point(x);
+        let size = self.variables.len();
+        let mut values = Arr2D::full(0.0, size, size);
+        for (i, row) in jacobian.iter().enumerate() {
+            for (j, derivative) in row.iter().enumerate() {
+                values[i][j] = derivative.eval_multivariate(&point)?;
+            }
+        }
+        Ok(values)
+    }
+}
+
+// Solves J dx = -F with PJ = LU
+fn solve_step(jacobian: &Arr2D<f64>, residual: &[f64]) -> Result<Vec<f64>, SolverError> {
+    let size = residual.len();
+    let (lower, upper, permutation) = lu_pivot_decomposition(jacobian)?;
+    let rhs: Vec<f64> = (0..size)
+        .map(|i| {
+            -(0..size)
+                .map(|j| permutation[i][j] * residual[j])
+                .sum::<f64>()
+        })
+        .collect();
+    let mut intermediate = vec![0.0; size];
+    forward_substitution(&lower, size, &rhs, &mut intermediate);
+    let mut step = vec![0.0; size];
+    back_substitution(&upper, size, &intermediate, &mut step);
+    Ok(step)
+}
+
+// Relative size of the step as a percentage, or None when the solution is the origin
+fn step_error(x_curr: &[f64], step: &[f64]) -> Option<f64> {
+    let x_norm = norm_sqr(x_curr).sqrt();
+    if x_norm == 0.0 {
+        return None;
+    }
+    Some(norm_sqr(step).sqrt() / x_norm * 100.0)
+}
+
+fn norm_sqr(vector: &[f64]) -> f64 {
+    vector.iter().map(|v| v * v).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_TOL: f64 = 1e-8;
+
+    fn parse_system(equations: &[&str]) -> Vec<IntermediatePolynomial> {
+        equations
+            .iter()
+            .map(|eq| IntermediatePolynomial::parse(eq).unwrap())
+            .collect()
+    }
+
+    fn assert_solves(system: &[IntermediatePolynomial], solution: &[f64]) {
+        let system = System::new(system, solution).unwrap();
+        for value in system.eval(solution).unwrap() {
+            assert!(value.abs() < 1e-8, "residual {value}");
+        }
+    }
+
+    #[test]
+    fn test_variable_order() {
+        let system = parse_system(&["y^2 + x - 1", "zx - y"]);
+        assert_eq!(system_variables(&system), vec!["x", "y", "z"]);
+    }
+
+    #[test]
+    fn test_newton_circle_and_line() {
+        // x^2 + y^2 = 4 meets y = x at (√2, √2)
+        let system = parse_system(&["x^2 + y^2 - 4", "x - y"]);
+        let solution = newton_system(&system, &[1.0, 2.0], 50, ERROR_TOL).unwrap();
+        assert!((solution[0] - 2.0_f64.sqrt()).abs() < 1e-8);
+        assert!((solution[1] - 2.0_f64.sqrt()).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_newton_textbook_system() {
+        // u = x^2 + xy - 10, v = y + 3xy^2 - 57, solved at (2, 3)
+        let system = parse_system(&["x^2 + xy - 10", "y + 3xy^2 - 57"]);
+        let solution = newton_system(&system, &[1.5, 3.5], 50, ERROR_TOL).unwrap();
+        assert!((solution[0] - 2.0).abs() < 1e-8);
+        assert!((solution[1] - 3.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_newton_three_equations() {
+        let system = parse_system(&["x + y + z - 6", "xy - 2", "x^2 + z^2 - 10"]);
+        let solution = newton_system(&system, &[1.2, 1.8, 2.7], 50, ERROR_TOL).unwrap();
+        assert_solves(&system, &solution);
+        assert!((solution[0] - 1.0).abs() < 1e-8);
+        assert!((solution[1] - 2.0).abs() < 1e-8);
+        assert!((solution[2] - 3.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_damped_from_far_guess() {
+        let system = parse_system(&["x^2 + xy - 10", "y + 3xy^2 - 57"]);
+        let solution = damped_newton_system(&system, &[8.0, 8.0], 100, ERROR_TOL).unwrap();
+        assert_solves(&system, &solution);
+    }
+
+    #[test]
+    fn test_broyden() {
+        let system = parse_system(&["x^2 + xy - 10", "y + 3xy^2 - 57"]);
+        let solution = broyden_system(&system, &[1.5, 3.5], 100, ERROR_TOL).unwrap();
+        assert!((solution[0] - 2.0).abs() < 1e-6);
+        assert!((solution[1] - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_singular_jacobian() {
+        // Both partial derivatives vanish at the origin
+        let system = parse_system(&["x^2 + y^2 - 1", "x^2 - y^2"]);
+        let result = newton_system(&system, &[0.0, 0.0], 50, ERROR_TOL);
+        assert!(matches!(result, Err(SolverError::SingularMatrix)));
+    }
+
+    #[test]
+    fn test_non_square_system() {
+        let system = parse_system(&["x + y + z", "x - y"]);
+        let result = newton_system(&system, &[1.0, 1.0, 1.0], 50, ERROR_TOL);
+        assert!(matches!(result, Err(SolverError::NonSquareMatrix)));
+    }
+
+    #[test]
+    fn test_initial_guess_length() {
+        let system = parse_system(&["x + y - 3", "x - y - 1"]);
+        let result = newton_system(&system, &[1.0], 50, ERROR_TOL);
+        assert!(matches!(
+            result,
+            Err(SolverError::NumArgumentsMismatch {
+                num_rows: 2,
+                rhs_len: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_empty_system() {
+        assert_eq!(
+            newton_system(&[], &[], 10, ERROR_TOL).unwrap(),
+            Vec::<f64>::new()
+        );
+        assert!(
+            damped_newton_system(&[], &[], 10, ERROR_TOL)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(broyden_system(&[], &[], 10, ERROR_TOL).unwrap().is_empty());
+        assert!(matches!(
+            newton_system(&[], &[1.0], 10, ERROR_TOL),
+            Err(SolverError::NumArgumentsMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        let system = parse_system(&["x^2 + xy - 10", "y + 3xy^2 - 57"]);
+        let result = newton_system(&system, &[1.5, 3.5], 1, ERROR_TOL);
+        assert!(matches!(result, Err(SolverError::MaxIterationsReached)));
+    }
+}
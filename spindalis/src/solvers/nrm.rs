@@ -1,48 +1,92 @@
-use crate::polynomials::PolynomialTraits;
-use crate::solvers::{SolveMode, SolverError};
+use crate::convergence::{Iteration, SolveReport, SolverOptions};
+use crate::objective::Objective;
+use crate::solvers::{SolveMode, SolverError, report_solution};
 
-pub fn newton_raphson_method<P>(
-    polynomial: &P,
+/// Newton-Raphson method.
+/// Uses the objective's analytical derivative when it has one, and a central
+/// difference otherwise.
+pub fn newton_raphson_method<O>(
+    function: &O,
     x_init: f64,
     itermax: usize,
     error_tol: f64,
     mode: SolveMode,
 ) -> Result<f64, SolverError>
 where
-    P: PolynomialTraits,
+    O: Objective,
+{
+    let mut options = SolverOptions {
+        rel_tol: error_tol,
+        itermax,
+        ..Default::default()
+    };
+    report_solution(newton_raphson_with_options(
+        function,
+        x_init,
+        mode,
+        &mut options,
+    )?)
+}
+
+/// Newton-Raphson method with shared stopping criteria.
+/// The residual is the function value at the final estimate.
+pub fn newton_raphson_with_options<O>(
+    function: &O,
+    x_init: f64,
+    mode: SolveMode,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<f64>, SolverError>
+where
+    O: Objective,
 {
     let mut iter = 0;
     let mut x_curr = x_init;
     let mut approx_err = 100_f64;
-    let polynomial = {
-        match mode {
-            SolveMode::Root => polynomial,
-            SolveMode::Extrema => &polynomial.derivate_univariate()?,
+    let mut history = Vec::new();
+    let function_dx = function.derivative_or_numerical()?;
+    let function_dx2;
+    let (function, function_dx): (&dyn Objective, &dyn Objective) = match mode {
+        SolveMode::Root => (function, &function_dx),
+        SolveMode::Extrema => {
+            function_dx2 = function_dx.derivative_or_numerical()?;
+            (&function_dx, &function_dx2)
         }
     };
-    let polynomial_dx = polynomial.derivate_univariate()?;
-    loop {
+    let mut f_curr = function.eval(x_curr)?;
+    let termination = loop {
         let xr_old = x_curr;
-        x_curr = xr_old
-            - (polynomial.eval_univariate(x_curr)? / polynomial_dx.eval_univariate(x_curr)?);
+        x_curr = xr_old - (f_curr / function_dx.eval(x_curr)?);
+        f_curr = function.eval(x_curr)?;
         iter += 1;
         if x_curr != 0 as f64 {
             approx_err = ((x_curr - xr_old).abs() / x_curr) * 100.0;
         }
-        if approx_err.abs() < error_tol || iter >= itermax {
-            break;
+        history.push(approx_err);
+
+        let iteration = Iteration {
+            iteration: iter,
+            x: x_curr,
+            step: (x_curr - xr_old).abs(),
+            error: approx_err,
+            residual: f_curr,
+        };
+        if let Some(termination) = options.termination(&iteration) {
+            break termination;
         }
-    }
-    if iter >= itermax {
-        return Err(SolverError::MaxIterationsReached);
-    }
-    Ok(x_curr)
+    };
+    Ok(SolveReport {
+        solution: x_curr,
+        iterations: iter,
+        residual: f_curr,
+        history,
+        termination,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::polynomials::{IntermediatePolynomial, SimplePolynomial};
+    use crate::polynomials::{IntermediatePolynomial, PolynomialTraits, SimplePolynomial};
 
     const ERROR_TOL: f64 = 0.0001;
 
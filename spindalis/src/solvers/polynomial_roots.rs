@@ -0,0 +1,289 @@
+use crate::derivatives::simple_derivative;
+use crate::polynomials::{PolynomialError, SimplePolynomial};
+use crate::solvers::SolverError;
+use spindalis_core::complex::Complex;
+
+// Coefficients below this fraction of the largest one are treated as zero
+// while splitting the polynomial into square-free factors
+const CHOP_TOL: f64 = 1e-9;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolynomialRoot {
+    pub value: Complex,
+    pub multiplicity: usize,
+    /// Radius of a disc around `value` that is guaranteed to contain the root
+    pub error: f64,
+}
+
+impl PolynomialRoot {
+    pub fn is_real(&self) -> bool {
+        self.value.is_real()
+    }
+}
+
+/// Finds every real and complex root of a polynomial.
+///
+/// The polynomial is first split into square-free factors with Yun's algorithm,
+/// so that the roots of the `i`th factor have multiplicity `i`. The roots of each
+/// factor are then found together with the Aberth–Ehrlich iteration, which stops
+/// once every correction is below `error_tol` relative to the size of the root.
+///
+/// Roots are sorted by real part, then imaginary part. Roots whose imaginary part
+/// is within their error bound are returned as exactly real.
+pub fn polynomial_roots(
+    polynomial: &SimplePolynomial,
+    error_tol: f64,
+    itermax: usize,
+) -> Result<Vec<PolynomialRoot>, SolverError> {
+    if polynomial.degree().is_none() {
+        // Every point is a root of the zero polynomial
+        return Err(SolverError::FunctionError(PolynomialError::ZeroPolynomial));
+    }
+
+    let mut roots = Vec::new();
+    for (i, factor) in square_free_factors(polynomial).into_iter().enumerate() {
+        for (value, error) in aberth(&factor, error_tol, itermax)? {
+            roots.push(PolynomialRoot {
+                value,
+                multiplicity: i + 1,
+                error,
+            });
+        }
+    }
+    roots.sort_by(|a, b| {
+        a.value
+            .re
+            .total_cmp(&b.value.re)
+            .then(a.value.im.total_cmp(&b.value.im))
+    });
+    Ok(roots)
+}
+
+// Yun's algorithm: returns [a_1, a_2, ...] where p = c * a_1 * a_2^2 * a_3^3 ...
+// and each a_i is square-free. Factors without roots are returned as constants.
+fn square_free_factors(polynomial: &SimplePolynomial) -> Vec<SimplePolynomial> {
+    let p = polynomial.monic();
+    let dp = chop(&simple_derivative(&p), &p);
+    let g = p.gcd(&dp);
+    if g.degree() == Some(0) {
+        return vec![p];
+    }
+
+    let mut b = quotient(&p, &g);
+    let c = quotient(&dp, &g);
+    let mut d = chop(&(&c - &simple_derivative(&b)), &b);
+    let mut factors = Vec::new();
+    while b.degree().is_some_and(|deg| deg > 0) {
+        let a = b.gcd(&d);
+        b = quotient(&b, &a);
+        let c = quotient(&d, &a);
+        d = chop(&(&c - &simple_derivative(&b)), &b);
+        factors.push(a);
+    }
+    factors
+}
+
+// Simultaneous iteration for the roots of a square-free polynomial.
+// Returns each root along with its inclusion radius n|p(z)/p'(z)|.
+fn aberth(
+    polynomial: &SimplePolynomial,
+    error_tol: f64,
+    itermax: usize,
+) -> Result<Vec<(Complex, f64)>, SolverError> {
+    let Some(n) = polynomial.degree().filter(|&n| n > 0) else {
+        return Ok(Vec::new());
+    };
+    let coeffs = &polynomial.coefficients[..=n];
+
+    // Start on a circle whose radius bounds the roots (Fujiwara), offset so no
+    // starting point is real and conjugate pairs can separate
+    let lead = coeffs[n];
+    let radius = (0..n)
+        .map(|i| (coeffs[i] / lead).abs().powf(1.0 / (n - i) as f64))
+        .fold(0.0_f64, f64::max)
+        * 2.0;
+    let radius = if radius > 0.0 { radius } else { 1.0 };
+    let mut z: Vec<Complex> = (0..n)
+        .map(|k| {
+            let theta = std::f64::consts::TAU * k as f64 / n as f64 + 0.4;
+            Complex::from_polar(radius, theta)
+        })
+        .collect();
+
+    let mut converged = false;
+    for _ in 0..itermax {
+        converged = true;
+        for k in 0..n {
+            let (p, dp) = horner(coeffs, z[k]);
+            if p == Complex::ZERO {
+                continue;
+            }
+            let ratio = p / dp;
+            let repulsion = (0..n)
+                .filter(|&j| j != k)
+                .fold(Complex::ZERO, |acc, j| acc + (z[k] - z[j]).recip());
+            let step = ratio / (1.0 - ratio * repulsion);
+            if !step.is_finite() {
+                return Err(SolverError::NoConvergence);
+            }
+            z[k] -= step;
+            if step.abs() > error_tol * z[k].abs().max(1.0) {
+                converged = false;
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+    if !converged {
+        return Err(SolverError::MaxIterationsReached);
+    }
+
+    Ok(z.into_iter()
+        .map(|root| {
+            let (p, dp) = horner(coeffs, root);
+            let error = if p == Complex::ZERO {
+                0.0
+            } else {
+                n as f64 * (p / dp).abs()
+            };
+            let value = if root.im.abs() <= error {
+                Complex::from(root.re)
+            } else {
+                root
+            };
+            (value, error)
+        })
+        .collect())
+}
+
+// Evaluates p(z) and p'(z) together
+fn horner(coeffs: &[f64], z: Complex) -> (Complex, Complex) {
+    let mut p = Complex::ZERO;
+    let mut dp = Complex::ZERO;
+    for &c in coeffs.iter().rev() {
+        dp = dp * z + p;
+        p = p * z + c;
+    }
+    (p, dp)
+}
+
+// Zero out coefficients that are negligible next to those of `reference`
+fn chop(polynomial: &SimplePolynomial, reference: &SimplePolynomial) -> SimplePolynomial {
+    let scale = reference.iter().fold(0.0_f64, |acc, c| acc.max(c.abs()));
+    let coefficients = polynomial
+        .iter()
+        .map(|&c| if c.abs() <= CHOP_TOL * scale { 0.0 } else { c })
+        .collect();
+    SimplePolynomial {
+        coefficients,
+        variable: polynomial.variable,
+    }
+}
+
+// The divisors here are exact factors, so the remainder is rounding noise
+fn quotient(dividend: &SimplePolynomial, divisor: &SimplePolynomial) -> SimplePolynomial {
+    match dividend.div_rem(divisor) {
+        Ok((quotient, _)) => quotient,
+        Err(_) => dividend.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::PolynomialTraits;
+
+    const ERROR_TOL: f64 = 1e-12;
+
+    fn roots_of(poly: &str) -> Vec<PolynomialRoot> {
+        let parsed = SimplePolynomial::parse(poly).unwrap();
+        polynomial_roots(&parsed, ERROR_TOL, 500).unwrap()
+    }
+
+    fn approx_eq(a: Complex, b: Complex, tol: f64) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn test_real_roots() {
+        let roots = roots_of("x^3 - 6x^2 + 11x - 6");
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+            assert!(root.is_real());
+            assert!(approx_eq(root.value, Complex::from(expected), 1e-10));
+            assert_eq!(root.multiplicity, 1);
+            assert!(root.error < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_complex_roots() {
+        // (x - 1)(x^2 + 4)
+        let roots = roots_of("x^3 - x^2 + 4x - 4");
+        let expected = [
+            Complex::new(0.0, -2.0),
+            Complex::new(0.0, 2.0),
+            Complex::from(1.0),
+        ];
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip(expected) {
+            assert!(approx_eq(root.value, expected, 1e-10), "{}", root.value);
+        }
+    }
+
+    #[test]
+    fn test_multiplicity() {
+        // (x - 1)^3 (x + 2)^2
+        let roots = roots_of("x^5 + x^4 - 5x^3 - x^2 + 8x - 4");
+        assert_eq!(roots.len(), 2);
+        assert!(approx_eq(roots[0].value, Complex::from(-2.0), 1e-8));
+        assert_eq!(roots[0].multiplicity, 2);
+        assert!(approx_eq(roots[1].value, Complex::from(1.0), 1e-8));
+        assert_eq!(roots[1].multiplicity, 3);
+    }
+
+    #[test]
+    fn test_root_at_zero() {
+        let roots = roots_of("x^4 - x^2");
+        let values: Vec<(f64, usize)> =
+            roots.iter().map(|r| (r.value.re, r.multiplicity)).collect();
+        assert_eq!(values.len(), 3);
+        assert!((values[0].0 + 1.0).abs() < 1e-10 && values[0].1 == 1);
+        assert!(values[1].0.abs() < 1e-10 && values[1].1 == 2);
+        assert!((values[2].0 - 1.0).abs() < 1e-10 && values[2].1 == 1);
+    }
+
+    #[test]
+    fn test_roots_satisfy_polynomial() {
+        let parsed = SimplePolynomial::parse("2x^6 - 3x^5 + x^3 - 7x + 5").unwrap();
+        let roots = polynomial_roots(&parsed, ERROR_TOL, 500).unwrap();
+        let total: usize = roots.iter().map(|r| r.multiplicity).sum();
+        assert_eq!(total, 6);
+        for root in roots {
+            let (p, _) = horner(&parsed.coefficients, root.value);
+            assert!(p.abs() < 1e-8, "p({}) = {}", root.value, p);
+        }
+    }
+
+    #[test]
+    fn test_constant_has_no_roots() {
+        assert!(roots_of("5").is_empty());
+    }
+
+    #[test]
+    fn test_zero_polynomial() {
+        let parsed = SimplePolynomial::parse("0").unwrap();
+        assert!(matches!(
+            polynomial_roots(&parsed, ERROR_TOL, 100),
+            Err(SolverError::FunctionError(PolynomialError::ZeroPolynomial))
+        ));
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        let parsed = SimplePolynomial::parse("x^5 - 3x + 1").unwrap();
+        let result = polynomial_roots(&parsed, ERROR_TOL, 1);
+        assert!(matches!(result, Err(SolverError::MaxIterationsReached)));
+    }
+}
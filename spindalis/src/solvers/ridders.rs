@@ -0,0 +1,165 @@
+use crate::objective::Objective;
+use crate::solvers::{Bounds, SolveMode, SolverError, approx_error, bracket_values};
+
+/// Ridders' method.
+/// Each step evaluates the midpoint of the bracket and fits an exponential through
+/// the three points, giving quadratic convergence while keeping the root bracketed.
+pub fn ridders<O>(
+    function: &O,
+    bounds: Bounds,
+    error_tol: f64,
+    itermax: usize,
+    mode: SolveMode,
+) -> Result<f64, SolverError>
+where
+    O: Objective,
+{
+    let function: &dyn Objective = match mode {
+        SolveMode::Root => function,
+        SolveMode::Extrema => &function.derivative_or_numerical()?,
+    };
+    let (mut f_lower, mut f_upper) = bracket_values(function, &bounds)?;
+    if f_lower == 0.0 {
+        return Ok(bounds.lower);
+    }
+    if f_upper == 0.0 {
+        return Ok(bounds.upper);
+    }
+
+    let mut iter = 0;
+    let mut lower_bound = bounds.lower;
+    let mut upper_bound = bounds.upper;
+    let mut x_curr = bounds.init;
+    loop {
+        let x_old = x_curr;
+        let mid = (lower_bound + upper_bound) / 2.0;
+        let f_mid = function.eval(mid)?;
+        let s = (f_mid * f_mid - f_lower * f_upper).sqrt();
+        if s == 0.0 {
+            x_curr = mid;
+            break;
+        }
+        let sign = if f_lower > f_upper { 1.0 } else { -1.0 };
+        x_curr = mid + (mid - lower_bound) * sign * f_mid / s;
+        let f_curr = function.eval(x_curr)?;
+        let mut approx_err = approx_error(x_curr, x_old);
+
+        // Keep the tightest pair of points that still brackets the root
+        if f_curr == 0.0 {
+            approx_err = 0.0;
+        } else if f_mid * f_curr < 0.0 {
+            lower_bound = mid;
+            f_lower = f_mid;
+            upper_bound = x_curr;
+            f_upper = f_curr;
+        } else if f_lower * f_curr < 0.0 {
+            upper_bound = x_curr;
+            f_upper = f_curr;
+        } else {
+            lower_bound = x_curr;
+            f_lower = f_curr;
+        }
+        iter += 1;
+        if approx_err < error_tol || iter >= itermax {
+            break;
+        }
+    }
+    if iter >= itermax {
+        return Err(SolverError::MaxIterationsReached);
+    }
+
+    let poss_sol = function.eval(x_curr)?;
+    if poss_sol.abs() < 1e-4 {
+        Ok(x_curr)
+    } else {
+        Err(SolverError::NoConvergence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::{
+        IntermediatePolynomial, Polynomial, PolynomialTraits, SimplePolynomial,
+    };
+
+    const ERROR_TOL: f64 = 0.00001;
+
+    fn approx_eq(a: f64, b: f64, tol: f64) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn test_root_success() {
+        let parsed = SimplePolynomial::parse("x^2 - 4").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 1.0,
+            upper: 3.0,
+        };
+        let root = ridders(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 2.0, ERROR_TOL));
+    }
+
+    #[test]
+    fn test_root_success_intermediate_poly() {
+        let parsed = IntermediatePolynomial::parse("x^3 - 2x - 5").unwrap();
+        let bounds = Bounds {
+            lower: 2.0,
+            init: 2.0,
+            upper: 3.0,
+        };
+        let root = ridders(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 2.0945514815, 1e-6));
+    }
+
+    #[test]
+    fn test_transcendental_root() {
+        // cos(x) = x near 0.739085
+        let parsed = Polynomial::parse("cos(x) - x").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 0.5,
+            upper: 1.0,
+        };
+        let root = ridders(&parsed, bounds, 1e-10, 20, SolveMode::Root).unwrap();
+        assert!(approx_eq(root, 0.7390851332, 1e-9));
+    }
+
+    #[test]
+    fn test_extrema_success() {
+        // f(x) = x^2 - 5x + 10 has a minimum at x = 2.5
+        let parsed = SimplePolynomial::parse("x^2 - 5x + 10").unwrap();
+        let bounds = Bounds {
+            lower: -1.0,
+            init: 0.0,
+            upper: 3.0,
+        };
+        let min_x = ridders(&parsed, bounds, ERROR_TOL, 100, SolveMode::Extrema).unwrap();
+        assert!(approx_eq(min_x, 2.5, ERROR_TOL));
+    }
+
+    #[test]
+    fn test_no_sign_change() {
+        let parsed = SimplePolynomial::parse("x^2 + 5x + 10").unwrap();
+        let bounds = Bounds {
+            lower: -1.0,
+            init: 0.0,
+            upper: 1.0,
+        };
+        let result = ridders(&parsed, bounds, ERROR_TOL, 100, SolveMode::Root);
+        assert!(matches!(result, Err(SolverError::NoConvergence)));
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        let parsed = SimplePolynomial::parse("x^5 - 3x + 1").unwrap();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 0.5,
+            upper: 1.0,
+        };
+        let result = ridders(&parsed, bounds, 1e-12, 1, SolveMode::Root);
+        assert!(matches!(result, Err(SolverError::MaxIterationsReached)));
+    }
+}
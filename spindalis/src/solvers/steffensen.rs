@@ -0,0 +1,92 @@
+use crate::objective::Objective;
+use crate::solvers::{SolveMode, SolverError};
+
+/// Steffensen's method.
+/// Estimates the derivative from the forward difference `f(x + f(x)) - f(x)`,
+/// which gives quadratic convergence near a simple root without a derivative.
+pub fn steffensen_method<O>(
+    function: &O,
+    x_init: f64,
+    itermax: usize,
+    error_tol: f64,
+    mode: SolveMode,
+) -> Result<f64, SolverError>
+where
+    O: Objective,
+{
+    let mut iter = 0;
+    let mut x_curr = x_init;
+    let mut approx_err = 100_f64;
+    let function: &dyn Objective = match mode {
+        SolveMode::Root => function,
+        SolveMode::Extrema => &function.derivative_or_numerical()?,
+    };
+    loop {
+        let f_curr = function.eval(x_curr)?;
+        if f_curr == 0.0 {
+            return Ok(x_curr);
+        }
+        let slope = (function.eval(x_curr + f_curr)? - f_curr) / f_curr;
+        if slope == 0.0 {
+            return Err(SolverError::NoConvergence);
+        }
+        let xr_old = x_curr;
+        x_curr = xr_old - f_curr / slope;
+        iter += 1;
+        if x_curr != 0 as f64 {
+            approx_err = ((x_curr - xr_old).abs() / x_curr) * 100.0;
+        }
+        if approx_err.abs() < error_tol || iter >= itermax {
+            break;
+        }
+    }
+    if iter >= itermax {
+        return Err(SolverError::MaxIterationsReached);
+    }
+    Ok(x_curr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::{
+        IntermediatePolynomial, Polynomial, PolynomialTraits, SimplePolynomial,
+    };
+
+    const ERROR_TOL: f64 = 0.0001;
+
+    #[test]
+    fn test_root_success() {
+        let parsed = SimplePolynomial::parse("x^2 - 4").unwrap();
+        let root = steffensen_method(&parsed, 2.5, 100, ERROR_TOL, SolveMode::Root).unwrap();
+        assert!((root - 2.0).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_root_success_intermediate_poly() {
+        let parsed = IntermediatePolynomial::parse("x^3 - 2x - 5").unwrap();
+        let root = steffensen_method(&parsed, 2.0, 100, ERROR_TOL, SolveMode::Root).unwrap();
+        assert!((root - 2.0945514815).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_transcendental_root() {
+        let parsed = Polynomial::parse("cos(x) - x").unwrap();
+        let root = steffensen_method(&parsed, 0.5, 100, 1e-8, SolveMode::Root).unwrap();
+        assert!((root - 0.7390851332).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_extrema_success() {
+        let parsed = SimplePolynomial::parse("-x^2 + 4x").unwrap();
+        let x = steffensen_method(&parsed, 1.5, 100, ERROR_TOL, SolveMode::Extrema).unwrap();
+        assert!((x - 2.0).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_no_convergence() {
+        let parsed = SimplePolynomial::parse("x^2 + 4").unwrap();
+        let result = steffensen_method(&parsed, 2.0, 100, ERROR_TOL, SolveMode::Root);
+        assert!(result.is_err());
+    }
+}
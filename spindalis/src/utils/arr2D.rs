@@ -1,9 +1,12 @@
-use crate::decomposition::lu_pivot_decomposition;
+use crate::decomposition::{
+    SVDMode, lu_pivot_decomposition, lu_pivot_decomposition_unchecked, svd_decomposition,
+};
+use crate::solvers::SolverError;
 use crate::utils::{Arr2DError, back_substitution, forward_substitution};
 use std::{
     any::type_name,
     fmt::{self, Display},
-    ops::{Div, Index, IndexMut, Mul},
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Default)]
@@ -350,6 +353,274 @@ where
     }
 }
 
+impl<T> Arr2D<T> {
+    /// Combine two arrays element by element with `f`.
+    /// Each dimension must either match or be 1 in one of the arrays, in which case
+    /// that row or column vector is repeated across the other array.
+    pub fn broadcast_with<U, V, F>(&self, rhs: &Arr2D<U>, f: F) -> Result<Arr2D<V>, Arr2DError>
+    where
+        F: Fn(&T, &U) -> V,
+    {
+        let mismatch = || Arr2DError::InvalidBroadcastShape {
+            lhs: self.shape(),
+            rhs: rhs.shape(),
+        };
+        let height = broadcast_dim(self.height, rhs.height).ok_or_else(mismatch)?;
+        let width = broadcast_dim(self.width, rhs.width).ok_or_else(mismatch)?;
+
+        let mut inner = Vec::with_capacity(height * width);
+        for i in 0..height {
+            let (li, ri) = (i.min(self.height - 1), i.min(rhs.height - 1));
+            for j in 0..width {
+                let (lj, rj) = (j.min(self.width - 1), j.min(rhs.width - 1));
+                inner.push(f(
+                    &self.inner[li * self.width + lj],
+                    &rhs.inner[ri * rhs.width + rj],
+                ));
+            }
+        }
+        Ok(Arr2D {
+            inner,
+            height,
+            width,
+        })
+    }
+
+    /// Elementwise sum, broadcasting row and column vectors
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, Arr2DError>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        self.broadcast_with(rhs, |&a, &b| a + b)
+    }
+
+    /// Elementwise difference, broadcasting row and column vectors
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, Arr2DError>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        self.broadcast_with(rhs, |&a, &b| a - b)
+    }
+
+    /// Hadamard (elementwise) product, broadcasting row and column vectors
+    pub fn hadamard(&self, rhs: &Self) -> Result<Self, Arr2DError>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        self.broadcast_with(rhs, |&a, &b| a * b)
+    }
+
+    /// Elementwise quotient, broadcasting row and column vectors
+    pub fn elementwise_div(&self, rhs: &Self) -> Result<Self, Arr2DError>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        self.broadcast_with(rhs, |&a, &b| a / b)
+    }
+}
+
+// Broadcast length of one dimension, where a length of 1 stretches to match the other
+fn broadcast_dim(lhs: usize, rhs: usize) -> Option<usize> {
+    if lhs == rhs || rhs == 1 {
+        Some(lhs)
+    } else if lhs == 1 {
+        Some(rhs)
+    } else {
+        None
+    }
+}
+
+// Add and Sub for every owned/borrowed pair of arrays, plus array op scalar.
+// Like Mul, a shape mismatch gives an empty array; use `try_add` or `try_sub`
+// to get the error instead.
+macro_rules! elementwise_op {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        // &matrix op &matrix
+        impl<'b, T> $trait<&'b Arr2D<T>> for &Arr2D<T>
+        where
+            T: $trait<Output = T> + Copy,
+        {
+            type Output = Arr2D<T>;
+
+            fn $method(self, rhs: &'b Arr2D<T>) -> Arr2D<T> {
+                self.$checked(rhs).unwrap_or_else(|_| Arr2D::new())
+            }
+        }
+
+        // matrix op matrix
+        impl<T> $trait<Arr2D<T>> for Arr2D<T>
+        where
+            T: $trait<Output = T> + Copy,
+        {
+            type Output = Arr2D<T>;
+
+            fn $method(self, rhs: Arr2D<T>) -> Arr2D<T> {
+                self.$checked(&rhs).unwrap_or_else(|_| Arr2D::new())
+            }
+        }
+
+        // matrix op &matrix
+        impl<'b, T> $trait<&'b Arr2D<T>> for Arr2D<T>
+        where
+            T: $trait<Output = T> + Copy,
+        {
+            type Output = Arr2D<T>;
+
+            fn $method(self, rhs: &'b Arr2D<T>) -> Arr2D<T> {
+                self.$checked(rhs).unwrap_or_else(|_| Arr2D::new())
+            }
+        }
+
+        // &matrix op matrix
+        impl<T> $trait<Arr2D<T>> for &Arr2D<T>
+        where
+            T: $trait<Output = T> + Copy,
+        {
+            type Output = Arr2D<T>;
+
+            fn $method(self, rhs: Arr2D<T>) -> Arr2D<T> {
+                self.$checked(&rhs).unwrap_or_else(|_| Arr2D::new())
+            }
+        }
+
+        // matrix op scalar
+        impl<T> $trait<T> for Arr2D<T>
+        where
+            T: $trait<Output = T> + Copy,
+        {
+            type Output = Arr2D<T>;
+
+            fn $method(self, rhs: T) -> Arr2D<T> {
+                (&self).$method(rhs)
+            }
+        }
+
+        // &matrix op scalar
+        impl<T> $trait<T> for &Arr2D<T>
+        where
+            T: $trait<Output = T> + Copy,
+        {
+            type Output = Arr2D<T>;
+
+            fn $method(self, rhs: T) -> Arr2D<T> {
+                self.map(|&a| a.$method(rhs))
+            }
+        }
+    };
+}
+
+elementwise_op!(Add, add, try_add);
+elementwise_op!(Sub, sub, try_sub);
+
+// Neg implementation for -&Arr2D (-&matrix)
+impl<T> Neg for &Arr2D<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Arr2D<T>;
+
+    fn neg(self) -> Arr2D<T> {
+        self.map(|&a| -a)
+    }
+}
+
+// Neg implementation for -Arr2D (-matrix)
+impl<T> Neg for Arr2D<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Arr2D<T>;
+
+    fn neg(self) -> Arr2D<T> {
+        -&self
+    }
+}
+
+// Compound assignment with a scalar, applied to every element in place
+macro_rules! scalar_assign {
+    ($trait:ident, $method:ident, $op:ident, $op_method:ident) => {
+        impl<T> $trait<T> for Arr2D<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            fn $method(&mut self, rhs: T) {
+                for item in &mut self.inner {
+                    *item = item.$op_method(rhs);
+                }
+            }
+        }
+    };
+}
+
+// Compound assignment with another array, by value or by reference, in place.
+// `rhs` may be broadcast into the shape of the left-hand side, but never the other
+// way around, so any other shape panics rather than reshaping or emptying `self`.
+macro_rules! array_assign {
+    ($trait:ident, $method:ident, $op:ident, $op_method:ident) => {
+        impl<'b, T> $trait<&'b Arr2D<T>> for Arr2D<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            fn $method(&mut self, rhs: &'b Arr2D<T>) {
+                broadcast_assign(self, rhs, |a, b| a.$op_method(b));
+            }
+        }
+
+        impl<T> $trait<Arr2D<T>> for Arr2D<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            fn $method(&mut self, rhs: Arr2D<T>) {
+                broadcast_assign(self, &rhs, |a, b| a.$op_method(b));
+            }
+        }
+    };
+}
+
+fn broadcast_assign<T: Copy>(lhs: &mut Arr2D<T>, rhs: &Arr2D<T>, f: impl Fn(T, T) -> T) {
+    let fits = |l: usize, r: usize| l == r || r == 1;
+    if !fits(lhs.height, rhs.height) || !fits(lhs.width, rhs.width) {
+        let err = Arr2DError::InvalidBroadcastShape {
+            lhs: lhs.shape(),
+            rhs: rhs.shape(),
+        };
+        panic!("cannot assign an array of a different shape: {err:?}");
+    }
+    let width = lhs.width;
+    for (k, value) in lhs.inner.iter_mut().enumerate() {
+        let (i, j) = (k / width, k % width);
+        let (ri, rj) = (i.min(rhs.height - 1), j.min(rhs.width - 1));
+        *value = f(*value, rhs.inner[ri * rhs.width + rj]);
+    }
+}
+
+scalar_assign!(AddAssign, add_assign, Add, add);
+scalar_assign!(SubAssign, sub_assign, Sub, sub);
+scalar_assign!(MulAssign, mul_assign, Mul, mul);
+scalar_assign!(DivAssign, div_assign, Div, div);
+array_assign!(AddAssign, add_assign, Add, add);
+array_assign!(SubAssign, sub_assign, Sub, sub);
+
+// MulAssign implementation for matrix *= &matrix (matrix product)
+impl<'b, T> MulAssign<&'b Arr2D<T>> for Arr2D<T>
+where
+    T: Mul<Output = T> + Clone + std::default::Default + std::marker::Copy + std::ops::AddAssign,
+{
+    fn mul_assign(&mut self, rhs: &'b Arr2D<T>) {
+        *self = &*self * rhs;
+    }
+}
+
+// MulAssign implementation for matrix *= matrix (matrix product)
+impl<T> MulAssign<Arr2D<T>> for Arr2D<T>
+where
+    T: Mul<Output = T> + Clone + std::default::Default + std::marker::Copy + std::ops::AddAssign,
+{
+    fn mul_assign(&mut self, rhs: Arr2D<T>) {
+        *self = &*self * &rhs;
+    }
+}
+
 impl<T: Copy> Arr2D<T> {
     pub fn as_scalar(&self) -> Option<T> {
         if self.height == 1 && self.width == 1 {
@@ -425,6 +696,112 @@ impl<T: Copy> Arr2D<T> {
     }
 }
 
+/// Matrix norms accepted by [`Arr2D::norm`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixNorm {
+    /// Square root of the sum of the squared entries
+    Frobenius,
+    /// Largest absolute column sum
+    One,
+    /// Largest absolute row sum
+    Infinity,
+    /// Largest singular value (spectral norm)
+    Two,
+}
+
+impl Arr2D<f64> {
+    /// Determinant from the diagonal of U in PA = LU, with the sign of the permutation.
+    /// Uses `lu_pivot_decomposition_unchecked`, so tiny pivots are not mistaken for zero
+    /// and the result is 0 only when U has an exact zero on its diagonal.
+    pub fn determinant(&self) -> Result<f64, Arr2DError> {
+        let (_, upper, permutation) =
+            lu_pivot_decomposition_unchecked(self).map_err(|e| match e {
+                SolverError::InvalidVector(e) => e,
+                _ => Arr2DError::NonSquareMatrix,
+            })?;
+
+        // Row i of P has its 1 in column order[i]; each swap that sorts it flips the sign
+        let mut order: Vec<usize> = permutation
+            .rows()
+            .map(|row| row.iter().position(|p| *p == 1.0).unwrap_or(0))
+            .collect();
+        let mut sign = 1.0;
+        for i in 0..order.len() {
+            while order[i] != i {
+                let j = order[i];
+                order.swap(i, j);
+                sign = -sign;
+            }
+        }
+        Ok((0..self.height).fold(sign, |det, i| det * upper[i][i]))
+    }
+
+    /// Sum of the diagonal entries
+    pub fn trace(&self) -> Result<f64, Arr2DError> {
+        if self.height != self.width {
+            return Err(Arr2DError::NonSquareMatrix);
+        }
+        Ok((0..self.height).map(|i| self[i][i]).sum())
+    }
+
+    /// The given matrix norm. Only `MatrixNorm::Two` needs an SVD, which fails with
+    /// `NoConvergence` if the QR sweeps do not converge.
+    pub fn norm(&self, kind: MatrixNorm) -> Result<f64, Arr2DError> {
+        let norm = match kind {
+            MatrixNorm::Frobenius => self.inner.iter().map(|x| x * x).sum::<f64>().sqrt(),
+            MatrixNorm::One => (0..self.width)
+                .map(|j| self.rows().map(|row| row[j].abs()).sum::<f64>())
+                .fold(0.0, f64::max),
+            MatrixNorm::Infinity => self
+                .rows()
+                .map(|row| row.iter().map(|x| x.abs()).sum::<f64>())
+                .fold(0.0, f64::max),
+            MatrixNorm::Two => self.singular_values()?.first().copied().unwrap_or(0.0),
+        };
+        Ok(norm)
+    }
+
+    /// Numerical rank, the number of singular values above
+    /// `max(height, width) * σ_max * f64::EPSILON`
+    pub fn rank(&self) -> Result<usize, Arr2DError> {
+        let singular_values = self.singular_values()?;
+        let tol = self.rank_tol(&singular_values);
+        Ok(singular_values.iter().filter(|s| **s > tol).count())
+    }
+
+    /// Number of singular values above an absolute tolerance
+    pub fn rank_with_tol(&self, tol: f64) -> Result<usize, Arr2DError> {
+        Ok(self.singular_values()?.iter().filter(|s| **s > tol).count())
+    }
+
+    /// Condition number in the 2-norm, `σ_max / σ_min`. Matrices that are rank
+    /// deficient by the tolerance of `rank` have an infinite condition number.
+    pub fn condition_number(&self) -> Result<f64, Arr2DError> {
+        let singular_values = self.singular_values()?;
+        let tol = self.rank_tol(&singular_values);
+        match (singular_values.first(), singular_values.last()) {
+            (Some(largest), Some(smallest)) if *smallest > tol => Ok(largest / smallest),
+            _ => Ok(f64::INFINITY),
+        }
+    }
+
+    // Default tolerance for treating a singular value as zero
+    fn rank_tol(&self, singular_values: &[f64]) -> f64 {
+        let largest = singular_values.first().copied().unwrap_or(0.0);
+        self.height.max(self.width) as f64 * largest * f64::EPSILON
+    }
+
+    // Singular values in decreasing order
+    fn singular_values(&self) -> Result<Vec<f64>, Arr2DError> {
+        let (_, singular_values, _) =
+            svd_decomposition(self, SVDMode::Thin).map_err(|e| match e {
+                SolverError::InvalidVector(e) => e,
+                _ => Arr2DError::NoConvergence,
+            })?;
+        Ok(singular_values)
+    }
+}
+
 /// Iterator for Arr2D
 pub struct Arr2DRows<'a, T> {
     data: &'a [T],
@@ -1203,6 +1580,126 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    // --- elementwise ---
+
+    #[test]
+    fn test_Add_trait_mat_add_mat() {
+        let arr1 = Arr2D::from(&[[1, 2], [3, 4]]);
+        let arr2 = Arr2D::from(&[[10, 20], [30, 40]]);
+        let expected = Arr2D::from(&[[11, 22], [33, 44]]);
+
+        assert_eq!(&arr1 + &arr2, expected);
+        assert_eq!(arr1.clone() + &arr2, expected);
+        assert_eq!(&arr1 + arr2.clone(), expected);
+        assert_eq!(arr1 + arr2, expected);
+    }
+
+    #[test]
+    fn test_Sub_trait_mat_sub_mat() {
+        let arr1 = Arr2D::from(&[[5, 5], [5, 5]]);
+        let arr2 = Arr2D::from(&[[1, 2], [3, 4]]);
+        let expected = Arr2D::from(&[[4, 3], [2, 1]]);
+
+        assert_eq!(&arr1 - &arr2, expected);
+        assert_eq!(arr1 - arr2, expected);
+    }
+
+    #[test]
+    fn test_scalar_add_sub() {
+        let mat = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+
+        assert_eq!(&mat + 1.0, Arr2D::from(&[[2.0, 3.0], [4.0, 5.0]]));
+        assert_eq!(mat - 1.0, Arr2D::from(&[[0.0, 1.0], [2.0, 3.0]]));
+    }
+
+    #[test]
+    fn test_Neg_trait() {
+        let mat = Arr2D::from(&[[1, -2], [0, 4]]);
+        let expected = Arr2D::from(&[[-1, 2], [0, -4]]);
+
+        assert_eq!(-&mat, expected);
+        assert_eq!(-mat, expected);
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let arr1 = Arr2D::from(&[[1, 2], [3, 4]]);
+        let arr2 = Arr2D::from(&[[5, 6], [7, 8]]);
+        let expected = Arr2D::from(&[[5, 12], [21, 32]]);
+
+        assert_eq!(arr1.hadamard(&arr2).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_elementwise_div() {
+        let arr1 = Arr2D::from(&[[1.0, 4.0], [9.0, 16.0]]);
+        let arr2 = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let expected = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+
+        assert_eq!(arr1.elementwise_div(&arr2).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_broadcast_row_and_column() {
+        let mat = Arr2D::from(&[[1, 2, 3], [4, 5, 6]]);
+        let row = Arr2D::from(&[[10, 20, 30]]);
+        let col = Arr2D::from(&[[1], [2]]);
+
+        assert_eq!(&mat + &row, Arr2D::from(&[[11, 22, 33], [14, 25, 36]]));
+        assert_eq!(&mat - &col, Arr2D::from(&[[0, 1, 2], [2, 3, 4]]));
+        assert_eq!(
+            col.hadamard(&row).unwrap(),
+            Arr2D::from(&[[10, 20, 30], [20, 40, 60]])
+        );
+    }
+
+    #[test]
+    fn test_broadcast_shape_mismatch_errors() {
+        let arr1 = Arr2D::from(&[[1, 2, 3], [4, 5, 6]]);
+        let arr2 = Arr2D::from(&[[1, 2], [3, 4]]);
+
+        let result = arr1.try_add(&arr2);
+        assert!(matches!(
+            result,
+            Err(Arr2DError::InvalidBroadcastShape {
+                lhs: (2, 3),
+                rhs: (2, 2)
+            })
+        ));
+        assert!((arr1 + arr2).is_empty());
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let mut mat = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        mat += &Arr2D::from(&[[1.0, 1.0]]);
+        assert_eq!(mat, Arr2D::from(&[[2.0, 3.0], [4.0, 5.0]]));
+        mat -= Arr2D::from(&[[2.0], [4.0]]);
+        assert_eq!(mat, Arr2D::from(&[[0.0, 1.0], [0.0, 1.0]]));
+        mat *= 4.0;
+        mat /= 2.0;
+        mat += 1.0;
+        mat -= 0.5;
+        assert_eq!(mat, Arr2D::from(&[[0.5, 2.5], [0.5, 2.5]]));
+        mat *= Arr2D::identity(2);
+        assert_eq!(mat, Arr2D::from(&[[0.5, 2.5], [0.5, 2.5]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidBroadcastShape")]
+    fn test_assign_mismatched_shape() {
+        let mut mat = Arr2D::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        mat += &Arr2D::from(&[[1.0, 2.0, 3.0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidBroadcastShape")]
+    fn test_assign_does_not_broadcast_self() {
+        // A row vector would have to grow to hold the result
+        let mut row = Arr2D::from(&[[1.0, 2.0]]);
+        row -= Arr2D::from(&[[1.0, 1.0], [2.0, 2.0]]);
+    }
+
     // --- misc ---
 
     #[test]
@@ -1278,4 +1775,80 @@ mod tests {
 
         assert_eq!(rounded_result, expected);
     }
+
+    // --- matrix analysis ---
+
+    #[test]
+    fn test_determinant() {
+        let matrix = Arr2D::from(&[[3.0, 0.0, 2.0], [2.0, 0.0, -2.0], [0.0, 1.0, 1.0]]);
+        assert!((matrix.determinant().unwrap() - 10.0).abs() < 1e-12);
+
+        // One row swap flips the sign
+        let matrix = Arr2D::from(&[[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(matrix.determinant().unwrap(), -1.0);
+
+        let singular = Arr2D::from(&[[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.determinant().unwrap(), 0.0);
+
+        // Tiny pivots are not mistaken for zero
+        let scaled = Arr2D::from(&[[1e-16, 0.0], [0.0, 1e16]]);
+        assert!((scaled.determinant().unwrap() - 1.0).abs() < 1e-15);
+        let small = &Arr2D::<f64>::identity(2) * 1e-17;
+        assert!((small.determinant().unwrap() - 1e-34).abs() < 1e-49);
+
+        let wide = Arr2D::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert!(matches!(
+            wide.determinant(),
+            Err(Arr2DError::NonSquareMatrix)
+        ));
+        assert!(matches!(wide.trace(), Err(Arr2DError::NonSquareMatrix)));
+    }
+
+    #[test]
+    fn test_trace_and_norms() {
+        let matrix = Arr2D::from(&[[1.0, -2.0], [-3.0, 4.0]]);
+        assert_eq!(matrix.trace().unwrap(), 5.0);
+        assert_eq!(matrix.norm(MatrixNorm::One).unwrap(), 6.0);
+        assert_eq!(matrix.norm(MatrixNorm::Infinity).unwrap(), 7.0);
+        assert!((matrix.norm(MatrixNorm::Frobenius).unwrap() - 30f64.sqrt()).abs() < 1e-12);
+        // σ_max² is the largest eigenvalue of AᵀA = [[10, -14], [-14, 20]]
+        let expected = (15.0 + 221f64.sqrt()).sqrt();
+        assert!((matrix.norm(MatrixNorm::Two).unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rank() {
+        let matrix = Arr2D::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(matrix.rank().unwrap(), 2);
+        assert_eq!(Arr2D::<f64>::identity(4).rank().unwrap(), 4);
+
+        // A design matrix with a duplicated column
+        let design = Arr2D::from(&[
+            [1.0, 1.0, 1.0],
+            [1.0, 2.0, 2.0],
+            [1.0, 3.0, 3.0],
+            [1.0, 4.0, 4.0],
+        ]);
+        assert_eq!(design.rank().unwrap(), 2);
+        assert_eq!(design.transpose().rank().unwrap(), 2);
+
+        let nearly = Arr2D::from(&[[1.0, 0.0], [0.0, 1e-9]]);
+        assert_eq!(nearly.rank().unwrap(), 2);
+        assert_eq!(nearly.rank_with_tol(1e-6).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let matrix = Arr2D::from(&[[2.0, 0.0], [0.0, 0.5]]);
+        assert!((matrix.condition_number().unwrap() - 4.0).abs() < 1e-12);
+
+        // Agrees with ‖A‖₂‖A⁻¹‖₂
+        let matrix = Arr2D::from(&[[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        let expected = matrix.norm(MatrixNorm::Two).unwrap()
+            * matrix.inverse().unwrap().norm(MatrixNorm::Two).unwrap();
+        assert!((matrix.condition_number().unwrap() - expected).abs() < 1e-10);
+
+        let singular = Arr2D::from(&[[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.condition_number().unwrap(), f64::INFINITY);
+    }
 }
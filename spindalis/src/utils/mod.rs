@@ -1,10 +1,13 @@
 #[allow(non_snake_case)]
 pub mod arr2D;
+pub mod sparse;
 pub mod substitution;
 pub mod variation;
 
 pub use arr2D::Arr2D;
+pub use arr2D::MatrixNorm;
 pub use arr2D::Rounding;
+pub use sparse::{CooMatrix, CscMatrix, CsrMatrix};
 pub use substitution::back_substitution;
 pub use substitution::forward_substitution;
 pub use variation::arith_mean;
@@ -28,10 +31,20 @@ pub enum Arr2DError {
         lhs: usize,
         rhs: usize,
     },
+    InvalidBroadcastShape {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    IndexOutOfBounds {
+        row: usize,
+        col: usize,
+        shape: (usize, usize),
+    },
     ConversionFailed {
         from: &'static str,
         to: &'static str,
     },
+    NoConvergence,
 }
 
 #[derive(Copy, Clone)]
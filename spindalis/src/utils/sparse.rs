@@ -0,0 +1,564 @@
+// Sparse matrix formats. COO (coordinate) is for assembling a matrix entry by entry,
+// CSR (compressed sparse row) for fast products and row access, and CSC (compressed
+// sparse column) for column access. Assemble in COO, then convert once.
+use crate::utils::{Arr2D, Arr2DError};
+
+/// Sparse matrix stored as `(row, column, value)` triplets.
+///
+/// Triplets can be pushed in any order and may repeat a position; repeated entries
+/// are summed when converting to another format, as in finite element assembly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix {
+    pub height: usize,
+    pub width: usize,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<f64>,
+}
+
+/// Compressed sparse row matrix. Entries are sorted by column within each row, with
+/// no duplicates and no stored zeros.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix {
+    pub height: usize,
+    pub width: usize,
+    // Row i has its entries at indptr[i]..indptr[i + 1]
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+/// Compressed sparse column matrix. Entries are sorted by row within each column,
+/// with no duplicates and no stored zeros.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix {
+    pub height: usize,
+    pub width: usize,
+    // Column j has its entries at indptr[j]..indptr[j + 1]
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl CooMatrix {
+    /// An empty (all zero) matrix of the given shape
+    pub fn new(height: usize, width: usize) -> Self {
+        CooMatrix {
+            height,
+            width,
+            rows: Vec::new(),
+            cols: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn from_triplets(
+        height: usize,
+        width: usize,
+        triplets: &[(usize, usize, f64)],
+    ) -> Result<Self, Arr2DError> {
+        let mut matrix = CooMatrix::new(height, width);
+        for &(row, col, value) in triplets {
+            matrix.push(row, col, value)?;
+        }
+        Ok(matrix)
+    }
+
+    /// Adds `value` at `(row, col)`, on top of anything already there
+    pub fn push(&mut self, row: usize, col: usize, value: f64) -> Result<(), Arr2DError> {
+        if row >= self.height || col >= self.width {
+            return Err(Arr2DError::IndexOutOfBounds {
+                row,
+                col,
+                shape: self.shape(),
+            });
+        }
+        self.rows.push(row);
+        self.cols.push(col);
+        self.values.push(value);
+        Ok(())
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    /// Number of stored triplets, counting repeats
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        self.rows
+            .iter()
+            .zip(&self.cols)
+            .zip(&self.values)
+            .map(|((&row, &col), &value)| (row, col, value))
+    }
+
+    pub fn transpose(&self) -> CooMatrix {
+        CooMatrix {
+            height: self.width,
+            width: self.height,
+            rows: self.cols.clone(),
+            cols: self.rows.clone(),
+            values: self.values.clone(),
+        }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix {
+        let (indptr, indices, values) = compress(
+            self.height,
+            self.width,
+            self.rows.iter().copied().zip(self.cols.iter().copied()),
+            &self.values,
+        );
+        CsrMatrix {
+            height: self.height,
+            width: self.width,
+            indptr,
+            indices,
+            values,
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix {
+        let (indptr, indices, values) = compress(
+            self.width,
+            self.height,
+            self.cols.iter().copied().zip(self.rows.iter().copied()),
+            &self.values,
+        );
+        CscMatrix {
+            height: self.height,
+            width: self.width,
+            indptr,
+            indices,
+            values,
+        }
+    }
+
+    pub fn to_dense(&self) -> Arr2D<f64> {
+        let mut dense = Arr2D::full(0.0, self.height, self.width);
+        for (row, col, value) in self.triplets() {
+            dense[row][col] += value;
+        }
+        dense
+    }
+}
+
+impl CsrMatrix {
+    pub fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    /// Number of stored (non-zero) entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Column indices and values of the entries in row `i`
+    pub fn row(&self, i: usize) -> (&[usize], &[f64]) {
+        let range = self.indptr[i]..self.indptr[i + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    /// Entry at `(row, col)`, which is 0 if it is not stored
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        let (indices, values) = self.row(row);
+        indices
+            .binary_search(&col)
+            .map(|k| values[k])
+            .unwrap_or(0.0)
+    }
+
+    pub fn transpose(&self) -> CsrMatrix {
+        // The CSC arrays of A are the CSR arrays of Aᵀ
+        let csc = self.to_csc();
+        CsrMatrix {
+            height: self.width,
+            width: self.height,
+            indptr: csc.indptr,
+            indices: csc.indices,
+            values: csc.values,
+        }
+    }
+
+    pub fn to_coo(&self) -> CooMatrix {
+        let mut coo = CooMatrix::new(self.height, self.width);
+        for i in 0..self.height {
+            let (indices, values) = self.row(i);
+            for (&j, &value) in indices.iter().zip(values) {
+                coo.rows.push(i);
+                coo.cols.push(j);
+                coo.values.push(value);
+            }
+        }
+        coo
+    }
+
+    pub fn to_csc(&self) -> CscMatrix {
+        self.to_coo().to_csc()
+    }
+
+    pub fn to_dense(&self) -> Arr2D<f64> {
+        let mut dense = Arr2D::full(0.0, self.height, self.width);
+        for i in 0..self.height {
+            let (indices, values) = self.row(i);
+            for (&j, &value) in indices.iter().zip(values) {
+                dense[i][j] = value;
+            }
+        }
+        dense
+    }
+
+    /// Sparse matrix times vector, `Ax`
+    pub fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, Arr2DError> {
+        if x.len() != self.width {
+            return Err(Arr2DError::InvalidDotShape {
+                lhs: self.width,
+                rhs: x.len(),
+            });
+        }
+        Ok((0..self.height)
+            .map(|i| {
+                let (indices, values) = self.row(i);
+                indices.iter().zip(values).map(|(&j, a)| a * x[j]).sum()
+            })
+            .collect())
+    }
+
+    /// Sparse matrix times dense matrix, `AB`
+    pub fn dot(&self, rhs: &Arr2D<f64>) -> Result<Arr2D<f64>, Arr2DError> {
+        if rhs.height != self.width {
+            return Err(Arr2DError::InvalidDotShape {
+                lhs: self.width,
+                rhs: rhs.height,
+            });
+        }
+        let mut result = Arr2D::full(0.0, self.height, rhs.width);
+        for i in 0..self.height {
+            let (indices, values) = self.row(i);
+            for (&k, &a) in indices.iter().zip(values) {
+                for (r, b) in result[i].iter_mut().zip(&rhs[k]) {
+                    *r += a * b;
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl CscMatrix {
+    pub fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    /// Number of stored (non-zero) entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Row indices and values of the entries in column `j`
+    pub fn col(&self, j: usize) -> (&[usize], &[f64]) {
+        let range = self.indptr[j]..self.indptr[j + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    /// Entry at `(row, col)`, which is 0 if it is not stored
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        let (indices, values) = self.col(col);
+        indices
+            .binary_search(&row)
+            .map(|k| values[k])
+            .unwrap_or(0.0)
+    }
+
+    pub fn transpose(&self) -> CscMatrix {
+        // The CSR arrays of A are the CSC arrays of Aᵀ
+        let csr = self.to_csr();
+        CscMatrix {
+            height: self.width,
+            width: self.height,
+            indptr: csr.indptr,
+            indices: csr.indices,
+            values: csr.values,
+        }
+    }
+
+    pub fn to_coo(&self) -> CooMatrix {
+        let mut coo = CooMatrix::new(self.height, self.width);
+        for j in 0..self.width {
+            let (indices, values) = self.col(j);
+            for (&i, &value) in indices.iter().zip(values) {
+                coo.rows.push(i);
+                coo.cols.push(j);
+                coo.values.push(value);
+            }
+        }
+        coo
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix {
+        self.to_coo().to_csr()
+    }
+
+    pub fn to_dense(&self) -> Arr2D<f64> {
+        let mut dense = Arr2D::full(0.0, self.height, self.width);
+        for j in 0..self.width {
+            let (indices, values) = self.col(j);
+            for (&i, &value) in indices.iter().zip(values) {
+                dense[i][j] = value;
+            }
+        }
+        dense
+    }
+
+    /// Sparse matrix times vector, `Ax`
+    pub fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, Arr2DError> {
+        if x.len() != self.width {
+            return Err(Arr2DError::InvalidDotShape {
+                lhs: self.width,
+                rhs: x.len(),
+            });
+        }
+        let mut result = vec![0.0; self.height];
+        for (j, x) in x.iter().enumerate() {
+            let (indices, values) = self.col(j);
+            for (&i, a) in indices.iter().zip(values) {
+                result[i] += a * x;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Sparse matrix times dense matrix, `AB`
+    pub fn dot(&self, rhs: &Arr2D<f64>) -> Result<Arr2D<f64>, Arr2DError> {
+        if rhs.height != self.width {
+            return Err(Arr2DError::InvalidDotShape {
+                lhs: self.width,
+                rhs: rhs.height,
+            });
+        }
+        let mut result = Arr2D::full(0.0, self.height, rhs.width);
+        for k in 0..self.width {
+            let (indices, values) = self.col(k);
+            for (&i, &a) in indices.iter().zip(values) {
+                for (r, b) in result[i].iter_mut().zip(&rhs[k]) {
+                    *r += a * b;
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+// Compressed arrays for `major` rows (or columns) from unsorted (major, minor) pairs,
+// sorted by the minor index with duplicates summed and zeros dropped
+fn compress<I>(
+    major_len: usize,
+    minor_len: usize,
+    positions: I,
+    values: &[f64],
+) -> (Vec<usize>, Vec<usize>, Vec<f64>)
+where
+    I: Iterator<Item = (usize, usize)>,
+{
+    let mut buckets: Vec<Vec<(usize, f64)>> = vec![Vec::new(); major_len];
+    for ((major, minor), &value) in positions.zip(values) {
+        buckets[major].push((minor, value));
+    }
+
+    let mut indptr = Vec::with_capacity(major_len + 1);
+    let mut indices = Vec::new();
+    let mut data = Vec::new();
+    // Running sums for the current bucket, indexed by the minor index
+    let mut sums = vec![0.0; minor_len];
+    indptr.push(0);
+    for bucket in &mut buckets {
+        bucket.sort_by_key(|(minor, _)| *minor);
+        for &(minor, value) in bucket.iter() {
+            sums[minor] += value;
+        }
+        let mut last = None;
+        for &(minor, _) in bucket.iter() {
+            if last == Some(minor) {
+                continue;
+            }
+            last = Some(minor);
+            if sums[minor] != 0.0 {
+                indices.push(minor);
+                data.push(sums[minor]);
+            }
+            sums[minor] = 0.0;
+        }
+        indptr.push(indices.len());
+    }
+    (indptr, indices, data)
+}
+
+impl From<&Arr2D<f64>> for CooMatrix {
+    fn from(dense: &Arr2D<f64>) -> Self {
+        let mut coo = CooMatrix::new(dense.height, dense.width);
+        for (i, row) in dense.rows().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                if value != 0.0 {
+                    coo.rows.push(i);
+                    coo.cols.push(j);
+                    coo.values.push(value);
+                }
+            }
+        }
+        coo
+    }
+}
+
+impl From<&Arr2D<f64>> for CsrMatrix {
+    fn from(dense: &Arr2D<f64>) -> Self {
+        CooMatrix::from(dense).to_csr()
+    }
+}
+
+impl From<&Arr2D<f64>> for CscMatrix {
+    fn from(dense: &Arr2D<f64>) -> Self {
+        CooMatrix::from(dense).to_csc()
+    }
+}
+
+impl From<&CooMatrix> for CsrMatrix {
+    fn from(coo: &CooMatrix) -> Self {
+        coo.to_csr()
+    }
+}
+
+impl From<&CooMatrix> for CscMatrix {
+    fn from(coo: &CooMatrix) -> Self {
+        coo.to_csc()
+    }
+}
+
+// Densifying conversions, so that sparse matrices can be passed to the functions that
+// take `M: TryInto<Arr2D<f64>, Error = Arr2DError>`, such as the direct solvers
+impl TryFrom<&CooMatrix> for Arr2D<f64> {
+    type Error = Arr2DError;
+
+    fn try_from(sparse: &CooMatrix) -> Result<Self, Self::Error> {
+        Ok(sparse.to_dense())
+    }
+}
+
+impl TryFrom<&CsrMatrix> for Arr2D<f64> {
+    type Error = Arr2DError;
+
+    fn try_from(sparse: &CsrMatrix) -> Result<Self, Self::Error> {
+        Ok(sparse.to_dense())
+    }
+}
+
+impl TryFrom<&CscMatrix> for Arr2D<f64> {
+    type Error = Arr2DError;
+
+    fn try_from(sparse: &CscMatrix) -> Result<Self, Self::Error> {
+        Ok(sparse.to_dense())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Arr2D<f64> {
+        Arr2D::from(&[
+            [4.0, 0.0, 0.0, -1.0],
+            [0.0, 0.0, 2.5, 0.0],
+            [1.0, 0.0, 3.0, 0.0],
+        ])
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let dense = example();
+        let csr = CsrMatrix::from(&dense);
+        let csc = CscMatrix::from(&dense);
+        assert_eq!(csr.nnz(), 5);
+        assert_eq!(csc.nnz(), 5);
+        assert_eq!(csr.to_dense(), dense);
+        assert_eq!(csc.to_dense(), dense);
+        assert_eq!(csr.to_csc(), csc);
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(Arr2D::try_from(&csr.to_coo()).unwrap(), dense);
+        assert_eq!(csr.get(2, 2), 3.0);
+        assert_eq!(csc.get(1, 0), 0.0);
+        assert_eq!(csr.row(0), (&[0, 3][..], &[4.0, -1.0][..]));
+        assert_eq!(csc.col(2), (&[1, 2][..], &[2.5, 3.0][..]));
+    }
+
+    #[test]
+    fn test_triplet_assembly() {
+        // Repeated positions are summed and cancelling entries are dropped
+        let triplets = [
+            (2, 1, 1.0),
+            (0, 0, 2.0),
+            (2, 1, 0.5),
+            (1, 2, 3.0),
+            (0, 0, -2.0),
+            (1, 0, 4.0),
+        ];
+        let coo = CooMatrix::from_triplets(3, 3, &triplets).unwrap();
+        assert_eq!(coo.nnz(), 6);
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 3);
+        let expected = Arr2D::from(&[[0.0, 0.0, 0.0], [4.0, 0.0, 3.0], [0.0, 1.5, 0.0]]);
+        assert_eq!(csr.to_dense(), expected);
+        assert_eq!(coo.to_dense(), expected);
+        assert_eq!(coo.to_csc().to_dense(), expected);
+
+        let mut coo = CooMatrix::new(2, 2);
+        assert!(matches!(
+            coo.push(2, 0, 1.0),
+            Err(Arr2DError::IndexOutOfBounds {
+                row: 2,
+                col: 0,
+                shape: (2, 2)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let dense = example();
+        let csr = CsrMatrix::from(&dense);
+        let csc = CscMatrix::from(&dense);
+        assert_eq!(csr.transpose().to_dense(), dense.transpose());
+        assert_eq!(csc.transpose().to_dense(), dense.transpose());
+        assert_eq!(
+            CooMatrix::from(&dense).transpose().to_dense(),
+            dense.transpose()
+        );
+    }
+
+    #[test]
+    fn test_products() {
+        let dense = example();
+        let csr = CsrMatrix::from(&dense);
+        let csc = CscMatrix::from(&dense);
+        let x = [1.0, 2.0, -1.0, 0.5];
+        let expected = vec![3.5, -2.5, -2.0];
+        assert_eq!(csr.mul_vec(&x).unwrap(), expected);
+        assert_eq!(csc.mul_vec(&x).unwrap(), expected);
+
+        let rhs = Arr2D::from(&[[1.0, 0.0], [2.0, 1.0], [0.0, -1.0], [1.0, 3.0]]);
+        let expected = dense.dot(&rhs).unwrap();
+        assert_eq!(csr.dot(&rhs).unwrap(), expected);
+        assert_eq!(csc.dot(&rhs).unwrap(), expected);
+
+        assert!(matches!(
+            csr.mul_vec(&[1.0, 2.0]),
+            Err(Arr2DError::InvalidDotShape { lhs: 4, rhs: 2 })
+        ));
+        assert!(matches!(
+            csc.dot(&Arr2D::full(1.0, 3, 1)),
+            Err(Arr2DError::InvalidDotShape { lhs: 4, rhs: 3 })
+        ));
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::derivatives::partial_derivative_advanced;
+    use spindalis::polynomials::{Polynomial, PolynomialError, PolynomialTraits};
+
+    fn derive(expr: &str, var: &str) -> Polynomial {
+        let parsed = Polynomial::parse(expr).unwrap();
+        Polynomial::new(partial_derivative_advanced(parsed.expr(), var).unwrap())
+    }
+
+    fn assert_close(poly: &Polynomial, vars: &[(&str, f64)], expected: f64) {
+        let result = poly.eval_multivariate(&vars.to_vec()).unwrap();
+        assert!(
+            (result - expected).abs() < 1e-10,
+            "Expected {expected} but got {result} for {poly}",
+        );
+    }
+
+    #[test]
+    fn test_constant_is_zero() {
+        assert_eq!(format!("{}", derive("pi + e + 4", "x")), "0");
+    }
+
+    #[test]
+    fn test_other_variable_is_zero() {
+        assert_eq!(format!("{}", derive("4y^2 + ln(y)", "x")), "0");
+    }
+
+    #[test]
+    fn test_power_rule_display() {
+        assert_eq!(format!("{}", derive("x^3", "x")), "3x^2");
+    }
+
+    #[test]
+    fn test_linear_display() {
+        assert_eq!(format!("{}", derive("4x + 2", "x")), "4");
+    }
+
+    #[test]
+    fn test_sin_display() {
+        assert_eq!(format!("{}", derive("sin(x)", "x")), "cos(x)");
+    }
+
+    #[test]
+    fn test_cos_display() {
+        assert_eq!(format!("{}", derive("cos(x)", "x")), "-sin(x)");
+    }
+
+    #[test]
+    fn test_ln_display() {
+        assert_eq!(format!("{}", derive("ln(x)", "x")), "1 / x");
+    }
+
+    #[test]
+    fn test_exponential_display() {
+        assert_eq!(format!("{}", derive("e^x", "x")), "e ^ x");
+    }
+
+    #[test]
+    fn test_tan() {
+        let x: f64 = 0.3;
+        assert_close(&derive("tan(x)", "x"), &[("x", x)], 1.0 / x.cos().powi(2));
+    }
+
+    #[test]
+    fn test_cot() {
+        let x: f64 = 0.3;
+        assert_close(&derive("cot(x)", "x"), &[("x", x)], -1.0 / x.sin().powi(2));
+    }
+
+    #[test]
+    fn test_log() {
+        let x: f64 = 2.5;
+        assert_close(&derive("log(x)", "x"), &[("x", x)], 1.0 / (x * 10_f64.ln()));
+    }
+
+    #[test]
+    fn test_chain_rule() {
+        let x: f64 = 0.7;
+        let expected = 2.0 * x * (x * x + 1.0).cos();
+        assert_close(&derive("sin(x^2 + 1)", "x"), &[("x", x)], expected);
+    }
+
+    #[test]
+    fn test_nested_chain_rule() {
+        let x: f64 = 0.4;
+        // d/dx ln(cos(3x)) = -3tan(3x)
+        let expected = -3.0 * (3.0 * x).tan();
+        assert_close(&derive("ln(cos(3x))", "x"), &[("x", x)], expected);
+    }
+
+    #[test]
+    fn test_product_rule() {
+        let x: f64 = 1.2;
+        let expected = x.exp() * (x.sin() + x.cos());
+        assert_close(&derive("sin(x)*e^x", "x"), &[("x", x)], expected);
+    }
+
+    #[test]
+    fn test_quotient_rule() {
+        let x: f64 = 1.5;
+        // d/dx sin(x)/x = (x cos(x) - sin(x)) / x^2
+        let expected = (x * x.cos() - x.sin()) / (x * x);
+        assert_close(&derive("sin(x) / x", "x"), &[("x", x)], expected);
+    }
+
+    #[test]
+    fn test_variable_exponent() {
+        let x: f64 = 1.3;
+        // d/dx x^x = x^x (ln(x) + 1)
+        let expected = x.powf(x) * (x.ln() + 1.0);
+        assert_close(&derive("x^x", "x"), &[("x", x)], expected);
+    }
+
+    #[test]
+    fn test_constant_base_exponent() {
+        let x: f64 = 0.8;
+        let expected = 2_f64.powf(x) * 2_f64.ln();
+        assert_close(&derive("2^x", "x"), &[("x", x)], expected);
+    }
+
+    #[test]
+    fn test_multivariate_partial() {
+        let vars = [("x", 0.5), ("y", 2.0)];
+        let (x, y): (f64, f64) = (0.5, 2.0);
+        // d/dy sin(x)*e^x + ln(y) = 1/y
+        assert_close(&derive("sin(x)*e^x + ln(y)", "y"), &vars, 1.0 / y);
+        // d/dx x^2 y^3 + sin(xy) = 2xy^3 + ycos(xy)
+        let expected = 2.0 * x * y.powi(3) + y * (x * y).cos();
+        assert_close(&derive("x^2y^3 + sin(xy)", "x"), &vars, expected);
+    }
+
+    #[test]
+    fn test_unary_minus() {
+        assert_close(&derive("-sin(x)", "x"), &[("x", 0.0)], -1.0);
+    }
+
+    #[test]
+    fn test_factorial_unsupported() {
+        let parsed = Polynomial::parse("x!").unwrap();
+        let result = partial_derivative_advanced(parsed.expr(), "x");
+        assert!(matches!(
+            result,
+            Err(PolynomialError::UnsupportedDerivative { .. })
+        ));
+    }
+
+    #[test]
+    fn test_struct_derivative_with_functions() {
+        let parsed = Polynomial::parse("sin(x)*e^x").unwrap();
+        let derived = parsed.derivate_univariate().unwrap();
+        let x: f64 = 0.25;
+        let expected = x.exp() * (x.sin() + x.cos());
+        assert!((derived.eval_univariate(x).unwrap() - expected).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::integrals::{analytical_integral, definite_integral};
+    use spindalis::polynomials::{Polynomial, PolynomialError, PolynomialTraits};
+    use spindalis::solvers::{Bounds, SolveMode, bisection, newton_raphson_method};
+
+    const ERROR_TOL: f64 = 1e-5;
+
+    #[test]
+    fn test_eval_univariate() {
+        let parsed = Polynomial::parse("3x^2 - 2x + 1").unwrap();
+        let result = parsed.eval_univariate(2.0).unwrap();
+        assert_eq!(result, 9.0);
+    }
+
+    #[test]
+    fn test_eval_univariate_too_many_variables() {
+        let parsed = Polynomial::parse("sin(x)*e^x + ln(y)").unwrap();
+        let result = parsed.eval_univariate(2.0);
+        assert!(matches!(
+            result,
+            Err(PolynomialError::TooManyVariables { .. })
+        ));
+    }
+
+    #[test]
+    fn test_eval_multivariate() {
+        let parsed = Polynomial::parse("sin(x)*e^x + ln(y)").unwrap();
+        let result = parsed.eval_multivariate(&[("x", 0.0), ("y", 1.0)]).unwrap();
+        assert!(result.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_derivative_polynomial() {
+        let parsed = Polynomial::parse("x^3 - 4x").unwrap();
+        let derived = parsed.derivate_univariate().unwrap();
+        // 3x^2 - 4 at x = 2
+        assert_eq!(derived.eval_univariate(2.0).unwrap(), 8.0);
+    }
+
+    #[test]
+    fn test_derivative_quotient() {
+        let parsed = Polynomial::parse("1 / x").unwrap();
+        let derived = parsed.derivate_univariate().unwrap();
+        assert!((derived.eval_univariate(2.0).unwrap() + 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_derivative_exponential() {
+        let parsed = Polynomial::parse("e^(2x)").unwrap();
+        let derived = parsed.derivate_univariate().unwrap();
+        let expected = 2.0 * 2_f64.exp();
+        assert!((derived.eval_univariate(1.0).unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_partial_derivative() {
+        let parsed = Polynomial::parse("4x^2y^3 + 4x - 2y").unwrap();
+        let dx = parsed.derivate_multivariate("x");
+        let dy = parsed.derivate_multivariate("y");
+        let vars = [("x", 1.0), ("y", 2.0)];
+        // 8xy^3 + 4
+        assert_eq!(dx.eval_multivariate(&vars).unwrap(), 68.0);
+        // 12x^2y^2 - 2
+        assert_eq!(dy.eval_multivariate(&vars).unwrap(), 46.0);
+    }
+
+    #[test]
+    fn test_indefinite_integral() {
+        let parsed = Polynomial::parse("3x^2 + 2x + 1 + 1/x").unwrap();
+        let integrated = parsed.indefinite_integral_univariate().unwrap();
+        // x^3 + x^2 + x + ln(x) at x = 1
+        assert!((integrated.eval_univariate(1.0).unwrap() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_indefinite_integral_multivariate() {
+        let parsed = Polynomial::parse("6x^2y + y").unwrap();
+        let integrated = parsed.indefinite_integral_multivariate("x");
+        let vars = [("x", 2.0), ("y", 3.0)];
+        // 2x^3y + xy
+        assert_eq!(integrated.eval_multivariate(&vars).unwrap(), 54.0);
+    }
+
+    #[test]
+    fn test_bisection_with_functions() {
+        let parsed = Polynomial::parse("sin(x)*e^x - 1").unwrap();
+        let root = bisection(
+            &parsed,
+            Bounds {
+                lower: 0.0,
+                init: 0.2,
+                upper: 1.0,
+            },
+            ERROR_TOL,
+            100,
+            SolveMode::Root,
+        )
+        .unwrap();
+        assert!(parsed.eval_univariate(root).unwrap().abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_newton_raphson() {
+        let parsed = Polynomial::parse("x^2 - 2").unwrap();
+        let root = newton_raphson_method(&parsed, 1.0, 100, ERROR_TOL, SolveMode::Root).unwrap();
+        assert!((root - 2_f64.sqrt()).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_newton_raphson_with_functions() {
+        let parsed = Polynomial::parse("sin(x)*e^x - 1").unwrap();
+        let root = newton_raphson_method(&parsed, 0.5, 100, ERROR_TOL, SolveMode::Root).unwrap();
+        assert!(parsed.eval_univariate(root).unwrap().abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_definite_integral_with_functions() {
+        let parsed = Polynomial::parse("e^x").unwrap();
+        let result = definite_integral(&parsed, 0.0, 1.0, 100).unwrap();
+        let expected = 1_f64.exp() - 1.0;
+        assert!((result - expected).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_analytical_integral() {
+        let parsed = Polynomial::parse("64x^3 - 144x^2 + 108x - 27").unwrap();
+        let result = analytical_integral(&parsed, -3.0, 5.0).unwrap();
+        assert!((result - 2056.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::derivatives::partial_derivative_advanced;
+    use spindalis::polynomials::{Polynomial, PolynomialTraits, simplify_expr};
+
+    fn simplify(expr: &str) -> String {
+        let parsed = Polynomial::parse(expr).unwrap();
+        format!("{}", parsed.simplify())
+    }
+
+    #[test]
+    fn test_fold_constants() {
+        assert_eq!(simplify("2 * 3 + 4 / 2 - 1"), "7");
+        assert_eq!(simplify("5!"), "120");
+        assert_eq!(simplify("ln(e) + sin(0)"), "1");
+    }
+
+    #[test]
+    fn test_collect_like_terms() {
+        assert_eq!(simplify("2x + 3x - x"), "4x");
+        assert_eq!(simplify("x - y - 2x + 3y"), "-x + 2y");
+        assert_eq!(simplify("x - x"), "0");
+    }
+
+    #[test]
+    fn test_combine_powers() {
+        assert_eq!(simplify("x*x*x"), "x^3");
+        assert_eq!(simplify("x^2 * x^3"), "x^5");
+        assert_eq!(simplify("x^0.5 * x^0.5"), "x");
+        assert_eq!(simplify("(x^2)^3"), "x^6");
+        assert_eq!(simplify("x / x"), "1");
+    }
+
+    #[test]
+    fn test_combine_symbolic_powers() {
+        assert_eq!(simplify("x^a * x^b"), "x ^ (a + b)");
+        assert_eq!(simplify("e^x * e^x"), "e ^ (2x)");
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        assert_eq!(simplify("3 + x + 2 + x^2"), "x^2 + x + 5");
+        assert_eq!(simplify("x*y*x*4"), "4x^2 * y");
+        assert_eq!(simplify("2*pi*x"), "2π * x");
+    }
+
+    #[test]
+    fn test_same_form_regardless_of_input_order() {
+        let a = Polynomial::parse("y + 2x + x^2").unwrap().simplify();
+        let b = Polynomial::parse("x^2 + y + x*2").unwrap().simplify();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_distribute_numeric_factors() {
+        assert_eq!(simplify("2(x + 1)"), "2x + 2");
+        assert_eq!(simplify("(2x + 2) / 2"), "x + 1");
+        assert_eq!(simplify("-(x - 1)"), "-x + 1");
+    }
+
+    #[test]
+    fn test_sums_kept_as_factors() {
+        assert_eq!(simplify("(x + 1) * (x + 1)"), "(x + 1) ^ 2");
+        assert_eq!(simplify("x / (x + 1)"), "x / (x + 1)");
+    }
+
+    #[test]
+    fn test_negative_powers_become_division() {
+        assert_eq!(simplify("(2x)^-2"), "0.25 / x^2");
+    }
+
+    #[test]
+    fn test_function_arguments_simplified() {
+        assert_eq!(simplify("sin((x + 1))"), "sin(x + 1)");
+        assert_eq!(simplify("ln(x + x)"), "ln(2x)");
+    }
+
+    #[test]
+    fn test_simplified_value_unchanged() {
+        let expr = "4x + 2 - 5x^2 * 4x^4 / 6x^6 + sin(x)*x*sin(x)";
+        let parsed = Polynomial::parse(expr).unwrap();
+        let simplified = parsed.simplify();
+        for x in [0.5, 1.0, 2.5] {
+            let expected = parsed.eval_univariate(x).unwrap();
+            let result = simplified.eval_univariate(x).unwrap();
+            assert!((result - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_simplify_is_idempotent() {
+        let parsed = Polynomial::parse("x^2*y + 3 - y*x*x + sin(x)*2").unwrap();
+        let once = simplify_expr(parsed.expr());
+        assert_eq!(simplify_expr(&once), once);
+    }
+
+    #[test]
+    fn test_derivative_output() {
+        let parsed = Polynomial::parse("x^3 * ln(x)").unwrap();
+        let derived = partial_derivative_advanced(parsed.expr(), "x").unwrap();
+        assert_eq!(format!("{derived}"), "x^2 + 3x^2 * ln(x)");
+
+        let parsed = Polynomial::parse("1 / (x^2 + 1)").unwrap();
+        let derived = partial_derivative_advanced(parsed.expr(), "x").unwrap();
+        assert_eq!(format!("{derived}"), "-2x / (x^2 + 1) ^ 2");
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::polynomials::{
+        CompiledExpr, Expr, Instruction, Polynomial, PolynomialError, PolynomialTraits,
+        eval_advanced_polynomial,
+    };
+
+    #[test]
+    fn test_matches_tree_evaluation() {
+        let exprs = [
+            "4x^2 - 3x + 2",
+            "sin(x)*e^x + ln(y)",
+            "x / y - (x % 3) * y",
+            "-x^3 + tan(y) - cot(x) + log(x*y)",
+            "2^x * phi - tau / y + 4!",
+        ];
+        for expr in exprs {
+            let poly = Polynomial::parse(expr).unwrap();
+            let compiled = poly.compile().unwrap();
+            for (x, y) in [(0.5, 1.5), (1.25, 2.0), (3.0, 0.75)] {
+                let expected =
+                    eval_advanced_polynomial(poly.expr(), &[("x", x), ("y", y)]).unwrap();
+                let slots: Vec<f64> = compiled
+                    .variables()
+                    .iter()
+                    .map(|v| if v == "x" { x } else { y })
+                    .collect();
+                let result = compiled.eval(&slots).unwrap();
+                assert!(
+                    (result - expected).abs() < 1e-12,
+                    "{expr}: expected {expected}, got {result}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_slots_are_sorted() {
+        let poly = Polynomial::parse("z + 2y + 3x").unwrap();
+        let compiled = poly.compile().unwrap();
+        assert_eq!(compiled.variables(), ["x", "y", "z"]);
+        assert_eq!(compiled.slot("z"), Some(2));
+        assert_eq!(compiled.eval(&[1.0, 10.0, 100.0]).unwrap(), 123.0);
+    }
+
+    #[test]
+    fn test_custom_slot_order() {
+        let poly = Polynomial::parse("x - y").unwrap();
+        let compiled = CompiledExpr::with_variables(poly.expr(), &["y", "unused", "x"]).unwrap();
+        assert_eq!(compiled.eval(&[1.0, 0.0, 5.0]).unwrap(), 4.0);
+    }
+
+    #[test]
+    fn test_missing_slot() {
+        let poly = Polynomial::parse("x + y").unwrap();
+        let result = CompiledExpr::with_variables(poly.expr(), &["x"]);
+        assert!(matches!(
+            result,
+            Err(PolynomialError::VariableNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wrong_number_of_values() {
+        let compiled = Polynomial::parse("x + y").unwrap().compile().unwrap();
+        assert!(matches!(
+            compiled.eval(&[1.0]),
+            Err(PolynomialError::TooFewVariables { .. })
+        ));
+        assert!(matches!(
+            compiled.eval(&[1.0, 2.0, 3.0]),
+            Err(PolynomialError::TooManyVariables { .. })
+        ));
+    }
+
+    #[test]
+    fn test_constants_folded() {
+        let compiled = Polynomial::parse("2 * pi * x + (cos(0) + 2) * 3!")
+            .unwrap()
+            .compile()
+            .unwrap();
+        assert_eq!(
+            compiled.instructions(),
+            [
+                Instruction::Push(2.0 * std::f64::consts::PI),
+                Instruction::Load(0),
+                Instruction::Mul,
+                Instruction::Push(18.0),
+                Instruction::Add,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_factorial() {
+        let compiled = Polynomial::parse("x!").unwrap().compile().unwrap();
+        assert_eq!(compiled.eval(&[4.0]).unwrap(), 24.0);
+        assert!(matches!(
+            compiled.eval(&[1.5]),
+            Err(PolynomialError::InvalidFactorial { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reused_stack() {
+        let compiled = Polynomial::parse("x^2 + 2x + 1")
+            .unwrap()
+            .compile()
+            .unwrap();
+        let mut stack = Vec::new();
+        for i in 0..100 {
+            let x = i as f64;
+            let result = compiled.eval_with_stack(&[x], &mut stack).unwrap();
+            assert_eq!(result, (x + 1.0).powi(2));
+        }
+    }
+
+    #[test]
+    fn test_no_variables() {
+        let compiled = Polynomial::parse("e^2").unwrap().compile().unwrap();
+        assert!(compiled.variables().is_empty());
+        assert_eq!(compiled.eval(&[]).unwrap(), std::f64::consts::E.powf(2.0));
+    }
+
+    #[test]
+    fn test_unsupported_operators() {
+        use spindalis::polynomials::core::advanced::{Operators, Token};
+
+        let x = || Box::new(Expr::Variable("x".into()));
+        let prefix_fac = Expr::UnaryOpPrefix {
+            op: Operators::Fac,
+            value: x(),
+        };
+        let binary_fac = Expr::BinaryOp {
+            op: Operators::Fac,
+            lhs: x(),
+            rhs: x(),
+            paren: false,
+        };
+        for expr in [prefix_fac, binary_fac] {
+            assert!(matches!(
+                CompiledExpr::compile(&expr),
+                Err(PolynomialError::UnexpectedToken {
+                    token: Token::Operator(Operators::Fac)
+                })
+            ));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::complex::Complex;
+    use spindalis::polynomials::{
+        IntermediatePolynomial, Polynomial, PolynomialError, PolynomialTraits, SimplePolynomial,
+        eval_advanced_polynomial_complex,
+    };
+    use spindalis::solvers::polynomial_roots;
+
+    const ERROR_TOL: f64 = 1e-10;
+
+    fn approx_eq(a: Complex, b: Complex) -> bool {
+        (a - b).abs() < ERROR_TOL
+    }
+
+    #[test]
+    fn test_simple_at_i() {
+        let poly = SimplePolynomial::parse("x^2 + 1").unwrap();
+        assert!(approx_eq(
+            poly.eval_complex(Complex::I).unwrap(),
+            Complex::ZERO
+        ));
+        let poly = SimplePolynomial::parse("2x^3 - x + 4").unwrap();
+        // 2(1+i)^3 - (1+i) + 4 = 2(-2+2i) - 1 - i + 4
+        let result = poly.eval_complex(Complex::new(1.0, 1.0)).unwrap();
+        assert!(approx_eq(result, Complex::new(-1.0, 3.0)));
+    }
+
+    #[test]
+    fn test_real_point_matches_real_eval() {
+        let exprs = ["3x^4 - 2x + 1", "x^3 - 6x^2 + 11x - 6"];
+        for expr in exprs {
+            let simple = SimplePolynomial::parse(expr).unwrap();
+            let intermediate = IntermediatePolynomial::parse(expr).unwrap();
+            let advanced = Polynomial::parse(expr).unwrap();
+            for x in [-1.5, 0.0, 2.25] {
+                let expected = Complex::from(simple.eval_univariate(x).unwrap());
+                assert!(approx_eq(simple.eval_complex(x).unwrap(), expected));
+                assert!(approx_eq(intermediate.eval_complex(x).unwrap(), expected));
+                assert!(approx_eq(advanced.eval_complex(x).unwrap(), expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_intermediate_fractional_power() {
+        let poly = IntermediatePolynomial::parse("x^1/2").unwrap();
+        let result = poly.eval_complex(-4.0).unwrap();
+        assert!(approx_eq(result, Complex::new(0.0, 2.0)));
+    }
+
+    #[test]
+    fn test_advanced_euler_identity() {
+        let poly = Polynomial::parse("e^x + 1").unwrap();
+        let result = poly
+            .eval_complex(Complex::new(0.0, std::f64::consts::PI))
+            .unwrap();
+        assert!(approx_eq(result, Complex::ZERO));
+    }
+
+    #[test]
+    fn test_advanced_functions() {
+        let poly = Polynomial::parse("sin(x)^2 + cos(x)^2").unwrap();
+        let z = Complex::new(0.4, -1.2);
+        let expected = z.powi(2).sin() + z.powi(2).cos();
+        assert!(approx_eq(poly.eval_complex(z).unwrap(), expected));
+
+        let poly = Polynomial::parse("ln(x)").unwrap();
+        let result = poly.eval_complex(-1.0).unwrap();
+        assert!(approx_eq(result, Complex::new(0.0, std::f64::consts::PI)));
+    }
+
+    #[test]
+    fn test_transfer_function_on_unit_circle() {
+        // H(z) = 1 / (1 - 0.5z^-1) evaluated at z = e^(iw)
+        let poly = Polynomial::parse("1 / (1 - 0.5 / z)").unwrap();
+        for k in 0..8 {
+            let w = k as f64 * std::f64::consts::PI / 4.0;
+            let z = Complex::from_polar(1.0, w);
+            let expected = (Complex::ONE - 0.5 * z.recip()).recip();
+            assert!(approx_eq(poly.eval_complex(z).unwrap(), expected));
+        }
+    }
+
+    #[test]
+    fn test_multivariate_complex() {
+        let poly = Polynomial::parse("x * y").unwrap();
+        let result =
+            eval_advanced_polynomial_complex(poly.expr(), &[("x", Complex::I), ("y", Complex::I)])
+                .unwrap();
+        assert!(approx_eq(result, Complex::from(-1.0)));
+    }
+
+    #[test]
+    fn test_factorial_needs_real_argument() {
+        let poly = Polynomial::parse("x!").unwrap();
+        assert!(approx_eq(
+            poly.eval_complex(4.0).unwrap(),
+            Complex::from(24.0)
+        ));
+        assert!(matches!(
+            poly.eval_complex(Complex::I),
+            Err(PolynomialError::UnsupportedComplex { .. })
+        ));
+    }
+
+    #[test]
+    fn test_complex_roots_check() {
+        let poly = SimplePolynomial::parse("x^4 + 2x^2 + 5x + 3").unwrap();
+        for root in polynomial_roots(&poly, 1e-12, 500).unwrap() {
+            let value = poly.eval_complex(root.value).unwrap();
+            assert!(value.abs() < 1e-8);
+        }
+    }
+}
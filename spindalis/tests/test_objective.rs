@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::complex::Complex;
+    use spindalis::integrals::{definite_integral, romberg_definite};
+    use spindalis::objective::{Objective, PolyObjective};
+    use spindalis::polynomials::{PolynomialError, PolynomialTraits, SimplePolynomial};
+    use spindalis::solvers::{
+        Bounds, SolveMode, bisection, brent, newton_raphson_method, secant_method,
+    };
+
+    const ERROR_TOL: f64 = 1e-6;
+
+    // Stands in for a model that is only available as Rust code
+    struct Decay {
+        rate: f64,
+    }
+
+    impl Objective for Decay {
+        fn eval(&self, x: f64) -> Result<f64, PolynomialError> {
+            Ok((-self.rate * x).exp() - 0.5)
+        }
+    }
+
+    // A polynomial type from outside the crate, `coeff * x^power + constant`
+    #[derive(Debug, Clone)]
+    struct Binomial {
+        coeff: f64,
+        power: i32,
+        constant: f64,
+    }
+
+    impl PolynomialTraits for Binomial {
+        fn parse(_input: &str) -> Result<Self, PolynomialError> {
+            unimplemented!()
+        }
+
+        fn eval_univariate<F>(&self, point: F) -> Result<f64, PolynomialError>
+        where
+            F: Into<f64> + Clone + std::fmt::Debug,
+        {
+            Ok(self.coeff * point.into().powi(self.power) + self.constant)
+        }
+
+        fn eval_multivariate<V, S, F>(&self, _vars: &V) -> Result<f64, PolynomialError>
+        where
+            V: IntoIterator<Item = (S, F)> + std::fmt::Debug + Clone,
+            S: AsRef<str>,
+            F: Into<f64>,
+        {
+            unimplemented!()
+        }
+
+        fn eval_complex<C>(&self, _point: C) -> Result<Complex, PolynomialError>
+        where
+            C: Into<Complex>,
+        {
+            unimplemented!()
+        }
+
+        fn derivate_univariate(&self) -> Result<Self, PolynomialError> {
+            Ok(Binomial {
+                coeff: self.coeff * self.power as f64,
+                power: self.power - 1,
+                constant: 0.0,
+            })
+        }
+
+        fn derivate_multivariate<S>(&self, _var: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            unimplemented!()
+        }
+
+        fn indefinite_integral_univariate(&self) -> Result<Self, PolynomialError> {
+            unimplemented!()
+        }
+
+        fn indefinite_integral_multivariate<S>(&self, _var: S) -> Self
+        where
+            S: AsRef<str>,
+        {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn test_bisection_closure() {
+        let f = |x: f64| x.cos() - x;
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 0.2,
+            upper: 1.0,
+        };
+        let root = bisection(&f, bounds, 1e-8, 200, SolveMode::Root).unwrap();
+        assert!((root - 0.7390851332).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_newton_numerical_derivative() {
+        let f = |x: f64| x * x * x - 2.0 * x - 5.0;
+        let root = newton_raphson_method(&f, 2.0, 100, 1e-8, SolveMode::Root).unwrap();
+        assert!((root - 2.0945514815).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_newton_extrema_closure() {
+        // x e^(-x) has its maximum at x = 1
+        let f = |x: f64| x * (-x).exp();
+        let x = newton_raphson_method(&f, 0.5, 100, 1e-6, SolveMode::Extrema).unwrap();
+        assert!((x - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_brent_extrema_closure() {
+        let f = |x: f64| (x - 1.5).powi(2) + x.sin();
+        let bounds = Bounds {
+            lower: 0.0,
+            init: 1.0,
+            upper: 3.0,
+        };
+        let x = brent(&f, bounds, 1e-8, 100, SolveMode::Extrema).unwrap();
+        // f'(x) = 2(x - 1.5) + cos(x) = 0
+        assert!((2.0 * (x - 1.5) + x.cos()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_custom_objective() {
+        let model = Decay { rate: 0.25 };
+        let root = secant_method(&model, 1.0, 2.0, 100, 1e-8, SolveMode::Root).unwrap();
+        assert!((root - 4.0 * 2.0_f64.ln()).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_function_pointer_integral() {
+        let result = definite_integral(&f64::sin, 0.0, std::f64::consts::PI, 100).unwrap();
+        assert!((result - 2.0).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_romberg_closure() {
+        let f = |x: f64| (-x * x).exp();
+        let result = romberg_definite(&f, 0.0, 1.0, 8, 1e-8).unwrap();
+        assert!((result - 0.7468241328).abs() < ERROR_TOL);
+    }
+
+    #[test]
+    fn test_polynomial_and_closure_agree() {
+        let parsed = SimplePolynomial::parse("x^3 - 6x^2 + 11x - 6").unwrap();
+        let f = |x: f64| x.powi(3) - 6.0 * x.powi(2) + 11.0 * x - 6.0;
+        let bounds = || Bounds {
+            lower: 1.5,
+            init: 2.0,
+            upper: 2.5,
+        };
+        let from_poly = brent(&parsed, bounds(), 1e-10, 100, SolveMode::Root).unwrap();
+        let from_closure = brent(&f, bounds(), 1e-10, 100, SolveMode::Root).unwrap();
+        assert!((from_poly - from_closure).abs() < 1e-10);
+        assert!((from_poly - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_poly_objective_custom_polynomial() {
+        // 2x^3 - 16 = 0 at x = 2, found with the analytical derivative 6x^2
+        let cubic = PolyObjective(Binomial {
+            coeff: 2.0,
+            power: 3,
+            constant: -16.0,
+        });
+        let dx = cubic.derivative().unwrap().unwrap();
+        assert_eq!(dx.eval(2.0).unwrap(), 24.0);
+        let root = newton_raphson_method(&cubic, 3.0, 100, 1e-10, SolveMode::Root).unwrap();
+        assert!((root - 2.0).abs() < ERROR_TOL);
+    }
+}
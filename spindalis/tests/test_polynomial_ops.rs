@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::polynomials::{IntermediatePolynomial, PolynomialTraits, SimplePolynomial};
+    use spindalis::polynomials::{Term, parse_intermediate_polynomial, parse_simple_polynomial};
+
+    fn term(coefficient: f64, variables: &[(&str, f64)]) -> Term {
+        Term {
+            coefficient,
+            variables: variables.iter().map(|(v, p)| (v.to_string(), *p)).collect(),
+        }
+    }
+
+    fn assert_terms(poly: &IntermediatePolynomial, expected: &[Term]) {
+        assert_eq!(poly.len(), expected.len(), "{poly}");
+        for t in expected {
+            assert!(poly.contains(t), "{t} missing from {poly}");
+        }
+    }
+
+    #[test]
+    fn test_simple_add_sub() {
+        let p = parse_simple_polynomial("3x^2 + 2x + 1").unwrap();
+        let q = parse_simple_polynomial("x^3 - 2x + 4").unwrap();
+        assert_eq!(&p + &q, vec![5.0, 0.0, 3.0, 1.0]);
+        assert_eq!(&p - &q, vec![-3.0, 4.0, 3.0, -1.0]);
+        assert_eq!((&p + &q).variable, Some('x'));
+    }
+
+    #[test]
+    fn test_simple_sub_trims_leading_zeros() {
+        let p = parse_simple_polynomial("x^2 + x").unwrap();
+        let q = parse_simple_polynomial("x^2 - 1").unwrap();
+        assert_eq!(&p - &q, vec![1.0, 1.0]);
+        assert_eq!(&p - &p, vec![0.0]);
+        assert_eq!(format!("{}", &p - &p), "0");
+    }
+
+    #[test]
+    fn test_simple_mul() {
+        let p = parse_simple_polynomial("x + 1").unwrap();
+        let q = parse_simple_polynomial("x - 1").unwrap();
+        let product = &p * &q;
+        assert_eq!(product, vec![-1.0, 0.0, 1.0]);
+        assert_eq!(format!("{product}"), "x^2 - 1");
+    }
+
+    #[test]
+    fn test_simple_mul_evaluates_to_product() {
+        let p = parse_simple_polynomial("3x^3 - x + 2").unwrap();
+        let q = parse_simple_polynomial("2x^2 + 5").unwrap();
+        let product = p.clone() * q.clone();
+        for x in [-1.5, 0.0, 2.0] {
+            let expected = p.eval_univariate(x).unwrap() * q.eval_univariate(x).unwrap();
+            assert!((product.eval_univariate(x).unwrap() - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_simple_neg_and_scalar() {
+        let p = parse_simple_polynomial("2x^2 - 4").unwrap();
+        assert_eq!(-&p, vec![4.0, 0.0, -2.0]);
+        assert_eq!(&p * 0.5, vec![-2.0, 0.0, 1.0]);
+        assert_eq!(&p / 2.0, vec![-2.0, 0.0, 1.0]);
+        assert_eq!(&p + 4.0, vec![0.0, 0.0, 2.0]);
+        assert_eq!(&p - 1.0, vec![-5.0, 0.0, 2.0]);
+        assert_eq!(&p * 0.0, vec![0.0]);
+    }
+
+    #[test]
+    fn test_simple_assign_ops() {
+        let mut p = parse_simple_polynomial("x + 1").unwrap();
+        let q = parse_simple_polynomial("x - 1").unwrap();
+        p *= &q;
+        assert_eq!(p, vec![-1.0, 0.0, 1.0]);
+        p += q.clone();
+        assert_eq!(p, vec![-2.0, 1.0, 1.0]);
+        p -= &q;
+        assert_eq!(p, vec![-1.0, 0.0, 1.0]);
+        p *= 3.0;
+        p /= 3.0;
+        p += 1.0;
+        assert_eq!(p, vec![0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_simple_constant_keeps_variable() {
+        let constant = SimplePolynomial::parse("3").unwrap();
+        let p = SimplePolynomial::parse("2t").unwrap();
+        let sum = &constant + &p;
+        assert_eq!(sum.variable, Some('t'));
+        assert_eq!(format!("{sum}"), "2t + 3");
+    }
+
+    #[test]
+    #[should_panic(expected = "different variables")]
+    fn test_simple_different_variables_panics() {
+        let x = SimplePolynomial::parse("x + 1").unwrap();
+        let y = SimplePolynomial::parse("y").unwrap();
+        let _ = &x + &y;
+    }
+
+    #[test]
+    #[should_panic(expected = "different variables")]
+    fn test_simple_mul_different_variables_panics() {
+        let x = SimplePolynomial::parse("x").unwrap();
+        let y = SimplePolynomial::parse("2y").unwrap();
+        let _ = x * y;
+    }
+
+    #[test]
+    fn test_intermediate_add_merges_like_terms() {
+        let p = parse_intermediate_polynomial("3x^2 + 2xy - 1").unwrap();
+        let q = parse_intermediate_polynomial("x^2 - 2yx + 4z").unwrap();
+        let sum = &p + &q;
+        assert_terms(
+            &sum,
+            &[
+                term(4.0, &[("x", 2.0)]),
+                term(-1.0, &[]),
+                term(4.0, &[("z", 1.0)]),
+            ],
+        );
+        assert_eq!(sum.variables, vec!["x", "y", "z"]);
+    }
+
+    #[test]
+    fn test_intermediate_sub() {
+        let p = parse_intermediate_polynomial("x^2 + y").unwrap();
+        let difference = &p - &p;
+        assert!(difference.is_empty());
+        assert_eq!(format!("{difference}"), "0");
+        assert_eq!(difference.variables, vec!["x", "y"]);
+    }
+
+    #[test]
+    fn test_intermediate_mul() {
+        let p = parse_intermediate_polynomial("x + y").unwrap();
+        let q = parse_intermediate_polynomial("x - y").unwrap();
+        let product = &p * &q;
+        assert_terms(
+            &product,
+            &[term(1.0, &[("x", 2.0)]), term(-1.0, &[("y", 2.0)])],
+        );
+    }
+
+    #[test]
+    fn test_intermediate_mul_adds_exponents() {
+        let p = parse_intermediate_polynomial("2x^2y").unwrap();
+        let q = parse_intermediate_polynomial("3x^-2z^1/2").unwrap();
+        let product = p * q;
+        assert_terms(&product, &[term(6.0, &[("y", 1.0), ("z", 0.5)])]);
+        assert_eq!(product.variables, vec!["x", "y", "z"]);
+    }
+
+    #[test]
+    fn test_intermediate_mul_evaluates_to_product() {
+        let p = parse_intermediate_polynomial("3x^2y - x + 2").unwrap();
+        let q = parse_intermediate_polynomial("2y^2 + x - 5").unwrap();
+        let product = &p * &q;
+        let vars = [("x", 1.5), ("y", -0.5)];
+        let expected = p.eval_multivariate(&vars).unwrap() * q.eval_multivariate(&vars).unwrap();
+        assert!((product.eval_multivariate(&vars).unwrap() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_intermediate_neg_and_scalar() {
+        let p = parse_intermediate_polynomial("2x - 4").unwrap();
+        assert_terms(&-&p, &[term(-2.0, &[("x", 1.0)]), term(4.0, &[])]);
+        assert_terms(&(&p / 2.0), &[term(1.0, &[("x", 1.0)]), term(-2.0, &[])]);
+        assert_terms(&(&p + 4.0), &[term(2.0, &[("x", 1.0)])]);
+        assert!((&p * 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_intermediate_assign_ops() {
+        let mut p = parse_intermediate_polynomial("x").unwrap();
+        p *= parse_intermediate_polynomial("y").unwrap();
+        p += 1.0;
+        p -= &parse_intermediate_polynomial("xy").unwrap();
+        p *= 5.0;
+        assert_terms(&p, &[term(5.0, &[])]);
+        assert_eq!(p.variables, vec!["x", "y"]);
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::polynomials::{PolynomialError, SimplePolynomial, parse_simple_polynomial};
+
+    const ERROR_TOL: f64 = 1e-10;
+
+    fn approx_eq(poly: &SimplePolynomial, expected: &[f64]) -> bool {
+        poly.len() == expected.len()
+            && poly
+                .iter()
+                .zip(expected)
+                .all(|(a, b)| (a - b).abs() < ERROR_TOL)
+    }
+
+    #[test]
+    fn test_degree() {
+        assert_eq!(
+            parse_simple_polynomial("4x^3 + 1").unwrap().degree(),
+            Some(3)
+        );
+        assert_eq!(parse_simple_polynomial("7").unwrap().degree(), Some(0));
+        assert_eq!(parse_simple_polynomial("0").unwrap().degree(), None);
+    }
+
+    #[test]
+    fn test_div_rem_exact() {
+        let p = parse_simple_polynomial("x^3 - 6x^2 + 11x - 6").unwrap();
+        let d = parse_simple_polynomial("x - 1").unwrap();
+        let (q, r) = p.div_rem(&d).unwrap();
+        assert_eq!(q, vec![6.0, -5.0, 1.0]);
+        assert_eq!(r, vec![0.0]);
+    }
+
+    #[test]
+    fn test_div_rem_with_remainder() {
+        let p = parse_simple_polynomial("2x^4 + 3x^2 - x + 5").unwrap();
+        let d = parse_simple_polynomial("x^2 + 1").unwrap();
+        let (q, r) = p.div_rem(&d).unwrap();
+        assert_eq!(q, vec![1.0, 0.0, 2.0]);
+        assert_eq!(r, vec![4.0, -1.0]);
+        assert_eq!(&(&q * &d) + &r, p);
+    }
+
+    #[test]
+    fn test_div_rem_lower_degree_dividend() {
+        let p = parse_simple_polynomial("3x + 2").unwrap();
+        let d = parse_simple_polynomial("x^2").unwrap();
+        let (q, r) = p.div_rem(&d).unwrap();
+        assert_eq!(q, vec![0.0]);
+        assert_eq!(r, p);
+    }
+
+    #[test]
+    fn test_div_rem_by_zero() {
+        let p = parse_simple_polynomial("x + 1").unwrap();
+        let zero = parse_simple_polynomial("0").unwrap();
+        assert!(matches!(
+            p.div_rem(&zero),
+            Err(PolynomialError::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn test_deflate() {
+        let p = parse_simple_polynomial("x^3 - 6x^2 + 11x - 6").unwrap();
+        let (q, value) = p.deflate(2.0);
+        assert_eq!(q, vec![3.0, -4.0, 1.0]);
+        assert_eq!(value, 0.0);
+
+        let (_, value) = p.deflate(4.0);
+        assert_eq!(value, 6.0);
+    }
+
+    #[test]
+    fn test_gcd() {
+        // (x - 1)(x - 2) and (x - 1)(x + 3)
+        let p = parse_simple_polynomial("x^2 - 3x + 2").unwrap();
+        let q = parse_simple_polynomial("x^2 + 2x - 3").unwrap();
+        assert!(approx_eq(&p.gcd(&q), &[-1.0, 1.0]));
+        assert!(approx_eq(&q.gcd(&p), &[-1.0, 1.0]));
+    }
+
+    #[test]
+    fn test_gcd_repeated_root() {
+        // (x - 1)^2 (x + 2) and its derivative share (x - 1)
+        let p = parse_simple_polynomial("x^3 - 3x + 2").unwrap();
+        let dp = parse_simple_polynomial("3x^2 - 3").unwrap();
+        assert!(approx_eq(&p.gcd(&dp), &[-1.0, 1.0]));
+    }
+
+    #[test]
+    fn test_gcd_coprime_and_zero() {
+        let p = parse_simple_polynomial("x^2 + 1").unwrap();
+        let q = parse_simple_polynomial("x - 5").unwrap();
+        assert!(approx_eq(&p.gcd(&q), &[1.0]));
+
+        let zero = parse_simple_polynomial("0").unwrap();
+        assert!(approx_eq(&p.gcd(&zero), &[1.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_monic() {
+        let p = parse_simple_polynomial("4x^2 - 2").unwrap();
+        assert_eq!(p.monic(), vec![-0.5, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_compose() {
+        let p = parse_simple_polynomial("x^2 + 1").unwrap();
+        let q = parse_simple_polynomial("2x - 3").unwrap();
+        // (2x - 3)^2 + 1
+        assert_eq!(p.compose(&q), vec![10.0, -12.0, 4.0]);
+        // 2(x^2 + 1) - 3
+        assert_eq!(q.compose(&p), vec![-1.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_compose_change_of_variable() {
+        let p = parse_simple_polynomial("x^3 - 2x").unwrap();
+        let shift = parse_simple_polynomial("t + 1").unwrap();
+        let shifted = p.compose(&shift);
+        assert_eq!(shifted.variable, Some('t'));
+        for t in [-2.0, 0.5, 3.0] {
+            let expected = (t + 1.0_f64).powi(3) - 2.0 * (t + 1.0);
+            let result: f64 = shifted
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c * t.powi(i as i32))
+                .sum();
+            assert!((result - expected).abs() < ERROR_TOL);
+        }
+    }
+
+    #[test]
+    fn test_sturm_sequence() {
+        let p = parse_simple_polynomial("x^3 - 3x + 1").unwrap();
+        let sequence = p.sturm_sequence();
+        assert_eq!(sequence.len(), 4);
+        assert_eq!(sequence[1], vec![-3.0, 0.0, 3.0]);
+        assert_eq!(sequence[3].degree(), Some(0));
+    }
+
+    #[test]
+    fn test_count_real_roots() {
+        // Three real roots, near -1.879, 0.347 and 1.532
+        let p = parse_simple_polynomial("x^3 - 3x + 1").unwrap();
+        assert_eq!(p.count_real_roots(-10.0, 10.0), 3);
+        assert_eq!(p.count_real_roots(0.0, 1.0), 1);
+        assert_eq!(p.count_real_roots(0.5, 1.5), 0);
+        let q = parse_simple_polynomial("x^4 + 1").unwrap();
+        assert_eq!(q.count_real_roots(-10.0, 10.0), 0);
+    }
+
+    #[test]
+    fn test_count_real_roots_with_sequence() {
+        let p = parse_simple_polynomial("x^3 - 3x + 1").unwrap();
+        let sequence = p.sturm_sequence();
+        for (a, b) in [(-10.0, 10.0), (0.0, 1.0), (0.5, 1.5), (-2.0, 0.0)] {
+            assert_eq!(
+                SimplePolynomial::count_real_roots_with(&sequence, a, b),
+                p.count_real_roots(a, b)
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_real_roots_distinct() {
+        // (x - 1)^2 (x + 1) has two distinct roots, and the interval is open below
+        let p = parse_simple_polynomial("x^3 - x^2 - x + 1").unwrap();
+        assert_eq!(p.count_real_roots(-2.0, 2.0), 2);
+        assert_eq!(p.count_real_roots(-1.0, 2.0), 1);
+        assert_eq!(p.count_real_roots(-2.0, 1.0), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "different variables")]
+    fn test_div_rem_different_variables_panics() {
+        let x = parse_simple_polynomial("x^2 - 1").unwrap();
+        let y = parse_simple_polynomial("y - 1").unwrap();
+        let _ = x.div_rem(&y);
+    }
+
+    #[test]
+    #[should_panic(expected = "different variables")]
+    fn test_gcd_different_variables_panics() {
+        let x = parse_simple_polynomial("x^2 - 1").unwrap();
+        let y = parse_simple_polynomial("y - 1").unwrap();
+        let _ = x.gcd(&y);
+    }
+
+    #[test]
+    fn test_gcd_constant_takes_variable() {
+        let t = parse_simple_polynomial("t^2 - 1").unwrap();
+        let constant = parse_simple_polynomial("2").unwrap();
+        let (quotient, _) = t.div_rem(&constant).unwrap();
+        assert_eq!(quotient.variable, Some('t'));
+        assert_eq!(t.gcd(&constant).variable, Some('t'));
+    }
+}
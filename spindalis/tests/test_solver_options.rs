@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use spindalis::convergence::{SolverOptions, Termination};
+    use spindalis::eigen::power_method_with_options;
+    use spindalis::integrals::romberg_definite_with_options;
+    use spindalis::polynomials::{PolynomialTraits, SimplePolynomial};
+    use spindalis::solvers::{
+        Bounds, SolveMode, bisection_with_options, newton_raphson_with_options,
+    };
+    use spindalis::utils::Arr2D;
+    use std::ops::ControlFlow;
+
+    fn bounds() -> Bounds {
+        Bounds {
+            lower: 0.0,
+            init: 1.0,
+            upper: 3.0,
+        }
+    }
+
+    #[test]
+    fn test_bisection_report() {
+        let parsed = SimplePolynomial::parse("x^2 - 2").unwrap();
+        let mut options = SolverOptions {
+            rel_tol: 1e-6,
+            ..Default::default()
+        };
+        let report =
+            bisection_with_options(&parsed, bounds(), SolveMode::Root, &mut options).unwrap();
+        assert!(report.converged());
+        assert_eq!(report.termination, Termination::RelativeTolerance);
+        assert_eq!(report.history.len(), report.iterations);
+        assert!((report.solution - 2.0_f64.sqrt()).abs() < 1e-6);
+        assert!(report.residual.abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_bisection_absolute_tolerance() {
+        let parsed = SimplePolynomial::parse("x^2 - 2").unwrap();
+        let mut options = SolverOptions {
+            abs_tol: 1e-6,
+            rel_tol: 0.0,
+            ..Default::default()
+        };
+        let report =
+            bisection_with_options(&parsed, bounds(), SolveMode::Root, &mut options).unwrap();
+        assert_eq!(report.termination, Termination::AbsoluteTolerance);
+        // Steps after the first are 3 / 2^k, which first drops below 1e-6 at k = 22
+        assert_eq!(report.iterations, 22);
+    }
+
+    #[test]
+    fn test_bisection_no_convergence_report() {
+        let parsed = SimplePolynomial::parse("x^2 + 10").unwrap();
+        let mut options = SolverOptions::default();
+        let report =
+            bisection_with_options(&parsed, bounds(), SolveMode::Root, &mut options).unwrap();
+        assert_eq!(report.termination, Termination::NoConvergence);
+        assert!(!report.converged());
+        assert!(report.residual >= 10.0);
+    }
+
+    #[test]
+    fn test_newton_function_tolerance() {
+        let parsed = SimplePolynomial::parse("x^3 - 2x - 5").unwrap();
+        let mut options = SolverOptions {
+            rel_tol: 0.0,
+            fn_tol: 1e-10,
+            ..Default::default()
+        };
+        let report =
+            newton_raphson_with_options(&parsed, 2.0, SolveMode::Root, &mut options).unwrap();
+        assert_eq!(report.termination, Termination::FunctionTolerance);
+        assert!(report.residual.abs() <= 1e-10);
+        // Errors shrink quadratically
+        assert!(report.history.windows(2).all(|w| w[1] < w[0]));
+    }
+
+    #[test]
+    fn test_newton_max_iterations() {
+        let parsed = SimplePolynomial::parse("x^2 + 4").unwrap();
+        let mut options = SolverOptions {
+            itermax: 25,
+            ..Default::default()
+        };
+        let report =
+            newton_raphson_with_options(&parsed, 2.0, SolveMode::Root, &mut options).unwrap();
+        assert_eq!(report.termination, Termination::MaxIterationsReached);
+        assert_eq!(report.iterations, 25);
+        assert_eq!(report.history.len(), 25);
+    }
+
+    #[test]
+    fn test_callback_traces_and_cancels() {
+        let parsed = SimplePolynomial::parse("x^2 - 2").unwrap();
+        let mut trace = Vec::new();
+        let mut options = SolverOptions {
+            rel_tol: 1e-12,
+            ..Default::default()
+        }
+        .with_callback(|iteration| {
+            trace.push(iteration.x);
+            if iteration.iteration == 5 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        let report =
+            bisection_with_options(&parsed, bounds(), SolveMode::Root, &mut options).unwrap();
+        drop(options);
+        assert_eq!(report.termination, Termination::Cancelled);
+        assert_eq!(report.iterations, 5);
+        assert_eq!(trace, vec![1.5, 0.75, 1.125, 1.3125, 1.40625]);
+    }
+
+    #[test]
+    fn test_romberg_report() {
+        let f = |x: f64| (-x * x).exp();
+        let mut options = SolverOptions {
+            rel_tol: 1e-8,
+            ..Default::default()
+        };
+        let report = romberg_definite_with_options(&f, 0.0, 1.0, &mut options).unwrap();
+        assert!(report.converged());
+        assert!((report.solution - 0.7468241328).abs() < 1e-9);
+        assert!(report.residual < 1e-8);
+    }
+
+    #[test]
+    fn test_romberg_beyond_fixed_table() {
+        // Needs more iterations than the old fixed-size Romberg table allowed
+        let f = |x: f64| x.abs().sqrt();
+        let mut options = SolverOptions {
+            rel_tol: 1e-10,
+            itermax: 30,
+            ..Default::default()
+        };
+        let report = romberg_definite_with_options(&f, 0.0, 1.0, &mut options).unwrap();
+        assert!(report.iterations > 9);
+        assert!((report.solution - 2.0 / 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_power_method_report() {
+        let matrix = Arr2D::from(&[[2, 8, 10], [8, 4, 5], [10, 5, 7]]);
+        let mut options = SolverOptions {
+            rel_tol: 1e-10,
+            ..Default::default()
+        };
+        let report = power_method_with_options(&matrix, None, &mut options).unwrap();
+        let (eigenvalue, _) = report.solution;
+        assert!(report.converged());
+        assert!((eigenvalue - 19.88).abs() < 0.01);
+        assert!(report.residual < 1e-4);
+    }
+}
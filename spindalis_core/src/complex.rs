@@ -0,0 +1,346 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A complex number `re + im·i`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+    pub const ONE: Complex = Complex { re: 1.0, im: 0.0 };
+    pub const I: Complex = Complex { re: 0.0, im: 1.0 };
+
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// Build from a modulus and an argument in radians
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    pub fn conj(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Modulus |z|
+    pub fn abs(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Squared modulus, avoiding the square root
+    pub fn norm_sqr(&self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Argument in radians, in (-π, π]
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// 1/z, where the reciprocal of zero is a real infinity as it is for `f64`
+    pub fn recip(&self) -> Self {
+        if *self == Self::ZERO {
+            return Self::new(f64::INFINITY, 0.0);
+        }
+        let denom = self.norm_sqr();
+        Self::new(self.re / denom, -self.im / denom)
+    }
+
+    /// Principal square root
+    pub fn sqrt(&self) -> Self {
+        Self::from_polar(self.abs().sqrt(), self.arg() / 2.0)
+    }
+
+    pub fn exp(&self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Principal natural logarithm
+    pub fn ln(&self) -> Self {
+        Self::new(self.abs().ln(), self.arg())
+    }
+
+    pub fn log10(&self) -> Self {
+        self.ln() / std::f64::consts::LN_10
+    }
+
+    pub fn sin(&self) -> Self {
+        Self::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+
+    pub fn cos(&self) -> Self {
+        Self::new(
+            self.re.cos() * self.im.cosh(),
+            -self.re.sin() * self.im.sinh(),
+        )
+    }
+
+    pub fn tan(&self) -> Self {
+        self.sin() / self.cos()
+    }
+
+    /// Integer power by repeated squaring
+    pub fn powi(&self, n: i32) -> Self {
+        // Squaring an infinite base would mix in NaN from 0·∞
+        if n < 0 && *self == Self::ZERO {
+            return self.recip();
+        }
+        let mut base = if n < 0 { self.recip() } else { *self };
+        let mut exp = n.unsigned_abs();
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Principal value of z^p
+    pub fn powf(&self, p: f64) -> Self {
+        if p.fract() == 0.0 && p.abs() <= i32::MAX as f64 {
+            return self.powi(p as i32);
+        }
+        if self.im == 0.0 && self.re >= 0.0 {
+            return Self::from(self.re.powf(p));
+        }
+        Self::from_polar(self.abs().powf(p), self.arg() * p)
+    }
+
+    /// Principal value of z^w
+    pub fn powc(&self, w: Complex) -> Self {
+        if w.im == 0.0 {
+            return self.powf(w.re);
+        }
+        // |0^w| = 0^Re(w), and the argument of the result is undefined
+        if *self == Self::ZERO {
+            return match w.re {
+                re if re > 0.0 => Self::ZERO,
+                re if re < 0.0 => Self::new(f64::INFINITY, 0.0),
+                _ => Self::new(f64::NAN, f64::NAN),
+            };
+        }
+        (w * self.ln()).exp()
+    }
+
+    pub fn is_real(&self) -> bool {
+        self.im == 0.0
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Self::new(re, 0.0)
+    }
+}
+
+impl std::fmt::Display for Complex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.im < 0.0 { '-' } else { '+' };
+        match f.precision() {
+            Some(p) => write!(f, "{:.*} {sign} {:.*}i", p, self.re, p, self.im.abs()),
+            None => write!(f, "{} {sign} {}i", self.re, self.im.abs()),
+        }
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+    fn div(self, rhs: Complex) -> Complex {
+        let denom = rhs.norm_sqr();
+        Complex::new(
+            (self.re * rhs.re + self.im * rhs.im) / denom,
+            (self.im * rhs.re - self.re * rhs.im) / denom,
+        )
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+// Mixed real/complex arithmetic and the `*Assign` operators all go through
+// the complex implementations above
+macro_rules! real_ops {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident;)*) => {
+        $(
+            impl $trait<f64> for Complex {
+                type Output = Complex;
+                fn $method(self, rhs: f64) -> Complex {
+                    self.$method(Complex::from(rhs))
+                }
+            }
+            impl $trait<Complex> for f64 {
+                type Output = Complex;
+                fn $method(self, rhs: Complex) -> Complex {
+                    Complex::from(self).$method(rhs)
+                }
+            }
+            impl $assign_trait for Complex {
+                fn $assign_method(&mut self, rhs: Complex) {
+                    *self = (*self).$method(rhs);
+                }
+            }
+            impl $assign_trait<f64> for Complex {
+                fn $assign_method(&mut self, rhs: f64) {
+                    *self = (*self).$method(rhs);
+                }
+            }
+        )*
+    };
+}
+
+real_ops! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_TOL: f64 = 1e-12;
+
+    fn approx_eq(a: Complex, b: Complex) -> bool {
+        (a - b).abs() < ERROR_TOL
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert!(approx_eq(a / b * b, a));
+        assert_eq!(-a, Complex::new(-1.0, -2.0));
+    }
+
+    #[test]
+    fn test_mixed_real() {
+        let a = Complex::new(1.0, 2.0);
+        assert_eq!(a * 2.0, Complex::new(2.0, 4.0));
+        assert_eq!(2.0 * a, Complex::new(2.0, 4.0));
+        assert_eq!(1.0 - a, Complex::new(0.0, -2.0));
+        let mut b = a;
+        b += 1.0;
+        b *= Complex::I;
+        assert_eq!(b, Complex::new(-2.0, 2.0));
+    }
+
+    #[test]
+    fn test_i_squared() {
+        assert_eq!(Complex::I * Complex::I, Complex::from(-1.0));
+    }
+
+    #[test]
+    fn test_polar_and_sqrt() {
+        let z = Complex::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+        assert!(approx_eq(z, Complex::new(0.0, 2.0)));
+        assert!((z.abs() - 2.0).abs() < ERROR_TOL);
+        assert!(approx_eq(
+            Complex::from(-4.0).sqrt(),
+            Complex::new(0.0, 2.0)
+        ));
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        // e^(iπ) = -1
+        let z = Complex::new(0.0, std::f64::consts::PI).exp();
+        assert!(approx_eq(z, Complex::from(-1.0)));
+        let w = Complex::new(1.5, -0.5);
+        assert!(approx_eq(w.ln().exp(), w));
+        assert!(approx_eq(Complex::from(100.0).log10(), Complex::from(2.0)));
+    }
+
+    #[test]
+    fn test_trig() {
+        let z = Complex::new(0.3, 0.7);
+        let identity = z.sin() * z.sin() + z.cos() * z.cos();
+        assert!(approx_eq(identity, Complex::ONE));
+        assert!(approx_eq(z.tan(), z.sin() / z.cos()));
+        assert!(approx_eq(
+            Complex::from(0.5).sin(),
+            Complex::from(0.5_f64.sin())
+        ));
+    }
+
+    #[test]
+    fn test_powers() {
+        let z = Complex::new(1.0, 1.0);
+        assert!(approx_eq(z.powi(4), Complex::from(-4.0)));
+        assert!(approx_eq(z.powi(-2), Complex::new(0.0, -0.5)));
+        assert!(approx_eq(z.powf(0.5) * z.powf(0.5), z));
+        assert!(approx_eq(
+            Complex::from(-8.0).powf(1.0 / 3.0).powi(3),
+            Complex::from(-8.0)
+        ));
+        // i^i = e^(-π/2)
+        let ii = Complex::I.powc(Complex::I);
+        assert!(approx_eq(
+            ii,
+            Complex::from((-std::f64::consts::FRAC_PI_2).exp())
+        ));
+    }
+
+    #[test]
+    fn test_zero_reciprocal_and_powers() {
+        let infinity = Complex::new(f64::INFINITY, 0.0);
+        assert_eq!(Complex::ZERO.recip(), infinity);
+        assert_eq!(Complex::ZERO.powi(-2), infinity);
+        assert_eq!(Complex::ZERO.powf(-0.5), infinity);
+
+        assert_eq!(Complex::ZERO.powc(Complex::new(2.0, 1.0)), Complex::ZERO);
+        assert_eq!(Complex::ZERO.powc(Complex::new(-1.0, 1.0)), infinity);
+        let undefined = Complex::ZERO.powc(Complex::I);
+        assert!(undefined.re.is_nan() && undefined.im.is_nan());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Complex::new(1.5, -2.0)), "1.5 - 2i");
+        assert_eq!(format!("{:.2}", Complex::new(1.0, 0.5)), "1.00 + 0.50i");
+    }
+}
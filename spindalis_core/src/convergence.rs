@@ -0,0 +1,185 @@
+use std::ops::ControlFlow;
+
+/// Per-iteration callback, returning `ControlFlow::Break(())` to stop the method early
+pub type IterationCallback<'a> = Box<dyn FnMut(&Iteration) -> ControlFlow<()> + 'a>;
+
+/// Stopping criteria shared by the iterative methods.
+///
+/// A method stops as soon as any enabled criterion is met. Setting a tolerance to
+/// `0.0` only accepts exact results for that criterion.
+pub struct SolverOptions<'a> {
+    /// Largest change between successive estimates that counts as converged
+    pub abs_tol: f64,
+    /// Approximate relative error, as a percentage like the `error_tol` arguments
+    pub rel_tol: f64,
+    /// Largest residual `|f(x)|` that counts as converged
+    pub fn_tol: f64,
+    pub itermax: usize,
+    /// Called after every iteration, for tracing progress or cancelling the method
+    pub callback: Option<IterationCallback<'a>>,
+}
+
+impl Default for SolverOptions<'_> {
+    fn default() -> Self {
+        Self {
+            abs_tol: 0.0,
+            rel_tol: 1e-5,
+            fn_tol: 0.0,
+            itermax: 1000,
+            callback: None,
+        }
+    }
+}
+
+impl<'a> SolverOptions<'a> {
+    pub fn with_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&Iteration) -> ControlFlow<()> + 'a,
+    {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Decides whether a method should stop after `iteration`.
+    /// Convergence is checked first, then the callback, then the iteration limit.
+    pub fn termination(&mut self, iteration: &Iteration) -> Option<Termination> {
+        if iteration.residual.abs() <= self.fn_tol {
+            return Some(Termination::FunctionTolerance);
+        }
+        if iteration.step.abs() <= self.abs_tol {
+            return Some(Termination::AbsoluteTolerance);
+        }
+        if iteration.error.abs() < self.rel_tol {
+            return Some(Termination::RelativeTolerance);
+        }
+        if let Some(callback) = self.callback.as_mut()
+            && callback(iteration).is_break()
+        {
+            return Some(Termination::Cancelled);
+        }
+        if iteration.iteration >= self.itermax {
+            return Some(Termination::MaxIterationsReached);
+        }
+        None
+    }
+}
+
+/// State of a method after one iteration, as passed to the callback
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iteration {
+    /// Number of iterations completed, starting from 1
+    pub iteration: usize,
+    /// Current estimate
+    pub x: f64,
+    /// Absolute change from the previous estimate
+    pub step: f64,
+    /// Approximate relative error as a percentage
+    pub error: f64,
+    pub residual: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    AbsoluteTolerance,
+    RelativeTolerance,
+    FunctionTolerance,
+    MaxIterationsReached,
+    Cancelled,
+    /// The method stopped but the result failed its final check
+    NoConvergence,
+}
+
+impl Termination {
+    pub fn is_converged(&self) -> bool {
+        matches!(
+            self,
+            Termination::AbsoluteTolerance
+                | Termination::RelativeTolerance
+                | Termination::FunctionTolerance
+        )
+    }
+}
+
+/// Outcome of an iterative method
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport<T> {
+    pub solution: T,
+    pub iterations: usize,
+    /// Residual of the final estimate, as defined by each method
+    pub residual: f64,
+    /// Approximate relative error after each iteration, or the relative residual
+    /// `‖b - Ax‖ / ‖b‖` for the iterative linear solvers
+    pub history: Vec<f64>,
+    pub termination: Termination,
+}
+
+impl<T> SolveReport<T> {
+    pub fn converged(&self) -> bool {
+        self.termination.is_converged()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iteration(step: f64, error: f64, residual: f64) -> Iteration {
+        Iteration {
+            iteration: 1,
+            x: 1.0,
+            step,
+            error,
+            residual,
+        }
+    }
+
+    #[test]
+    fn test_criteria() {
+        let mut options = SolverOptions {
+            abs_tol: 1e-6,
+            rel_tol: 1e-3,
+            fn_tol: 1e-8,
+            ..Default::default()
+        };
+        assert_eq!(options.termination(&iteration(1.0, 1.0, 1.0)), None);
+        assert_eq!(
+            options.termination(&iteration(1.0, 1.0, 1e-9)),
+            Some(Termination::FunctionTolerance)
+        );
+        assert_eq!(
+            options.termination(&iteration(1e-7, 1.0, 1.0)),
+            Some(Termination::AbsoluteTolerance)
+        );
+        assert_eq!(
+            options.termination(&iteration(1.0, 1e-4, 1.0)),
+            Some(Termination::RelativeTolerance)
+        );
+    }
+
+    #[test]
+    fn test_itermax() {
+        let mut options = SolverOptions {
+            itermax: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            options.termination(&iteration(1.0, 1.0, 1.0)),
+            Some(Termination::MaxIterationsReached)
+        );
+    }
+
+    #[test]
+    fn test_callback_cancels() {
+        let mut seen = Vec::new();
+        let mut options = SolverOptions::default().with_callback(|it| {
+            seen.push(it.x);
+            ControlFlow::Break(())
+        });
+        assert_eq!(
+            options.termination(&iteration(1.0, 1.0, 1.0)),
+            Some(Termination::Cancelled)
+        );
+        drop(options);
+        assert_eq!(seen, vec![1.0]);
+    }
+}
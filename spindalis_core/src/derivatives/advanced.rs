@@ -0,0 +1,158 @@
+use crate::polynomials::PolynomialError;
+use crate::polynomials::advanced::{Constants, Expr, Functions, Operators};
+use crate::polynomials::simplify::simplify_expr;
+
+pub fn partial_derivative_advanced<S>(expr: &Expr, var: S) -> Result<Expr, PolynomialError>
+where
+    S: AsRef<str>,
+{
+    let derived = differentiate(expr, var.as_ref())?;
+    Ok(simplify_expr(&derived))
+}
+
+fn differentiate(expr: &Expr, var: &str) -> Result<Expr, PolynomialError> {
+    // Anything that doesn't depend on var is a constant
+    if !expr.contains_variable(var) {
+        return Ok(Expr::Number(0.0));
+    }
+    match expr {
+        Expr::Variable(_) => Ok(Expr::Number(1.0)),
+        Expr::UnaryOpPrefix {
+            op: Operators::Sub,
+            value,
+        } => Ok(Expr::negate(differentiate(value, var)?)),
+        Expr::Function { func, inner } => differentiate_function(func, inner, var),
+        Expr::BinaryOp { op, lhs, rhs, .. } => match op {
+            Operators::Add | Operators::Sub => Ok(Expr::binary(
+                *op,
+                differentiate(lhs, var)?,
+                differentiate(rhs, var)?,
+            )),
+            Operators::Mul | Operators::CDot => {
+                // Product rule: (uv)' = u'v + uv'
+                let du = differentiate(lhs, var)?;
+                let dv = differentiate(rhs, var)?;
+                Ok(Expr::binary(
+                    Operators::Add,
+                    Expr::binary(Operators::Mul, du, *rhs.clone()),
+                    Expr::binary(Operators::Mul, *lhs.clone(), dv),
+                ))
+            }
+            Operators::Div => {
+                // Quotient rule: (u/v)' = (u'v - uv') / v^2
+                let du = differentiate(lhs, var)?;
+                let dv = differentiate(rhs, var)?;
+                let numerator = Expr::binary(
+                    Operators::Sub,
+                    Expr::binary(Operators::Mul, du, *rhs.clone()),
+                    Expr::binary(Operators::Mul, *lhs.clone(), dv),
+                );
+                let denominator = Expr::binary(Operators::Caret, *rhs.clone(), Expr::Number(2.0));
+                Ok(Expr::binary(Operators::Div, numerator, denominator))
+            }
+            Operators::Caret => differentiate_power(lhs, rhs, var),
+            // (u % c)' = u' wherever the remainder is continuous
+            Operators::Rem if !rhs.contains_variable(var) => differentiate(lhs, var),
+            _ => Err(PolynomialError::UnsupportedDerivative {
+                expr: expr.to_string(),
+            }),
+        },
+        _ => Err(PolynomialError::UnsupportedDerivative {
+            expr: expr.to_string(),
+        }),
+    }
+}
+
+// Chain rule: f(u)' = f'(u) * u'
+fn differentiate_function(
+    func: &Functions,
+    inner: &Expr,
+    var: &str,
+) -> Result<Expr, PolynomialError> {
+    let d_inner = differentiate(inner, var)?;
+    let u = inner.clone();
+    let outer = match func {
+        // sin(u)' = cos(u)
+        Functions::Sin => Expr::func(Functions::Cos, u),
+        // cos(u)' = -sin(u)
+        Functions::Cos => Expr::negate(Expr::func(Functions::Sin, u)),
+        // tan(u)' = 1 / cos(u)^2
+        Functions::Tan => Expr::binary(
+            Operators::Div,
+            Expr::Number(1.0),
+            Expr::binary(
+                Operators::Caret,
+                Expr::func(Functions::Cos, u),
+                Expr::Number(2.0),
+            ),
+        ),
+        // cot(u)' = -1 / sin(u)^2
+        Functions::Cot => Expr::binary(
+            Operators::Div,
+            Expr::Number(-1.0),
+            Expr::binary(
+                Operators::Caret,
+                Expr::func(Functions::Sin, u),
+                Expr::Number(2.0),
+            ),
+        ),
+        // ln(u)' = 1 / u
+        Functions::Ln => Expr::binary(Operators::Div, Expr::Number(1.0), u),
+        // log(u)' = 1 / (u * ln(10))
+        Functions::Log => Expr::binary(
+            Operators::Div,
+            Expr::Number(1.0),
+            Expr::binary(Operators::Mul, u, Expr::Number(10_f64.ln())),
+        ),
+    };
+    Ok(Expr::binary(Operators::Mul, outer, d_inner))
+}
+
+fn differentiate_power(base: &Expr, exponent: &Expr, var: &str) -> Result<Expr, PolynomialError> {
+    let d_base = differentiate(base, var)?;
+    if !exponent.contains_variable(var) {
+        // Power rule: (u^n)' = n * u^(n-1) * u'
+        let reduced = match exponent {
+            Expr::Number(n) => Expr::Number(n - 1.0),
+            _ => Expr::binary(Operators::Sub, exponent.clone(), Expr::Number(1.0)),
+        };
+        let power = Expr::binary(
+            Operators::Mul,
+            exponent.clone(),
+            Expr::binary(Operators::Caret, base.clone(), reduced),
+        );
+        return Ok(Expr::binary(Operators::Mul, power, d_base));
+    }
+
+    let d_exponent = differentiate(exponent, var)?;
+    let original = Expr::binary(Operators::Caret, base.clone(), exponent.clone());
+    if !base.contains_variable(var) {
+        // Exponential rule: (a^v)' = a^v * ln(a) * v'
+        let scaled = match base {
+            Expr::Constant(Constants::E) => d_exponent,
+            _ => Expr::binary(
+                Operators::Mul,
+                Expr::func(Functions::Ln, base.clone()),
+                d_exponent,
+            ),
+        };
+        return Ok(Expr::binary(Operators::Mul, original, scaled));
+    }
+
+    // General rule: (u^v)' = u^v * (v' * ln(u) + v * u' / u)
+    let log_term = Expr::binary(
+        Operators::Mul,
+        d_exponent,
+        Expr::func(Functions::Ln, base.clone()),
+    );
+    let ratio_term = Expr::binary(
+        Operators::Div,
+        Expr::binary(Operators::Mul, exponent.clone(), d_base),
+        base.clone(),
+    );
+    Ok(Expr::binary(
+        Operators::Mul,
+        original,
+        Expr::binary(Operators::Add, log_term, ratio_term),
+    ))
+}
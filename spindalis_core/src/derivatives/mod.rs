@@ -1,2 +1,3 @@
+pub mod advanced;
 pub mod intermediate;
 pub mod simple;
@@ -0,0 +1,101 @@
+use crate::polynomials::PolynomialError;
+use crate::polynomials::advanced::{Constants, Expr, Functions, Operators};
+use crate::polynomials::simplify::simplify_expr;
+
+// Integrates sums of power terms, constant multiples, 1/x and e^x
+pub fn indefinite_integral_advanced<S>(expr: &Expr, var: S) -> Result<Expr, PolynomialError>
+where
+    S: AsRef<str>,
+{
+    let integrated = integrate(expr, var.as_ref())?;
+    Ok(simplify_expr(&integrated))
+}
+
+fn integrate(expr: &Expr, var: &str) -> Result<Expr, PolynomialError> {
+    let x = Expr::Variable(var.to_string());
+    // ∫ c dx = cx
+    if !expr.contains_variable(var) {
+        return Ok(Expr::binary(Operators::Mul, expr.clone(), x));
+    }
+    match expr {
+        Expr::Variable(_) => Ok(power_rule(x, 1.0)),
+        Expr::UnaryOpPrefix {
+            op: Operators::Sub,
+            value,
+        } => Ok(Expr::negate(integrate(value, var)?)),
+        Expr::BinaryOp { op, lhs, rhs, .. } => {
+            match op {
+                Operators::Add | Operators::Sub => Ok(Expr::binary(
+                    *op,
+                    integrate(lhs, var)?,
+                    integrate(rhs, var)?,
+                )),
+                Operators::Mul | Operators::CDot if !lhs.contains_variable(var) => Ok(
+                    Expr::binary(Operators::Mul, *lhs.clone(), integrate(rhs, var)?),
+                ),
+                Operators::Mul | Operators::CDot if !rhs.contains_variable(var) => Ok(
+                    Expr::binary(Operators::Mul, *rhs.clone(), integrate(lhs, var)?),
+                ),
+                Operators::Div if !rhs.contains_variable(var) => Ok(Expr::binary(
+                    Operators::Div,
+                    integrate(lhs, var)?,
+                    *rhs.clone(),
+                )),
+                // c / x^n = c * x^-n
+                Operators::Div if !lhs.contains_variable(var) => match power_of(rhs, var) {
+                    Some(n) => Ok(Expr::binary(
+                        Operators::Mul,
+                        *lhs.clone(),
+                        power_rule(x, -n),
+                    )),
+                    None => unsupported(expr),
+                },
+                Operators::Caret => match (&**lhs, &**rhs) {
+                    (Expr::Variable(v), Expr::Number(n)) if v == var => Ok(power_rule(x, *n)),
+                    // ∫ e^x dx = e^x
+                    (Expr::Constant(Constants::E), Expr::Variable(v)) if v == var => {
+                        Ok(expr.clone())
+                    }
+                    _ => unsupported(expr),
+                },
+                _ => unsupported(expr),
+            }
+        }
+        _ => unsupported(expr),
+    }
+}
+
+// ∫ x^n dx = x^(n+1) / (n+1), or ln(x) when n = -1
+fn power_rule(x: Expr, n: f64) -> Expr {
+    if n == -1.0 {
+        return Expr::func(Functions::Ln, x);
+    }
+    Expr::binary(
+        Operators::Div,
+        Expr::binary(Operators::Caret, x, Expr::Number(n + 1.0)),
+        Expr::Number(n + 1.0),
+    )
+}
+
+// Returns n for expressions of the form x or x^n
+fn power_of(expr: &Expr, var: &str) -> Option<f64> {
+    match expr {
+        Expr::Variable(v) if v == var => Some(1.0),
+        Expr::BinaryOp {
+            op: Operators::Caret,
+            lhs,
+            rhs,
+            ..
+        } => match (&**lhs, &**rhs) {
+            (Expr::Variable(v), Expr::Number(n)) if v == var => Some(*n),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn unsupported(expr: &Expr) -> Result<Expr, PolynomialError> {
+    Err(PolynomialError::UnsupportedIntegral {
+        expr: expr.to_string(),
+    })
+}
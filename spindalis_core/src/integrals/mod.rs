@@ -1,3 +1,4 @@
+pub mod advanced_indefinite;
 pub mod intermediate_indefinite;
 pub mod simple_indefinite;
 pub mod univariate_definite;
@@ -6,6 +7,7 @@ pub use simple_indefinite::indefinite_integral_simple;
 pub use univariate_definite::analytical_integral;
 pub use univariate_definite::definite_integral;
 pub use univariate_definite::romberg_definite;
+pub use univariate_definite::romberg_definite_with_options;
 
 pub use crate::polynomials::PolynomialError;
 
@@ -1,14 +1,16 @@
+use crate::convergence::{Iteration, SolveReport, SolverOptions, Termination};
 use crate::integrals::IntegralError;
+use crate::objective::Objective;
 use crate::polynomials::structs::PolynomialTraits;
 
-pub fn definite_integral<P>(
-    poly: &P,
+pub fn definite_integral<O>(
+    poly: &O,
     start: f64,
     end: f64,
     segments: usize,
 ) -> Result<f64, IntegralError>
 where
-    P: PolynomialTraits,
+    O: Objective,
 {
     let segment_width = (end - start) / segments as f64;
     let mut sum = 0.0;
@@ -34,101 +36,140 @@ where
     Ok(sum)
 }
 
-pub fn romberg_definite<P>(
-    poly: &P,
+pub fn romberg_definite<O>(
+    poly: &O,
     start: f64,
     end: f64,
     maxiter: u32,
     tolerance: f64,
 ) -> Result<f64, IntegralError>
 where
-    P: PolynomialTraits,
+    O: Objective,
+{
+    let mut options = SolverOptions {
+        rel_tol: tolerance,
+        itermax: maxiter as usize,
+        ..Default::default()
+    };
+    let report = romberg_definite_with_options(poly, start, end, &mut options)?;
+    if !report.converged() {
+        return Err(IntegralError::MaxIterationsReached);
+    }
+    Ok(report.solution)
+}
+
+/// Romberg integration with shared stopping criteria.
+/// Each iteration doubles the number of trapezoidal segments and extrapolates.
+/// The residual is the change in the extrapolated estimate over the last iteration.
+pub fn romberg_definite_with_options<O>(
+    poly: &O,
+    start: f64,
+    end: f64,
+    options: &mut SolverOptions,
+) -> Result<SolveReport<f64>, IntegralError>
+where
+    O: Objective,
 {
-    let maxiter = maxiter as usize;
-    let mut romberg_table: Vec<Vec<f64>> = vec![vec![0.0; 10]; 10];
+    // romberg_table[j][k] is the k-th extrapolation of the trapezoidal rule with 2^j segments
+    let mut romberg_table: Vec<Vec<f64>> = vec![vec![trapezoidal_rule(poly, start, end, 1)?]];
+    let mut history = Vec::new();
+    let mut approx_err = 100.0;
+    let mut residual = f64::INFINITY;
     let mut iter = 0_usize;
-    let mut segments = 1;
-    romberg_table[1][1] = trapezoidal_rule(poly, start, end, segments)?;
 
-    loop {
+    let termination = loop {
         iter += 1;
-        segments = 2_u32.pow(iter as u32) as usize;
+        let Some(segments) = 1_usize.checked_shl(iter as u32) else {
+            break Termination::MaxIterationsReached;
+        };
 
-        romberg_table[iter + 1][1] = trapezoidal_rule(poly, start, end, segments)?;
-        for k in 2..=iter + 1 {
-            let j = 2 + iter - k;
-            let p = 4_usize.pow((k as u32) - 1) as f64;
-
-            romberg_table[j][k] =
-                (p * romberg_table[j + 1][k - 1] - romberg_table[j][k - 1]) / (p - 1.0);
+        romberg_table.push(vec![trapezoidal_rule(poly, start, end, segments)?]);
+        for k in 1..=iter {
+            let j = iter - k;
+            let p = 4_f64.powi(k as i32);
+            let next = (p * romberg_table[j + 1][k - 1] - romberg_table[j][k - 1]) / (p - 1.0);
+            romberg_table[j].push(next);
         }
-        let approx_err = ((romberg_table[1][iter + 1] - romberg_table[2][iter]).abs()
-            / romberg_table[1][iter + 1])
-            .abs()
-            * 100.0;
-        if iter >= maxiter || approx_err <= tolerance {
-            break;
+        let estimate = romberg_table[0][iter];
+        residual = (estimate - romberg_table[1][iter - 1]).abs();
+        if estimate != 0.0 {
+            approx_err = (residual / estimate).abs() * 100.0;
         }
-    }
-    if iter >= maxiter {
-        return Err(IntegralError::MaxIterationsReached);
-    }
-    Ok(romberg_table[1][iter + 1])
+        history.push(approx_err);
+
+        let iteration = Iteration {
+            iteration: iter,
+            x: estimate,
+            step: residual,
+            error: approx_err,
+            residual,
+        };
+        if let Some(termination) = options.termination(&iteration) {
+            break termination;
+        }
+    };
+
+    Ok(SolveReport {
+        solution: romberg_table[0][history.len()],
+        iterations: history.len(),
+        residual,
+        history,
+        termination,
+    })
 }
 
-fn trapezoidal_rule<P>(
-    poly: &P,
+fn trapezoidal_rule<O>(
+    poly: &O,
     start: f64,
     end: f64,
     segments: usize,
 ) -> Result<f64, IntegralError>
 where
-    P: PolynomialTraits,
+    O: Objective,
 {
     let mut xi = start;
     let segment_width = (end - start) / segments as f64;
-    let mut sum = poly.eval_univariate(xi)?;
+    let mut sum = poly.eval(xi)?;
     for _ in 1..segments {
         xi += segment_width;
-        sum += 2_f64 * poly.eval_univariate(xi)?;
+        sum += 2_f64 * poly.eval(xi)?;
     }
-    sum += poly.eval_univariate(end)?;
+    sum += poly.eval(end)?;
 
     Ok(segment_width * sum / 2_f64)
 }
 
 // Handles an odd number of segments
-fn simpson38<P>(segment_width: f64, poly: &P, points: [f64; 4]) -> Result<f64, IntegralError>
+fn simpson38<O>(segment_width: f64, poly: &O, points: [f64; 4]) -> Result<f64, IntegralError>
 where
-    P: PolynomialTraits,
+    O: Objective,
 {
     let mut f: Vec<f64> = Vec::new();
     for point in points {
-        let x = poly.eval_univariate(point)?;
+        let x = poly.eval(point)?;
         f.push(x)
     }
     Ok(3_f64 * segment_width * (f[0] + 3_f64 * f[1] + 3_f64 * f[2] + f[3]) / 8_f64)
 }
 
 // Handles an even number of segments
-fn simpson13<P>(
+fn simpson13<O>(
     segment_width: f64,
-    poly: &P,
+    poly: &O,
     start: f64,
     segments: usize,
 ) -> Result<f64, IntegralError>
 where
-    P: PolynomialTraits,
+    O: Objective,
 {
     let mut xi = start;
-    let mut sum = poly.eval_univariate(xi)?;
+    let mut sum = poly.eval(xi)?;
     for _ in 1..segments / 2 {
         xi += 2_f64 * segment_width;
-        sum +=
-            4_f64 * poly.eval_univariate(xi - segment_width)? + 2_f64 * poly.eval_univariate(xi)?;
+        sum += 4_f64 * poly.eval(xi - segment_width)? + 2_f64 * poly.eval(xi)?;
     }
     xi += 2_f64 * segment_width;
-    sum += 4_f64 * poly.eval_univariate(xi - segment_width)? + poly.eval_univariate(xi)?;
+    sum += 4_f64 * poly.eval(xi - segment_width)? + poly.eval(xi)?;
 
     Ok(segment_width * sum / 3_f64)
 }
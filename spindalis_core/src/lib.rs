@@ -1,3 +1,6 @@
+pub mod complex;
+pub mod convergence;
 pub mod derivatives;
 pub mod integrals;
+pub mod objective;
 pub mod polynomials;
@@ -0,0 +1,173 @@
+use crate::polynomials::PolynomialError;
+use crate::polynomials::structs::{
+    IntermediatePolynomial, Polynomial, PolynomialTraits, SimplePolynomial,
+};
+
+/// A real-valued function of one variable that the solvers and integrators can evaluate.
+///
+/// Implemented for every closure or function pointer `Fn(f64) -> f64` and for the
+/// polynomial structs. Other `PolynomialTraits` implementors can be wrapped in
+/// [`PolyObjective`]. Analytical derivatives are optional: objectives that do not
+/// provide one are differentiated numerically wherever a derivative is needed.
+pub trait Objective {
+    fn eval(&self, x: f64) -> Result<f64, PolynomialError>;
+
+    /// The analytical derivative, or `None` if the objective does not have one
+    fn derivative(&self) -> Option<Result<Self, PolynomialError>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// The analytical derivative if there is one, otherwise a central difference
+    fn derivative_or_numerical(&self) -> Result<Derivative<'_, Self>, PolynomialError>
+    where
+        Self: Sized,
+    {
+        match self.derivative() {
+            Some(derivative) => Ok(Derivative::Analytical(derivative?)),
+            None => Ok(Derivative::Numerical(self)),
+        }
+    }
+}
+
+impl<F> Objective for F
+where
+    F: Fn(f64) -> f64,
+{
+    fn eval(&self, x: f64) -> Result<f64, PolynomialError> {
+        Ok(self(x))
+    }
+}
+
+// A blanket impl over `PolynomialTraits` would overlap with the closure impl above,
+// so each polynomial struct is listed explicitly
+macro_rules! polynomial_objective {
+    ($($poly:ty),*) => {
+        $(
+            impl Objective for $poly {
+                fn eval(&self, x: f64) -> Result<f64, PolynomialError> {
+                    self.eval_univariate(x)
+                }
+
+                fn derivative(&self) -> Option<Result<Self, PolynomialError>> {
+                    Some(self.derivate_univariate())
+                }
+            }
+        )*
+    };
+}
+
+polynomial_objective!(SimplePolynomial, IntermediatePolynomial, Polynomial);
+
+/// Adapter that makes any `PolynomialTraits` implementor an [`Objective`],
+/// using its univariate evaluation and derivative
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolyObjective<P>(pub P);
+
+impl<P> Objective for PolyObjective<P>
+where
+    P: PolynomialTraits,
+{
+    fn eval(&self, x: f64) -> Result<f64, PolynomialError> {
+        self.0.eval_univariate(x)
+    }
+
+    fn derivative(&self) -> Option<Result<Self, PolynomialError>> {
+        Some(self.0.derivate_univariate().map(PolyObjective))
+    }
+}
+
+/// Derivative of an objective, returned by [`Objective::derivative_or_numerical`]
+pub enum Derivative<'a, O> {
+    Analytical(O),
+    /// Central difference of the borrowed objective
+    Numerical(&'a O),
+}
+
+impl<O> Objective for Derivative<'_, O>
+where
+    O: Objective,
+{
+    fn eval(&self, x: f64) -> Result<f64, PolynomialError> {
+        match self {
+            Derivative::Analytical(derivative) => derivative.eval(x),
+            Derivative::Numerical(objective) => central_difference(*objective, x),
+        }
+    }
+
+    fn derivative(&self) -> Option<Result<Self, PolynomialError>> {
+        match self {
+            Derivative::Analytical(derivative) => {
+                Some(derivative.derivative()?.map(Derivative::Analytical))
+            }
+            Derivative::Numerical(_) => None,
+        }
+    }
+}
+
+// The step balances truncation error (h^2) against rounding error (eps / h)
+fn central_difference<O>(objective: &O, x: f64) -> Result<f64, PolynomialError>
+where
+    O: Objective,
+{
+    let h = f64::EPSILON.cbrt() * x.abs().max(1.0);
+    Ok((objective.eval(x + h)? - objective.eval(x - h)?) / (2.0 * h))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERROR_TOL: f64 = 1e-8;
+
+    #[test]
+    fn test_closure() {
+        let f = |x: f64| x.sin() + 1.0;
+        assert_eq!(f.eval(0.0).unwrap(), 1.0);
+        assert!(f.derivative().is_none());
+    }
+
+    #[test]
+    fn test_function_pointer() {
+        let f: fn(f64) -> f64 = f64::exp;
+        assert_eq!(f.eval(0.0).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_polynomial() {
+        let poly = SimplePolynomial::parse("x^3 + 2x").unwrap();
+        assert_eq!(Objective::eval(&poly, 2.0).unwrap(), 12.0);
+        let dx = poly.derivative().unwrap().unwrap();
+        assert_eq!(Objective::eval(&dx, 2.0).unwrap(), 14.0);
+    }
+
+    #[test]
+    fn test_poly_objective() {
+        let poly = PolyObjective(IntermediatePolynomial::parse("x^2 - 3x").unwrap());
+        assert_eq!(poly.eval(4.0).unwrap(), 4.0);
+        let dx = poly.derivative_or_numerical().unwrap();
+        assert!(matches!(dx, Derivative::Analytical(_)));
+        assert_eq!(dx.eval(4.0).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_numerical_derivative() {
+        let f = |x: f64| x.powi(3);
+        let dx = f.derivative_or_numerical().unwrap();
+        assert!(matches!(dx, Derivative::Numerical(_)));
+        assert!((dx.eval(2.0).unwrap() - 12.0).abs() < 1e-6);
+        let dx2 = dx.derivative_or_numerical().unwrap();
+        assert!((dx2.eval(2.0).unwrap() - 12.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_analytical_derivative_chain() {
+        let poly = Polynomial::parse("sin(x)").unwrap();
+        let dx = poly.derivative_or_numerical().unwrap();
+        assert!(matches!(dx, Derivative::Analytical(_)));
+        let dx2 = dx.derivative_or_numerical().unwrap();
+        assert!((dx2.eval(1.0).unwrap() + 1.0_f64.sin()).abs() < ERROR_TOL);
+    }
+}
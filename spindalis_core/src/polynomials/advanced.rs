@@ -1,6 +1,7 @@
+use crate::complex::Complex;
 use crate::polynomials::PolynomialError;
 use crate::polynomials::structs::advanced::{Polynomial, TokenStream};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::LazyLock;
 
@@ -148,6 +149,32 @@ token_from_str! {
     }
 }
 
+impl Functions {
+    /// Apply the function to a real value (`log` is base 10, `ln` is natural log)
+    pub fn apply(&self, x: f64) -> f64 {
+        match self {
+            Self::Sin => x.sin(),
+            Self::Cos => x.cos(),
+            Self::Tan => x.tan(),
+            Self::Cot => 1.0 / x.tan(),
+            Self::Log => x.log10(),
+            Self::Ln => x.ln(),
+        }
+    }
+
+    /// Apply the function to a complex value, using principal branches
+    pub fn apply_complex(&self, z: Complex) -> Complex {
+        match self {
+            Self::Sin => z.sin(),
+            Self::Cos => z.cos(),
+            Self::Tan => z.tan(),
+            Self::Cot => z.cos() / z.sin(),
+            Self::Log => z.log10(),
+            Self::Ln => z.ln(),
+        }
+    }
+}
+
 impl std::fmt::Display for Functions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -173,6 +200,17 @@ token_from_str! {
     }
 }
 
+impl Constants {
+    pub fn value(&self) -> f64 {
+        match self {
+            Self::Pi => std::f64::consts::PI,
+            Self::E => std::f64::consts::E,
+            Self::Tau => std::f64::consts::TAU,
+            Self::Phi => (1.0 + 5_f64.sqrt()) / 2.0,
+        }
+    }
+}
+
 impl std::fmt::Display for Constants {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -280,7 +318,6 @@ impl std::fmt::Display for Expr {
     }
 }
 
-#[allow(dead_code)]
 fn lexer<S>(input: S) -> Result<Vec<Token>, PolynomialError>
 where
     S: AsRef<str>,
@@ -422,7 +459,6 @@ fn implied_multiplication_pass(token_stream: &mut Vec<Token>) {
     }
 }
 
-#[allow(dead_code)]
 fn parse_expr(token_stream: &mut TokenStream, min_bind_pow: f64) -> Result<Expr, PolynomialError> {
     let mut left = match token_stream.next() {
         Some(Token::Number(n)) => Ok(Expr::Number(n)),
@@ -482,7 +518,7 @@ fn parse_expr(token_stream: &mut TokenStream, min_bind_pow: f64) -> Result<Expr,
 
     // iteratively looks for operators with lower binding than minimum binding power
     while let Some(Token::Operator(op)) = token_stream.peek() {
-        let cbind_pow = *BINDING_POW.get(op).unwrap_or(&0.0);
+        let cbind_pow = binding_pow(op);
         if cbind_pow < min_bind_pow {
             break;
         }
@@ -505,7 +541,6 @@ fn parse_expr(token_stream: &mut TokenStream, min_bind_pow: f64) -> Result<Expr,
     Ok(left)
 }
 
-#[allow(dead_code)]
 fn parser(token_stream: Vec<Token>) -> Result<Polynomial, PolynomialError> {
     let mut tokens = token_stream;
     implied_multiplication_pass(&mut tokens);
@@ -570,6 +605,272 @@ fn fold_operations(expr: Expr) -> Expr {
     }
 }
 
+pub fn parse_advanced_polynomial<S>(input: S) -> Result<Polynomial, PolynomialError>
+where
+    S: AsRef<str>,
+{
+    let tokens = lexer(input)?;
+    parser(tokens)
+}
+
+pub fn eval_advanced_polynomial<V, S, F>(expr: &Expr, vars: &V) -> Result<f64, PolynomialError>
+where
+    V: IntoIterator<Item = (S, F)> + std::fmt::Debug + Clone,
+    S: AsRef<str>,
+    F: Into<f64>,
+{
+    let vars_map: HashMap<String, f64> = vars
+        .clone()
+        .into_iter()
+        .map(|(k, v)| (k.as_ref().to_string(), v.into()))
+        .collect();
+
+    evaluate(expr, &vars_map)
+}
+
+fn evaluate(expr: &Expr, vars: &HashMap<String, f64>) -> Result<f64, PolynomialError> {
+    match expr {
+        Expr::Number(n) => Ok(*n),
+        Expr::Variable(v) => match vars.get(v) {
+            Some(value) => Ok(*value),
+            None => Err(PolynomialError::VariableNotFound {
+                variable: v.to_string(),
+            }),
+        },
+        Expr::Constant(c) => Ok(c.value()),
+        Expr::Function { func, inner } => Ok(func.apply(evaluate(inner, vars)?)),
+        Expr::UnaryOpPrefix { op, value } => match op {
+            Operators::Sub => Ok(-evaluate(value, vars)?),
+            _ => Err(PolynomialError::UnexpectedToken {
+                token: Token::Operator(*op),
+            }),
+        },
+        Expr::UnaryOpPostfix { op, value } => match op {
+            Operators::Fac => factorial(evaluate(value, vars)?),
+            _ => Err(PolynomialError::UnexpectedToken {
+                token: Token::Operator(*op),
+            }),
+        },
+        Expr::BinaryOp { op, lhs, rhs, .. } => {
+            let lhs = evaluate(lhs, vars)?;
+            let rhs = evaluate(rhs, vars)?;
+            match op {
+                Operators::Add => Ok(lhs + rhs),
+                Operators::Sub => Ok(lhs - rhs),
+                Operators::Mul | Operators::CDot => Ok(lhs * rhs),
+                Operators::Div => Ok(lhs / rhs),
+                Operators::Rem => Ok(lhs % rhs),
+                Operators::Caret => Ok(lhs.powf(rhs)),
+                Operators::Fac => Err(PolynomialError::UnexpectedToken {
+                    token: Token::Operator(*op),
+                }),
+            }
+        }
+    }
+}
+
+pub fn eval_advanced_polynomial_complex<V, S, C>(
+    expr: &Expr,
+    vars: &V,
+) -> Result<Complex, PolynomialError>
+where
+    V: IntoIterator<Item = (S, C)> + std::fmt::Debug + Clone,
+    S: AsRef<str>,
+    C: Into<Complex>,
+{
+    let vars_map: HashMap<String, Complex> = vars
+        .clone()
+        .into_iter()
+        .map(|(k, v)| (k.as_ref().to_string(), v.into()))
+        .collect();
+
+    evaluate_complex(expr, &vars_map)
+}
+
+fn evaluate_complex(
+    expr: &Expr,
+    vars: &HashMap<String, Complex>,
+) -> Result<Complex, PolynomialError> {
+    match expr {
+        Expr::Number(n) => Ok(Complex::from(*n)),
+        Expr::Variable(v) => match vars.get(v) {
+            Some(value) => Ok(*value),
+            None => Err(PolynomialError::VariableNotFound {
+                variable: v.to_string(),
+            }),
+        },
+        Expr::Constant(c) => Ok(Complex::from(c.value())),
+        Expr::Function { func, inner } => Ok(func.apply_complex(evaluate_complex(inner, vars)?)),
+        Expr::UnaryOpPrefix {
+            op: Operators::Sub,
+            value,
+        } => Ok(-evaluate_complex(value, vars)?),
+        // Factorial and remainder are only defined for real operands
+        Expr::UnaryOpPostfix {
+            op: Operators::Fac,
+            value,
+        } => {
+            let value = real_operand(expr, evaluate_complex(value, vars)?)?;
+            Ok(Complex::from(factorial(value)?))
+        }
+        Expr::BinaryOp { op, lhs, rhs, .. } => {
+            let lhs_value = evaluate_complex(lhs, vars)?;
+            let rhs_value = evaluate_complex(rhs, vars)?;
+            match op {
+                Operators::Add => Ok(lhs_value + rhs_value),
+                Operators::Sub => Ok(lhs_value - rhs_value),
+                Operators::Mul | Operators::CDot => Ok(lhs_value * rhs_value),
+                Operators::Div => Ok(lhs_value / rhs_value),
+                Operators::Rem => {
+                    let a = real_operand(expr, lhs_value)?;
+                    let b = real_operand(expr, rhs_value)?;
+                    Ok(Complex::from(a % b))
+                }
+                Operators::Caret => Ok(lhs_value.powc(rhs_value)),
+                Operators::Fac => Err(PolynomialError::UnexpectedToken {
+                    token: Token::Operator(*op),
+                }),
+            }
+        }
+        Expr::UnaryOpPrefix { op, .. } | Expr::UnaryOpPostfix { op, .. } => {
+            Err(PolynomialError::UnexpectedToken {
+                token: Token::Operator(*op),
+            })
+        }
+    }
+}
+
+fn real_operand(expr: &Expr, value: Complex) -> Result<f64, PolynomialError> {
+    if value.is_real() {
+        Ok(value.re)
+    } else {
+        Err(PolynomialError::UnsupportedComplex {
+            expr: expr.to_string(),
+        })
+    }
+}
+
+// Factorial is only defined for non-negative integers
+pub(crate) fn factorial(n: f64) -> Result<f64, PolynomialError> {
+    if n < 0.0 || n.fract() != 0.0 {
+        return Err(PolynomialError::InvalidFactorial { num: n.to_string() });
+    }
+    // 171! and above overflow f64, so skip the loop for large (possibly huge) n
+    if n > 170.0 {
+        return Ok(f64::INFINITY);
+    }
+    Ok((1..=n as u64).map(|i| i as f64).product())
+}
+
+impl Expr {
+    /// Build a binary operation, wrapping children that bind more loosely than `op`
+    /// in parentheses so that the result displays (and re-parses) correctly
+    pub fn binary(op: Operators, lhs: Expr, rhs: Expr) -> Self {
+        let bind_pow = binding_pow(&op);
+        let lhs = match lhs {
+            Expr::BinaryOp { op: l_op, .. } if binding_pow(&l_op) < bind_pow => parenthesise(lhs),
+            _ => lhs,
+        };
+        // Only addition and multiplication are associative on the right hand side
+        let rhs = match rhs {
+            Expr::BinaryOp { op: r_op, .. }
+                if binding_pow(&r_op) < bind_pow
+                    || (binding_pow(&r_op) == bind_pow
+                        && !matches!(op, Operators::Add | Operators::Mul)) =>
+            {
+                parenthesise(rhs)
+            }
+            _ => rhs,
+        };
+        Expr::BinaryOp {
+            op,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            paren: false,
+        }
+    }
+
+    /// Build a negation, parenthesising sums and differences
+    pub fn negate(value: Expr) -> Self {
+        let value = match value {
+            Expr::BinaryOp {
+                op: Operators::Add | Operators::Sub,
+                ..
+            } => parenthesise(value),
+            _ => value,
+        };
+        Expr::UnaryOpPrefix {
+            op: Operators::Sub,
+            value: Box::new(value),
+        }
+    }
+
+    pub fn func(func: Functions, inner: Expr) -> Self {
+        Expr::Function {
+            func,
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Returns the sorted, de-duplicated list of variables in the expression
+    pub fn variables(&self) -> Vec<String> {
+        let mut found = HashSet::new();
+        self.collect_variables(&mut found);
+        let mut variables: Vec<String> = found.into_iter().collect();
+        variables.sort();
+        variables
+    }
+
+    fn collect_variables(&self, found: &mut HashSet<String>) {
+        match self {
+            Expr::Number(_) | Expr::Constant(_) => {}
+            Expr::Variable(v) => {
+                found.insert(v.clone());
+            }
+            Expr::Function { inner: value, .. }
+            | Expr::UnaryOpPrefix { value, .. }
+            | Expr::UnaryOpPostfix { value, .. } => value.collect_variables(found),
+            Expr::BinaryOp { lhs, rhs, .. } => {
+                lhs.collect_variables(found);
+                rhs.collect_variables(found);
+            }
+        }
+    }
+
+    pub fn contains_variable<S>(&self, var: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let var = var.as_ref();
+        match self {
+            Expr::Number(_) | Expr::Constant(_) => false,
+            Expr::Variable(v) => v == var,
+            Expr::Function { inner: value, .. }
+            | Expr::UnaryOpPrefix { value, .. }
+            | Expr::UnaryOpPostfix { value, .. } => value.contains_variable(var),
+            Expr::BinaryOp { lhs, rhs, .. } => {
+                lhs.contains_variable(var) || rhs.contains_variable(var)
+            }
+        }
+    }
+}
+
+fn binding_pow(op: &Operators) -> f64 {
+    *BINDING_POW.get(op).unwrap_or(&0.0)
+}
+
+fn parenthesise(expr: Expr) -> Expr {
+    match expr {
+        Expr::BinaryOp { op, lhs, rhs, .. } => Expr::BinaryOp {
+            op,
+            lhs,
+            rhs,
+            paren: true,
+        },
+        expr => expr,
+    }
+}
+
 impl From<f64> for Expr {
     fn from(v: f64) -> Self {
         Expr::Number(v)
@@ -1644,6 +1945,92 @@ mod tests {
             assert_eq!(format!("{e}"), "sin(4!)");
         }
     }
+    // ---------------------------
+    // Evaluation tests
+    // ---------------------------
+    mod eval_tests {
+        use super::*;
+
+        fn eval(expr: &str, vars: &[(&str, f64)]) -> Result<f64, PolynomialError> {
+            let parsed = parse_advanced_polynomial(expr)?;
+            eval_advanced_polynomial(parsed.expr(), &vars.to_vec())
+        }
+
+        #[test]
+        fn test_eval_polynomial() {
+            let result = eval("4x^2 + 2x - 1", &[("x", 2.0)]).unwrap();
+            assert_eq!(result, 19.0);
+        }
+
+        #[test]
+        fn test_eval_multivariate() {
+            let result = eval("4xy + 4x^2 - 2y + 4", &[("x", 1.0), ("y", 2.0)]).unwrap();
+            assert_eq!(result, 12.0);
+        }
+
+        #[test]
+        fn test_eval_functions_and_constants() {
+            let x: f64 = 0.5;
+            let y: f64 = 3.0;
+            let result = eval("sin(x)*e^x + ln(y)", &[("x", x), ("y", y)]).unwrap();
+            let expected = x.sin() * x.exp() + y.ln();
+            assert!((result - expected).abs() < 1e-12);
+        }
+
+        #[test]
+        fn test_eval_log_is_base_ten() {
+            let result = eval("log(100)", &[]).unwrap();
+            assert!((result - 2.0).abs() < 1e-12);
+        }
+
+        #[test]
+        fn test_eval_parentheses_and_unary() {
+            let result = eval("-(x + 1) * 3", &[("x", 2.0)]).unwrap();
+            assert_eq!(result, -9.0);
+        }
+
+        #[test]
+        fn test_eval_factorial() {
+            let result = eval("3! + 2", &[]).unwrap();
+            assert_eq!(result, 8.0);
+        }
+
+        #[test]
+        fn test_eval_large_factorial() {
+            assert_eq!(
+                eval("170!", &[]).unwrap(),
+                (1..=170).map(f64::from).product()
+            );
+            assert_eq!(eval("171!", &[]).unwrap(), f64::INFINITY);
+            // Returns straight away rather than multiplying 10^15 terms
+            assert_eq!(eval("x!", &[("x", 1e15)]).unwrap(), f64::INFINITY);
+        }
+
+        #[test]
+        fn test_eval_invalid_factorial() {
+            let result = eval("x!", &[("x", 2.5)]);
+            assert!(matches!(
+                result,
+                Err(PolynomialError::InvalidFactorial { .. })
+            ));
+        }
+
+        #[test]
+        fn test_eval_missing_variable() {
+            let result = eval("4x + y", &[("x", 1.0)]);
+            assert!(matches!(
+                result,
+                Err(PolynomialError::VariableNotFound { .. })
+            ));
+        }
+
+        #[test]
+        fn test_variables() {
+            let parsed = parse_advanced_polynomial("z + sin(x) * y^2 + x").unwrap();
+            assert_eq!(parsed.variables(), vec!["x", "y", "z"]);
+        }
+    }
+
     // ---------------------------
     // token_from_str! tests
     // ---------------------------
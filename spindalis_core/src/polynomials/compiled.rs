@@ -0,0 +1,224 @@
+use crate::polynomials::PolynomialError;
+use crate::polynomials::advanced::{Expr, Functions, Operators, Token, factorial};
+
+/// A single step of the stack machine used by `CompiledExpr`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    Push(f64),
+    Load(usize),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Neg,
+    Fac,
+    Call(Functions),
+}
+
+/// An `Expr` flattened into postfix bytecode.
+///
+/// Variables are bound to slots when the expression is compiled, so evaluating
+/// takes a plain slice of values instead of looking each variable up by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledExpr {
+    instructions: Vec<Instruction>,
+    variables: Vec<String>,
+    stack_size: usize,
+}
+
+impl CompiledExpr {
+    /// Compile with one slot per variable, in the sorted order of `Expr::variables`.
+    /// Fails on nodes that have no instruction, such as a prefix operator other than `-`.
+    pub fn compile(expr: &Expr) -> Result<Self, PolynomialError> {
+        let variables = expr.variables();
+        Self::with_variables(expr, &variables)
+    }
+
+    /// Compile with slots in the order given by `variables`.
+    /// Extra names are allowed and simply leave their slot unused.
+    pub fn with_variables<S>(expr: &Expr, variables: &[S]) -> Result<Self, PolynomialError>
+    where
+        S: AsRef<str>,
+    {
+        let mut compiled = Self {
+            instructions: Vec::new(),
+            variables: variables.iter().map(|v| v.as_ref().to_string()).collect(),
+            stack_size: 0,
+        };
+        let mut depth = 0;
+        compiled.emit(expr, &mut depth)?;
+        Ok(compiled)
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Slot index of `var`, if it was bound at compile time
+    pub fn slot<S>(&self, var: S) -> Option<usize>
+    where
+        S: AsRef<str>,
+    {
+        self.variables.iter().position(|v| v == var.as_ref())
+    }
+
+    /// Evaluate with `values[i]` bound to the variable in slot `i`
+    pub fn eval(&self, values: &[f64]) -> Result<f64, PolynomialError> {
+        let mut stack = Vec::with_capacity(self.stack_size);
+        self.eval_with_stack(values, &mut stack)
+    }
+
+    /// Same as `eval`, but reuses `stack` so repeated evaluations don't allocate
+    pub fn eval_with_stack(
+        &self,
+        values: &[f64],
+        stack: &mut Vec<f64>,
+    ) -> Result<f64, PolynomialError> {
+        if values.len() < self.variables.len() {
+            return Err(PolynomialError::TooFewVariables {
+                variables: self.variables.clone(),
+            });
+        }
+        if values.len() > self.variables.len() {
+            return Err(PolynomialError::TooManyVariables {
+                variables: self.variables.clone(),
+            });
+        }
+
+        stack.clear();
+        for instruction in &self.instructions {
+            let value = match instruction {
+                Instruction::Push(n) => *n,
+                Instruction::Load(slot) => values[*slot],
+                Instruction::Neg => -pop(stack),
+                Instruction::Fac => factorial(pop(stack))?,
+                Instruction::Call(func) => func.apply(pop(stack)),
+                binary => {
+                    let rhs = pop(stack);
+                    let lhs = pop(stack);
+                    apply_binary(binary, lhs, rhs)
+                }
+            };
+            stack.push(value);
+        }
+        Ok(pop(stack))
+    }
+
+    fn emit(&mut self, expr: &Expr, depth: &mut usize) -> Result<(), PolynomialError> {
+        match expr {
+            Expr::Number(n) => self.push(Instruction::Push(*n), depth),
+            Expr::Constant(c) => self.push(Instruction::Push(c.value()), depth),
+            Expr::Variable(v) => match self.slot(v) {
+                Some(slot) => self.push(Instruction::Load(slot), depth),
+                None => {
+                    return Err(PolynomialError::VariableNotFound {
+                        variable: v.to_string(),
+                    });
+                }
+            },
+            Expr::Function { func, inner } => {
+                self.emit(inner, depth)?;
+                self.unary(Instruction::Call(func.clone()));
+            }
+            Expr::UnaryOpPrefix {
+                op: Operators::Sub,
+                value,
+            } => {
+                self.emit(value, depth)?;
+                self.unary(Instruction::Neg);
+            }
+            Expr::UnaryOpPostfix {
+                op: Operators::Fac,
+                value,
+            } => {
+                self.emit(value, depth)?;
+                self.unary(Instruction::Fac);
+            }
+            Expr::UnaryOpPrefix { op, .. } | Expr::UnaryOpPostfix { op, .. } => {
+                return Err(PolynomialError::UnexpectedToken {
+                    token: Token::Operator(*op),
+                });
+            }
+            Expr::BinaryOp { op, lhs, rhs, .. } => {
+                let instruction = match op {
+                    Operators::Add => Instruction::Add,
+                    Operators::Sub => Instruction::Sub,
+                    Operators::Mul | Operators::CDot => Instruction::Mul,
+                    Operators::Div => Instruction::Div,
+                    Operators::Rem => Instruction::Rem,
+                    Operators::Caret => Instruction::Pow,
+                    Operators::Fac => {
+                        return Err(PolynomialError::UnexpectedToken {
+                            token: Token::Operator(*op),
+                        });
+                    }
+                };
+                self.emit(lhs, depth)?;
+                self.emit(rhs, depth)?;
+                self.binary(instruction, depth);
+            }
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, instruction: Instruction, depth: &mut usize) {
+        self.instructions.push(instruction);
+        *depth += 1;
+        self.stack_size = self.stack_size.max(*depth);
+    }
+
+    // Unary operations on a constant are folded at compile time
+    fn unary(&mut self, instruction: Instruction) {
+        if let Some(Instruction::Push(n)) = self.instructions.last() {
+            let folded = match &instruction {
+                Instruction::Neg => Some(-n),
+                Instruction::Call(func) => Some(func.apply(*n)),
+                // Invalid factorials are left to fail at evaluation
+                _ => factorial(*n).ok(),
+            };
+            if let Some(folded) = folded {
+                self.instructions.pop();
+                self.instructions.push(Instruction::Push(folded));
+                return;
+            }
+        }
+        self.instructions.push(instruction);
+    }
+
+    // Binary operations on two constants are folded at compile time
+    fn binary(&mut self, instruction: Instruction, depth: &mut usize) {
+        *depth -= 1;
+        if let [.., Instruction::Push(lhs), Instruction::Push(rhs)] = self.instructions[..] {
+            self.instructions.truncate(self.instructions.len() - 2);
+            self.instructions
+                .push(Instruction::Push(apply_binary(&instruction, lhs, rhs)));
+            return;
+        }
+        self.instructions.push(instruction);
+    }
+}
+
+fn apply_binary(instruction: &Instruction, lhs: f64, rhs: f64) -> f64 {
+    match instruction {
+        Instruction::Add => lhs + rhs,
+        Instruction::Sub => lhs - rhs,
+        Instruction::Mul => lhs * rhs,
+        Instruction::Div => lhs / rhs,
+        Instruction::Rem => lhs % rhs,
+        Instruction::Pow => lhs.powf(rhs),
+        _ => unreachable!("{instruction:?} is not a binary instruction"),
+    }
+}
+
+// Compiled programs always leave enough operands on the stack
+fn pop(stack: &mut Vec<f64>) -> f64 {
+    stack
+        .pop()
+        .expect("compiled expression underflowed its stack")
+}
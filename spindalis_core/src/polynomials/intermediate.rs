@@ -1,3 +1,4 @@
+use crate::complex::Complex;
 use crate::polynomials::{PolynomialError, structs::IntermediatePolynomial};
 use std::collections::{HashMap, HashSet};
 
@@ -178,3 +179,38 @@ where
     }
     Ok(result)
 }
+
+pub fn eval_intermediate_polynomial_complex<V, S, C>(
+    terms: &[Term],
+    vars: &V,
+) -> Result<Complex, PolynomialError>
+where
+    V: IntoIterator<Item = (S, C)> + std::fmt::Debug + Clone,
+    S: AsRef<str>,
+    C: Into<Complex>,
+{
+    let vars_map: HashMap<String, Complex> = vars
+        .clone()
+        .into_iter()
+        .map(|(k, v)| (k.as_ref().to_string(), v.into()))
+        .collect();
+
+    let mut result = Complex::ZERO;
+
+    for term in terms {
+        let mut term_value = Complex::from(term.coefficient);
+
+        for (var, pow) in &term.variables {
+            if let Some(value) = vars_map.get(var) {
+                // Fractional powers use the principal branch
+                term_value *= value.powf(*pow);
+            } else {
+                return Err(PolynomialError::VariableNotFound {
+                    variable: var.to_string(),
+                });
+            }
+        }
+        result += term_value;
+    }
+    Ok(result)
+}
@@ -1,6 +1,8 @@
 pub mod advanced;
+pub mod compiled;
 pub mod intermediate;
 pub mod simple;
+pub mod simplify;
 
 pub mod structs;
 
@@ -14,9 +16,12 @@ use advanced::Token;
 pub enum PolynomialError {
     InvalidCoefficient { coeff: String },
     InvalidConstant,
+    DivisionByZero,
+    ZeroPolynomial,
     InvalidExponent { pow: String },
     InvalidFractionalExponent { pow: String },
     InvalidFraction { frac: String },
+    InvalidFactorial { num: String },
     InvalidNumber { num: String },
     PolynomialSyntaxError,
     MissingVariable,
@@ -26,4 +31,7 @@ pub enum PolynomialError {
     VariableNotFound { variable: String },
     UnexpectedToken { token: Token },
     UnexpectedEndOfTokens,
+    UnsupportedDerivative { expr: String },
+    UnsupportedIntegral { expr: String },
+    UnsupportedComplex { expr: String },
 }
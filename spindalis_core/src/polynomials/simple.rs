@@ -1,3 +1,4 @@
+use crate::complex::Complex;
 use crate::polynomials::{PolynomialError, structs::SimplePolynomial};
 
 pub fn parse_simple_polynomial<S>(input: S) -> Result<SimplePolynomial, PolynomialError>
@@ -92,3 +93,16 @@ where
         .map(|(i, &c)| c * x.powi(i as i32))
         .sum()
 }
+
+pub fn eval_simple_polynomial_complex<C>(z: C, coeffs: &SimplePolynomial) -> Complex
+where
+    C: Into<Complex>,
+{
+    let z: Complex = z.into();
+    // Horner's scheme
+    coeffs
+        .coefficients
+        .iter()
+        .rev()
+        .fold(Complex::ZERO, |acc, &c| acc * z + c)
+}
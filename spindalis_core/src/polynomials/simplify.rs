@@ -0,0 +1,366 @@
+use crate::polynomials::advanced::{Constants, Expr, Functions, Operators, factorial};
+use std::cmp::Ordering;
+
+// The simplifier rewrites an expression as a sum of monomials, where each monomial
+// is a numeric coefficient multiplied by a product of (base, exponent) factors.
+// Like terms and repeated bases are merged in that form before it is turned back
+// into an `Expr` with a canonical ordering.
+//
+// Sums that appear inside products are only distributed over numeric coefficients,
+// so (x + 1)(x - 1) is kept factored while 2(x + 1) becomes 2x + 2.
+
+#[derive(Debug, Clone, PartialEq)]
+struct Monomial {
+    coeff: f64,
+    factors: Vec<(Expr, Expr)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Sum {
+    terms: Vec<Monomial>,
+}
+
+pub fn simplify_expr(expr: &Expr) -> Expr {
+    expand(expr).into_expr()
+}
+
+fn expand(expr: &Expr) -> Sum {
+    match expr {
+        Expr::Number(n) => Sum::number(*n),
+        Expr::Variable(_) | Expr::Constant(_) => Sum::factor(expr.clone(), Expr::Number(1.0)),
+        Expr::Function { func, inner } => simplify_function(func, simplify_expr(inner)),
+        Expr::UnaryOpPrefix {
+            op: Operators::Sub,
+            value,
+        } => expand(value).scale(-1.0),
+        Expr::UnaryOpPostfix {
+            op: Operators::Fac,
+            value,
+        } => {
+            let value = simplify_expr(value);
+            match value {
+                // Never fails, since the guard rules out invalid factorials
+                Expr::Number(n) if n >= 0.0 && n.fract() == 0.0 => {
+                    Sum::number(factorial(n).unwrap_or(f64::NAN))
+                }
+                _ => Sum::factor(
+                    Expr::UnaryOpPostfix {
+                        op: Operators::Fac,
+                        value: Box::new(value),
+                    },
+                    Expr::Number(1.0),
+                ),
+            }
+        }
+        Expr::BinaryOp { op, lhs, rhs, .. } => match op {
+            Operators::Add => expand(lhs).add(expand(rhs)),
+            Operators::Sub => expand(lhs).add(expand(rhs).scale(-1.0)),
+            Operators::Mul | Operators::CDot => expand(lhs).mul(expand(rhs)),
+            Operators::Div => expand(lhs).mul(expand(rhs).pow(Expr::Number(-1.0))),
+            Operators::Caret => expand(lhs).pow(simplify_expr(rhs)),
+            Operators::Rem => {
+                let lhs = simplify_expr(lhs);
+                let rhs = simplify_expr(rhs);
+                match (&lhs, &rhs) {
+                    (Expr::Number(a), Expr::Number(b)) => Sum::number(a % b),
+                    _ => Sum::factor(Expr::binary(Operators::Rem, lhs, rhs), Expr::Number(1.0)),
+                }
+            }
+            Operators::Fac => Sum::factor(expr.clone(), Expr::Number(1.0)),
+        },
+        // Only negation and factorial are produced by the parser
+        _ => Sum::factor(expr.clone(), Expr::Number(1.0)),
+    }
+}
+
+fn simplify_function(func: &Functions, inner: Expr) -> Sum {
+    match (func, &inner) {
+        (_, Expr::Number(n)) => Sum::number(func.apply(*n)),
+        (Functions::Ln, Expr::Constant(Constants::E)) => Sum::number(1.0),
+        _ => Sum::factor(Expr::func(func.clone(), inner), Expr::Number(1.0)),
+    }
+}
+
+impl Sum {
+    fn number(n: f64) -> Self {
+        if n == 0.0 {
+            return Sum { terms: Vec::new() };
+        }
+        Sum {
+            terms: vec![Monomial {
+                coeff: n,
+                factors: Vec::new(),
+            }],
+        }
+    }
+
+    fn factor(base: Expr, exponent: Expr) -> Self {
+        Sum {
+            terms: vec![Monomial {
+                coeff: 1.0,
+                factors: vec![(base, exponent)],
+            }],
+        }
+    }
+
+    fn as_number(&self) -> Option<f64> {
+        match self.terms.as_slice() {
+            [] => Some(0.0),
+            [term] if term.factors.is_empty() => Some(term.coeff),
+            _ => None,
+        }
+    }
+
+    fn scale(mut self, factor: f64) -> Self {
+        for term in &mut self.terms {
+            term.coeff *= factor;
+        }
+        self.collect()
+    }
+
+    fn add(mut self, other: Sum) -> Self {
+        self.terms.extend(other.terms);
+        self.collect()
+    }
+
+    fn mul(self, other: Sum) -> Self {
+        if let Some(n) = self.as_number() {
+            return other.scale(n);
+        }
+        if let Some(n) = other.as_number() {
+            return self.scale(n);
+        }
+        let lhs = self.into_monomial();
+        let rhs = other.into_monomial();
+        Sum {
+            terms: vec![lhs.mul(rhs)],
+        }
+        .collect()
+    }
+
+    fn pow(self, exponent: Expr) -> Self {
+        match exponent {
+            Expr::Number(0.0) => return Sum::number(1.0),
+            Expr::Number(1.0) => return self,
+            _ => {}
+        }
+        if let (Some(base), Expr::Number(n)) = (self.as_number(), &exponent) {
+            return Sum::number(base.powf(*n));
+        }
+        // (ax^b)^n = a^n x^bn is only valid for every x when n is an integer
+        if let (Expr::Number(n), [term]) = (&exponent, self.terms.as_slice())
+            && n.fract() == 0.0
+        {
+            let mut term = term.clone();
+            term.coeff = term.coeff.powf(*n);
+            for (_, exp) in &mut term.factors {
+                *exp = simplify_expr(&Expr::binary(Operators::Mul, exp.clone(), Expr::Number(*n)));
+            }
+            return Sum { terms: vec![term] }.collect();
+        }
+        Sum::factor(self.into_expr(), exponent)
+    }
+
+    // A multi-term sum used as a factor becomes an opaque base
+    fn into_monomial(self) -> Monomial {
+        match self.terms.len() {
+            1 => self.terms.into_iter().next().unwrap(),
+            _ => Monomial {
+                coeff: 1.0,
+                factors: vec![(self.into_expr(), Expr::Number(1.0))],
+            },
+        }
+    }
+
+    // Merge like terms and drop terms with a zero coefficient
+    fn collect(self) -> Self {
+        let mut collected: Vec<Monomial> = Vec::new();
+        for mut term in self.terms {
+            term.normalise();
+            if let Some(existing) = collected.iter_mut().find(|t| t.factors == term.factors) {
+                existing.coeff += term.coeff;
+            } else {
+                collected.push(term);
+            }
+        }
+        collected.retain(|term| term.coeff != 0.0);
+        collected.sort_by(Monomial::order);
+        Sum { terms: collected }
+    }
+
+    fn into_expr(self) -> Expr {
+        let mut terms = self.terms.into_iter();
+        let Some(first) = terms.next() else {
+            return Expr::Number(0.0);
+        };
+        let mut expr = first.into_expr();
+        for term in terms {
+            if term.coeff < 0.0 {
+                let positive = Monomial {
+                    coeff: -term.coeff,
+                    factors: term.factors,
+                };
+                expr = Expr::binary(Operators::Sub, expr, positive.into_expr());
+            } else {
+                expr = Expr::binary(Operators::Add, expr, term.into_expr());
+            }
+        }
+        expr
+    }
+}
+
+impl Monomial {
+    fn mul(mut self, other: Monomial) -> Self {
+        self.coeff *= other.coeff;
+        self.factors.extend(other.factors);
+        self
+    }
+
+    // Combine repeated bases (x^a * x^b = x^(a+b)) and sort the factors
+    fn normalise(&mut self) {
+        let mut merged: Vec<(Expr, Expr)> = Vec::new();
+        for (base, exp) in self.factors.drain(..) {
+            if let Some((_, existing)) = merged.iter_mut().find(|(b, _)| *b == base) {
+                *existing = simplify_expr(&Expr::binary(Operators::Add, existing.clone(), exp));
+            } else {
+                merged.push((base, exp));
+            }
+        }
+        merged.retain(|(_, exp)| *exp != Expr::Number(0.0));
+        merged.sort_by(|(a, _), (b, _)| factor_order(a, b));
+        self.factors = merged;
+    }
+
+    // Total power of the plain variables in the term
+    fn degree(&self) -> f64 {
+        self.factors
+            .iter()
+            .map(|(base, exp)| match (base, exp) {
+                (Expr::Variable(_), Expr::Number(n)) => *n,
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    // Highest degree first, constant terms last
+    fn order(a: &Monomial, b: &Monomial) -> Ordering {
+        if a.factors.is_empty() || b.factors.is_empty() {
+            return b.factors.is_empty().cmp(&a.factors.is_empty()).reverse();
+        }
+        b.degree().total_cmp(&a.degree()).then_with(|| {
+            for ((a_base, _), (b_base, _)) in a.factors.iter().zip(&b.factors) {
+                let ord = factor_order(a_base, b_base);
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            a.factors.len().cmp(&b.factors.len())
+        })
+    }
+
+    fn into_expr(self) -> Expr {
+        let mut numerator: Vec<Expr> = Vec::new();
+        let mut denominator: Vec<Expr> = Vec::new();
+        for (base, exp) in self.factors {
+            match exp {
+                Expr::Number(n) if n < 0.0 => denominator.push(power(base, -n)),
+                Expr::Number(n) => numerator.push(power(base, n)),
+                exp => numerator.push(Expr::binary(Operators::Caret, base, exp)),
+            }
+        }
+
+        // The coefficient leads the product so that it prints as 2x * y
+        let numerator = if numerator.is_empty() {
+            Expr::Number(self.coeff)
+        } else if self.coeff.abs() == 1.0 {
+            let product = product(numerator);
+            if self.coeff < 0.0 {
+                Expr::negate(product)
+            } else {
+                product
+            }
+        } else {
+            numerator.insert(0, Expr::Number(self.coeff));
+            product(numerator)
+        };
+        if denominator.is_empty() {
+            numerator
+        } else {
+            Expr::binary(Operators::Div, numerator, product(denominator))
+        }
+    }
+}
+
+fn product(factors: Vec<Expr>) -> Expr {
+    factors
+        .into_iter()
+        .reduce(|acc, factor| Expr::binary(Operators::Mul, acc, factor))
+        .unwrap_or(Expr::Number(1.0))
+}
+
+fn power(base: Expr, n: f64) -> Expr {
+    if n == 1.0 {
+        base
+    } else {
+        Expr::binary(Operators::Caret, base, Expr::Number(n))
+    }
+}
+
+// Constants, then variables, then everything else, each group ordered by display
+fn factor_order(a: &Expr, b: &Expr) -> Ordering {
+    fn rank(expr: &Expr) -> u8 {
+        match expr {
+            Expr::Number(_) => 0,
+            Expr::Constant(_) => 1,
+            Expr::Variable(_) => 2,
+            Expr::Function { .. } => 3,
+            _ => 4,
+        }
+    }
+    rank(a)
+        .cmp(&rank(b))
+        .then_with(|| a.to_string().cmp(&b.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_large_factorial() {
+        let factorial = |n: f64| Expr::UnaryOpPostfix {
+            op: Operators::Fac,
+            value: Box::new(Expr::Number(n)),
+        };
+        assert_eq!(simplify_expr(&factorial(5.0)), Expr::Number(120.0));
+        assert_eq!(simplify_expr(&factorial(1e15)), Expr::Number(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_nan_degree_ordering() {
+        // Terms with a NaN exponent mixed with ordinary powers must still sort
+        let power = |exp: f64| {
+            Expr::binary(
+                Operators::Caret,
+                Expr::Variable("x".into()),
+                Expr::Number(exp),
+            )
+        };
+        let sum = (0..40)
+            .map(|i| {
+                if i % 3 == 0 {
+                    power(f64::NAN)
+                } else {
+                    power(i as f64)
+                }
+            })
+            .reduce(|acc, term| Expr::binary(Operators::Add, acc, term))
+            .unwrap();
+        let simplified = simplify_expr(&sum);
+        // NaN sorts above every degree, and the rest stay in descending order
+        let text = format!("{simplified}");
+        assert!(text.starts_with("x^NaN"));
+        assert!(text.contains("x^NaN + x^38 + x^37 + x^35"));
+        assert!(text.ends_with("x^4 + x^2 + x"));
+    }
+}
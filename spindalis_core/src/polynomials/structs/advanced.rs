@@ -1,10 +1,20 @@
-use crate::polynomials::advanced::{Expr, Token};
+use crate::complex::Complex;
+use crate::derivatives::advanced::partial_derivative_advanced;
+use crate::integrals::advanced_indefinite::indefinite_integral_advanced;
+use crate::polynomials::PolynomialError;
+use crate::polynomials::advanced::{
+    Expr, Token, eval_advanced_polynomial, eval_advanced_polynomial_complex,
+    parse_advanced_polynomial,
+};
+use crate::polynomials::compiled::CompiledExpr;
+use crate::polynomials::simplify::simplify_expr;
+use crate::polynomials::structs::PolynomialTraits;
 use std::iter::Peekable;
 use std::vec::IntoIter;
 
 pub type TokenStream = Peekable<IntoIter<Token>>;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Polynomial {
     expr: Expr,
 }
@@ -12,6 +22,34 @@ impl Polynomial {
     pub fn new(expr: Expr) -> Self {
         Self { expr }
     }
+
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Sorted list of the variables present in the expression
+    pub fn variables(&self) -> Vec<String> {
+        self.expr.variables()
+    }
+
+    /// Folds constants, collects like terms and puts the expression in canonical order
+    pub fn simplify(&self) -> Self {
+        Self::new(simplify_expr(&self.expr))
+    }
+
+    /// Compile to bytecode with one slot per variable, in the order of `variables`
+    pub fn compile(&self) -> Result<CompiledExpr, PolynomialError> {
+        CompiledExpr::compile(&self.expr)
+    }
+
+    // Univariate methods fall back to `x` for expressions without any variables
+    fn univariate_variable(&self) -> Result<String, PolynomialError> {
+        let variables = self.variables();
+        if variables.len() > 1 {
+            return Err(PolynomialError::TooManyVariables { variables });
+        }
+        Ok(variables.into_iter().next().unwrap_or("x".to_string()))
+    }
 }
 
 impl std::fmt::Display for Polynomial {
@@ -20,24 +58,66 @@ impl std::fmt::Display for Polynomial {
     }
 }
 
-// impl PolynomialTraits for Polynomial {
-//     fn parse(input: &str) -> Result<Self, PolynomialError>{
-//         let tokens = lexer(input)?;
-//         let polynomial = parser(tokens)?;
-//         // TODO add a pass to combine 0*_ as 0 and 1*_ as _
-//         Some(polynomial)
-//     }
-//     fn eval_univariate<F>(&self, point: F) -> Result<f64, PolynomialError>{
-//     }
-//     fn eval_multivariate<V, S, F>(&self, vars: &V) -> Result<f64, PolynomialError> {
-//     }
-//     fn derivate_univariate(&self) -> Result<Self, PolynomialError>{
-//     }
-//     fn derivate_multivariate<S>(&self, var: S) -> Self{
-//     }
-//     fn indefinite_integral_univariate(&self) -> Result<Self, PolynomialError>{
-//     }
-//     fn indefinite_integral_multivariate<S>(&self, var: S) -> Self{
-//     }
-//
-// }
+impl PolynomialTraits for Polynomial {
+    fn parse(input: &str) -> Result<Polynomial, PolynomialError> {
+        parse_advanced_polynomial(input)
+    }
+
+    fn eval_univariate<F>(&self, point: F) -> Result<f64, PolynomialError>
+    where
+        F: Into<f64> + std::clone::Clone + std::fmt::Debug,
+    {
+        let var = self.univariate_variable()?;
+        eval_advanced_polynomial(&self.expr, &[(var, point)])
+    }
+
+    fn eval_complex<C>(&self, point: C) -> Result<Complex, PolynomialError>
+    where
+        C: Into<Complex>,
+    {
+        let var = self.univariate_variable()?;
+        eval_advanced_polynomial_complex(&self.expr, &[(var, point.into())])
+    }
+
+    fn eval_multivariate<V, S, F>(&self, vars: &V) -> Result<f64, PolynomialError>
+    where
+        V: IntoIterator<Item = (S, F)> + std::fmt::Debug + Clone,
+        S: AsRef<str>,
+        F: Into<f64>,
+    {
+        eval_advanced_polynomial(&self.expr, vars)
+    }
+
+    fn derivate_univariate(&self) -> Result<Self, PolynomialError> {
+        let var = self.univariate_variable()?;
+        Ok(Self::new(partial_derivative_advanced(&self.expr, var)?))
+    }
+
+    fn indefinite_integral_univariate(&self) -> Result<Self, PolynomialError> {
+        let var = self.univariate_variable()?;
+        Ok(Self::new(indefinite_integral_advanced(&self.expr, var)?))
+    }
+
+    // The trait requires the multivariate methods to be infallible.
+    // Expressions that can't be handled symbolically become NaN so that
+    // evaluating the result never silently produces a plausible number.
+    fn derivate_multivariate<S>(&self, var: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        match partial_derivative_advanced(&self.expr, var) {
+            Ok(expr) => Self::new(expr),
+            Err(_) => Self::new(Expr::Number(f64::NAN)),
+        }
+    }
+
+    fn indefinite_integral_multivariate<S>(&self, var: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        match indefinite_integral_advanced(&self.expr, var) {
+            Ok(expr) => Self::new(expr),
+            Err(_) => Self::new(Expr::Number(f64::NAN)),
+        }
+    }
+}
@@ -1,12 +1,14 @@
+use crate::complex::Complex;
 use crate::derivatives::intermediate::partial_derivative;
 use crate::integrals::intermediate_indefinite::indefinite_integral_intermediate;
 use crate::polynomials::intermediate::{
-    eval_intermediate_polynomial, parse_intermediate_polynomial,
+    eval_intermediate_polynomial, eval_intermediate_polynomial_complex,
+    parse_intermediate_polynomial,
 };
 use crate::polynomials::structs::PolynomialTraits;
 use crate::polynomials::{PolynomialError, Term};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct IntermediatePolynomial {
     pub terms: Vec<Term>,
     pub variables: Vec<String>,
@@ -61,6 +63,19 @@ impl PolynomialTraits for IntermediatePolynomial {
         Ok(evaluated)
     }
 
+    fn eval_complex<C>(&self, point: C) -> Result<Complex, PolynomialError>
+    where
+        C: Into<Complex>,
+    {
+        if self.variables.len() > 1 {
+            return Err(PolynomialError::TooManyVariables {
+                variables: self.variables.clone(),
+            });
+        }
+        let var = self.variables.first().map_or("x", |v| v.as_str());
+        eval_intermediate_polynomial_complex(&self.terms, &[(var, point.into())])
+    }
+
     fn eval_multivariate<V, S, F>(&self, vars: &V) -> Result<f64, PolynomialError>
     where
         V: IntoIterator<Item = (S, F)> + std::fmt::Debug + Clone,
@@ -2,10 +2,13 @@ pub mod advanced;
 pub mod intermediate;
 pub mod simple;
 
+mod ops;
+
 pub use advanced::Polynomial;
 pub use intermediate::IntermediatePolynomial;
 pub use simple::SimplePolynomial;
 
+use crate::complex::Complex;
 pub use crate::polynomials::PolynomialError; // import Polynomial Error
 
 pub trait PolynomialTraits {
@@ -20,6 +23,9 @@ pub trait PolynomialTraits {
         V: IntoIterator<Item = (S, F)> + std::fmt::Debug + Clone,
         S: AsRef<str>,
         F: Into<f64>;
+    fn eval_complex<C>(&self, point: C) -> Result<Complex, PolynomialError>
+    where
+        C: Into<Complex>;
     fn derivate_univariate(&self) -> Result<Self, PolynomialError>
     where
         Self: std::marker::Sized;
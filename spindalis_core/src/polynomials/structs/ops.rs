@@ -0,0 +1,313 @@
+use crate::polynomials::Term;
+use crate::polynomials::structs::{IntermediatePolynomial, SimplePolynomial};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Each operator is implemented once for `&lhs op &rhs` and the owned
+// combinations (plus the matching `*Assign` operator) forward to it.
+macro_rules! forward_binop {
+    ($poly:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $trait<$poly> for $poly {
+            type Output = $poly;
+            fn $method(self, rhs: $poly) -> $poly {
+                (&self).$method(&rhs)
+            }
+        }
+        impl $trait<&$poly> for $poly {
+            type Output = $poly;
+            fn $method(self, rhs: &$poly) -> $poly {
+                (&self).$method(rhs)
+            }
+        }
+        impl $trait<$poly> for &$poly {
+            type Output = $poly;
+            fn $method(self, rhs: $poly) -> $poly {
+                self.$method(&rhs)
+            }
+        }
+        impl $assign_trait<$poly> for $poly {
+            fn $assign_method(&mut self, rhs: $poly) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+        impl $assign_trait<&$poly> for $poly {
+            fn $assign_method(&mut self, rhs: &$poly) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+    };
+}
+
+// Scalar operators are implemented for `&poly op f64` and forwarded the same way
+macro_rules! forward_scalar_op {
+    ($poly:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $trait<f64> for $poly {
+            type Output = $poly;
+            fn $method(self, rhs: f64) -> $poly {
+                (&self).$method(rhs)
+            }
+        }
+        impl $assign_trait<f64> for $poly {
+            fn $assign_method(&mut self, rhs: f64) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+    };
+}
+
+// SimplePolynomial operators
+//
+// Adding, subtracting or multiplying two polynomials in different variables panics,
+// since the result would need more than one variable.
+
+impl SimplePolynomial {
+    // A SimplePolynomial holds a single variable, so operands must agree on it. A
+    // polynomial without a variable (a constant) takes the other operand's.
+    pub(crate) fn common_variable(&self, rhs: &SimplePolynomial) -> Option<char> {
+        match (self.variable, rhs.variable) {
+            (Some(a), Some(b)) if a != b => panic!(
+                "cannot combine polynomials in different variables ({a} and {b}); \
+                 use IntermediatePolynomial for multivariate arithmetic"
+            ),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn zip_with(&self, rhs: &SimplePolynomial, op: impl Fn(f64, f64) -> f64) -> Self {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        let coefficients = (0..len)
+            .map(|i| {
+                let a = self.coefficients.get(i).copied().unwrap_or(0.0);
+                let b = rhs.coefficients.get(i).copied().unwrap_or(0.0);
+                op(a, b)
+            })
+            .collect();
+        Self::trimmed(coefficients, self.common_variable(rhs))
+    }
+}
+
+impl Add<&SimplePolynomial> for &SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn add(self, rhs: &SimplePolynomial) -> SimplePolynomial {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl Sub<&SimplePolynomial> for &SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn sub(self, rhs: &SimplePolynomial) -> SimplePolynomial {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl Mul<&SimplePolynomial> for &SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn mul(self, rhs: &SimplePolynomial) -> SimplePolynomial {
+        let variable = self.common_variable(rhs);
+        if self.is_empty() || rhs.is_empty() {
+            return SimplePolynomial::trimmed(Vec::new(), variable);
+        }
+        let mut coefficients = vec![0.0; self.len() + rhs.len() - 1];
+        for (i, a) in self.iter().enumerate() {
+            for (j, b) in rhs.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+        SimplePolynomial::trimmed(coefficients, variable)
+    }
+}
+
+impl Neg for &SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn neg(self) -> SimplePolynomial {
+        SimplePolynomial {
+            coefficients: self.iter().map(|c| -c).collect(),
+            variable: self.variable,
+        }
+    }
+}
+
+impl Neg for SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn neg(self) -> SimplePolynomial {
+        -&self
+    }
+}
+
+forward_binop!(SimplePolynomial, Add, add, AddAssign, add_assign);
+forward_binop!(SimplePolynomial, Sub, sub, SubAssign, sub_assign);
+forward_binop!(SimplePolynomial, Mul, mul, MulAssign, mul_assign);
+
+// Adding or subtracting a scalar shifts the constant term
+impl Add<f64> for &SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn add(self, rhs: f64) -> SimplePolynomial {
+        self.zip_with(&SimplePolynomial::trimmed(vec![rhs], None), |a, b| a + b)
+    }
+}
+
+impl Sub<f64> for &SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn sub(self, rhs: f64) -> SimplePolynomial {
+        self + -rhs
+    }
+}
+
+impl Mul<f64> for &SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn mul(self, rhs: f64) -> SimplePolynomial {
+        let coefficients = self.iter().map(|c| c * rhs).collect();
+        SimplePolynomial::trimmed(coefficients, self.variable)
+    }
+}
+
+impl Div<f64> for &SimplePolynomial {
+    type Output = SimplePolynomial;
+    fn div(self, rhs: f64) -> SimplePolynomial {
+        let coefficients = self.iter().map(|c| c / rhs).collect();
+        SimplePolynomial::trimmed(coefficients, self.variable)
+    }
+}
+
+forward_scalar_op!(SimplePolynomial, Add, add, AddAssign, add_assign);
+forward_scalar_op!(SimplePolynomial, Sub, sub, SubAssign, sub_assign);
+forward_scalar_op!(SimplePolynomial, Mul, mul, MulAssign, mul_assign);
+forward_scalar_op!(SimplePolynomial, Div, div, DivAssign, div_assign);
+
+// IntermediatePolynomial operators
+
+impl IntermediatePolynomial {
+    // Merge terms with the same variables and exponents, dropping any that cancel.
+    // The variables list is the sorted union of both operands, even if a variable
+    // no longer appears in any term.
+    fn collected(terms: Vec<Term>, lhs: &[String], rhs: &[String]) -> Self {
+        let mut collected: Vec<Term> = Vec::new();
+        for mut term in terms {
+            term.variables.retain(|(_, pow)| *pow != 0.0);
+            term.variables.sort_by(|a, b| a.0.cmp(&b.0));
+            match collected.iter_mut().find(|t| t.variables == term.variables) {
+                Some(existing) => existing.coefficient += term.coefficient,
+                None => collected.push(term),
+            }
+        }
+        collected.retain(|term| term.coefficient != 0.0);
+
+        let mut variables: Vec<String> = lhs.iter().chain(rhs).cloned().collect();
+        variables.sort();
+        variables.dedup();
+        Self {
+            terms: collected,
+            variables,
+        }
+    }
+
+    fn map_coefficients(&self, op: impl Fn(f64) -> f64) -> Self {
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| Term {
+                coefficient: op(term.coefficient),
+                variables: term.variables.clone(),
+            })
+            .collect();
+        Self::collected(terms, &self.variables, &[])
+    }
+}
+
+// Product of two terms, adding the exponents of shared variables
+fn multiply_terms(lhs: &Term, rhs: &Term) -> Term {
+    let mut variables = lhs.variables.clone();
+    for (var, pow) in &rhs.variables {
+        match variables.iter_mut().find(|(v, _)| v == var) {
+            Some((_, existing)) => *existing += pow,
+            None => variables.push((var.clone(), *pow)),
+        }
+    }
+    Term {
+        coefficient: lhs.coefficient * rhs.coefficient,
+        variables,
+    }
+}
+
+impl Add<&IntermediatePolynomial> for &IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn add(self, rhs: &IntermediatePolynomial) -> IntermediatePolynomial {
+        let terms = self.terms.iter().chain(&rhs.terms).cloned().collect();
+        IntermediatePolynomial::collected(terms, &self.variables, &rhs.variables)
+    }
+}
+
+impl Sub<&IntermediatePolynomial> for &IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn sub(self, rhs: &IntermediatePolynomial) -> IntermediatePolynomial {
+        self + &-rhs
+    }
+}
+
+impl Mul<&IntermediatePolynomial> for &IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn mul(self, rhs: &IntermediatePolynomial) -> IntermediatePolynomial {
+        let terms = self
+            .terms
+            .iter()
+            .flat_map(|a| rhs.terms.iter().map(move |b| multiply_terms(a, b)))
+            .collect();
+        IntermediatePolynomial::collected(terms, &self.variables, &rhs.variables)
+    }
+}
+
+impl Neg for &IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn neg(self) -> IntermediatePolynomial {
+        self.map_coefficients(|c| -c)
+    }
+}
+
+impl Neg for IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn neg(self) -> IntermediatePolynomial {
+        -&self
+    }
+}
+
+forward_binop!(IntermediatePolynomial, Add, add, AddAssign, add_assign);
+forward_binop!(IntermediatePolynomial, Sub, sub, SubAssign, sub_assign);
+forward_binop!(IntermediatePolynomial, Mul, mul, MulAssign, mul_assign);
+
+// Adding or subtracting a scalar shifts the constant term
+impl Add<f64> for &IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn add(self, rhs: f64) -> IntermediatePolynomial {
+        let mut terms = self.terms.clone();
+        terms.push(Term {
+            coefficient: rhs,
+            variables: Vec::new(),
+        });
+        IntermediatePolynomial::collected(terms, &self.variables, &[])
+    }
+}
+
+impl Sub<f64> for &IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn sub(self, rhs: f64) -> IntermediatePolynomial {
+        self + -rhs
+    }
+}
+
+impl Mul<f64> for &IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn mul(self, rhs: f64) -> IntermediatePolynomial {
+        self.map_coefficients(|c| c * rhs)
+    }
+}
+
+impl Div<f64> for &IntermediatePolynomial {
+    type Output = IntermediatePolynomial;
+    fn div(self, rhs: f64) -> IntermediatePolynomial {
+        self.map_coefficients(|c| c / rhs)
+    }
+}
+
+forward_scalar_op!(IntermediatePolynomial, Add, add, AddAssign, add_assign);
+forward_scalar_op!(IntermediatePolynomial, Sub, sub, SubAssign, sub_assign);
+forward_scalar_op!(IntermediatePolynomial, Mul, mul, MulAssign, mul_assign);
+forward_scalar_op!(IntermediatePolynomial, Div, div, DivAssign, div_assign);
@@ -1,7 +1,10 @@
+use crate::complex::Complex;
 use crate::derivatives::simple::simple_derivative;
 use crate::integrals::simple_indefinite::indefinite_integral_simple;
 use crate::polynomials::PolynomialError;
-use crate::polynomials::simple::{eval_simple_polynomial, parse_simple_polynomial};
+use crate::polynomials::simple::{
+    eval_simple_polynomial, eval_simple_polynomial_complex, parse_simple_polynomial,
+};
 use crate::polynomials::structs::PolynomialTraits;
 use std::collections::HashMap;
 
@@ -11,10 +14,190 @@ pub struct SimplePolynomial {
     pub variable: Option<char>,
 }
 
+// Remainders smaller than this (relative to the dividend) are treated as zero by `gcd`
+const GCD_TOL: f64 = 1e-9;
+
 impl SimplePolynomial {
     pub fn is_empty(&self) -> bool {
         self.coefficients.is_empty()
     }
+
+    // Drop leading zero coefficients, keeping a single 0 for the zero polynomial
+    pub(crate) fn trimmed(mut coefficients: Vec<f64>, variable: Option<char>) -> Self {
+        while coefficients.len() > 1 && coefficients.last() == Some(&0.0) {
+            coefficients.pop();
+        }
+        if coefficients.is_empty() {
+            coefficients.push(0.0);
+        }
+        Self {
+            coefficients,
+            variable,
+        }
+    }
+
+    /// Highest power with a non-zero coefficient, or `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.iter().rposition(|&c| c != 0.0)
+    }
+
+    /// Long division returning `(quotient, remainder)` such that
+    /// `self = quotient * divisor + remainder` and the remainder has a lower degree.
+    ///
+    /// # Panics
+    /// If the polynomials are in different variables.
+    pub fn div_rem(
+        &self,
+        divisor: &SimplePolynomial,
+    ) -> Result<(SimplePolynomial, SimplePolynomial), PolynomialError> {
+        let variable = self.common_variable(divisor);
+        let Some(divisor_degree) = divisor.degree() else {
+            return Err(PolynomialError::DivisionByZero);
+        };
+        let lead = divisor.coefficients[divisor_degree];
+
+        let mut remainder = self.coefficients.clone();
+        let Some(degree) = self.degree().filter(|&d| d >= divisor_degree) else {
+            return Ok((
+                Self::trimmed(vec![0.0], variable),
+                Self::trimmed(remainder, variable),
+            ));
+        };
+        let mut quotient = vec![0.0; degree - divisor_degree + 1];
+        for i in (0..quotient.len()).rev() {
+            let coeff = remainder[i + divisor_degree] / lead;
+            quotient[i] = coeff;
+            for (j, d) in divisor.coefficients[..=divisor_degree].iter().enumerate() {
+                remainder[i + j] -= coeff * d;
+            }
+            // Cancelled exactly, even if rounding left a residue
+            remainder[i + divisor_degree] = 0.0;
+        }
+        remainder.truncate(divisor_degree);
+        Ok((
+            Self::trimmed(quotient, variable),
+            Self::trimmed(remainder, variable),
+        ))
+    }
+
+    /// Synthetic division by `(x - root)`, returning the quotient and `self(root)`
+    pub fn deflate(&self, root: f64) -> (SimplePolynomial, f64) {
+        let mut quotient = vec![0.0; self.coefficients.len().saturating_sub(1)];
+        let mut acc = 0.0;
+        for (i, &c) in self.coefficients.iter().enumerate().rev() {
+            acc = acc * root + c;
+            if i > 0 {
+                quotient[i - 1] = acc;
+            }
+        }
+        (Self::trimmed(quotient, self.variable), acc)
+    }
+
+    /// Monic greatest common divisor, computed with the Euclidean algorithm.
+    /// Remainders that are negligible relative to the dividend count as zero,
+    /// so nearly shared roots are treated as shared.
+    ///
+    /// # Panics
+    /// If the polynomials are in different variables.
+    pub fn gcd(&self, other: &SimplePolynomial) -> SimplePolynomial {
+        let variable = self.common_variable(other);
+        let mut a = Self::trimmed(self.coefficients.clone(), variable);
+        let mut b = Self::trimmed(other.coefficients.clone(), variable);
+        if a.degree() < b.degree() {
+            std::mem::swap(&mut a, &mut b);
+        }
+        while b.degree().is_some() {
+            // b is non-zero, so division can't fail
+            let (_, remainder) = a.div_rem(&b).unwrap();
+            let scale = a.iter().fold(0.0_f64, |acc, c| acc.max(c.abs()));
+            let remainder = remainder
+                .iter()
+                .map(|&c| if c.abs() <= GCD_TOL * scale { 0.0 } else { c })
+                .collect();
+            a = b.monic();
+            b = Self::trimmed(remainder, variable);
+        }
+        a.monic()
+    }
+
+    /// Scale so the leading coefficient is 1 (the zero polynomial is unchanged)
+    pub fn monic(&self) -> SimplePolynomial {
+        match self.degree() {
+            Some(d) => self / self.coefficients[d],
+            None => Self::trimmed(vec![0.0], self.variable),
+        }
+    }
+
+    /// Sturm sequence `p, p', -rem(p, p'), ...`, ending at the last non-zero remainder.
+    /// Each remainder is rescaled by a positive constant, which keeps the coefficients
+    /// well sized without changing the sign variations.
+    pub fn sturm_sequence(&self) -> Vec<SimplePolynomial> {
+        let mut sequence = vec![Self::trimmed(self.coefficients.clone(), self.variable)];
+        let derivative = simple_derivative(self);
+        if derivative.degree().is_none() {
+            return sequence;
+        }
+        sequence.push(derivative);
+        loop {
+            let len = sequence.len();
+            let (a, b) = (&sequence[len - 2], &sequence[len - 1]);
+            // b is non-zero, so division can't fail
+            let (_, remainder) = a.div_rem(b).unwrap();
+            let scale = a.iter().fold(0.0_f64, |acc, c| acc.max(c.abs()));
+            let remainder = Self::trimmed(
+                remainder
+                    .iter()
+                    .map(|&c| if c.abs() <= GCD_TOL * scale { 0.0 } else { -c })
+                    .collect(),
+                self.variable,
+            );
+            if remainder.degree().is_none() {
+                return sequence;
+            }
+            let norm = remainder.iter().fold(0.0_f64, |acc, c| acc.max(c.abs()));
+            sequence.push(&remainder / norm);
+        }
+    }
+
+    /// Number of distinct real roots in `(lower, upper]`, counted exactly with
+    /// Sturm's theorem
+    pub fn count_real_roots(&self, lower: f64, upper: f64) -> usize {
+        Self::count_real_roots_with(&self.sturm_sequence(), lower, upper)
+    }
+
+    /// Same as `count_real_roots`, using a Sturm sequence built beforehand with
+    /// `sturm_sequence`, so repeated counts don't rebuild it
+    pub fn count_real_roots_with(sequence: &[SimplePolynomial], lower: f64, upper: f64) -> usize {
+        sign_variations(sequence, lower).saturating_sub(sign_variations(sequence, upper))
+    }
+
+    /// Composition `self(inner(x))`, evaluated with Horner's scheme
+    pub fn compose(&self, inner: &SimplePolynomial) -> SimplePolynomial {
+        let variable = inner.variable.or(self.variable);
+        let mut result = Self::trimmed(vec![0.0], variable);
+        for &c in self.coefficients.iter().rev() {
+            result = &(&result * inner) + c;
+        }
+        result.variable = variable;
+        result
+    }
+}
+
+// Sign changes along a Sturm sequence evaluated at x, skipping zeros
+fn sign_variations(sequence: &[SimplePolynomial], x: f64) -> usize {
+    let mut variations = 0;
+    let mut previous = 0.0;
+    for poly in sequence {
+        let value = poly.iter().rev().fold(0.0, |acc, c| acc * x + c);
+        if value == 0.0 {
+            continue;
+        }
+        if previous * value < 0.0 {
+            variations += 1;
+        }
+        previous = value;
+    }
+    variations
 }
 
 impl std::ops::Deref for SimplePolynomial {
@@ -42,6 +225,13 @@ impl PolynomialTraits for SimplePolynomial {
         Ok(eval_simple_polynomial(point, self))
     }
 
+    fn eval_complex<C>(&self, point: C) -> Result<Complex, PolynomialError>
+    where
+        C: Into<Complex>,
+    {
+        Ok(eval_simple_polynomial_complex(point, self))
+    }
+
     fn derivate_univariate(&self) -> Result<Self, PolynomialError> {
         Ok(simple_derivative(self))
     }